@@ -32,7 +32,7 @@ impl Shared {
         data
     }
 
-    fn create_output_folder() {
+    pub fn create_output_folder() {
         let output = String::from("output");
         let output_dir = std::path::Path::new(&output);
 
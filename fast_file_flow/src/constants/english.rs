@@ -1,11 +1,36 @@
 pub const APP_TITLE: &str = "Fast File Flow - Unir";
 pub const APP_TOOLTIP: &str = "Fast File Flow - Fast Data Engineering Processes";
 pub const SEARCH_PLACEHOLDER: &str = "Search";
+pub const GO_TO_ROW_PLACEHOLDER: &str = "Go to row";
+pub const SEARCH_ALL_COLUMNS: &str = "All columns";
+pub const SEARCH_CASE_LABEL: &str = "Match case";
+pub const SEARCH_WORD_LABEL: &str = "Whole word";
+pub const SEARCH_REGEX_LABEL: &str = "Regex";
+pub const SEARCH_ROWS_LABEL: &str = "Row {} of {} matching, file row {}";
+pub const SEARCH_MATCH_LABEL: &str = "Match {} of {}";
+pub const SEARCH_COLUMNS_LABEL: &str = "{} columns";
+pub const SEARCH_TITLE: &str = "Search";
+pub const SEARCH_COLUMNS_HINT: &str = "Search in the checked columns, all of them when none is";
 
 pub const DIALOG_LOAD_PROJECT_TITLE: &str = "Fast File Flow Project";
 pub const DIALOG_PROJECT_EXTENSION: &str = "ffflow";
 pub const DIALOG_FILE_EXTENSION_CSV: &str = "csv";
 pub const DIALOG_FILE_EXTENSION_JSON: &str = "json";
+pub const DIALOG_FILE_EXTENSION_NDJSON: &str = "ndjson";
+pub const DIALOG_FILE_EXTENSION_JSONL: &str = "jsonl";
+pub const DIALOG_FILE_EXTENSION_PARQUET: &str = "parquet";
+pub const DIALOG_FILE_EXTENSION_XLSX: &str = "xlsx";
+pub const DIALOG_FILE_EXTENSION_XLSM: &str = "xlsm";
+pub const DIALOG_FILE_EXTENSION_XLS: &str = "xls";
+pub const DIALOG_FILE_EXTENSION_ODS: &str = "ods";
+pub const DIALOG_FILE_EXTENSION_TXT: &str = "txt";
+pub const DIALOG_FILE_EXTENSION_SQLITE: &str = "sqlite";
+pub const DIALOG_FILE_EXTENSION_SQLITE3: &str = "sqlite3";
+pub const DIALOG_FILE_EXTENSION_DB: &str = "db";
+pub const DIALOG_HEADER_NAMES_TITLE: &str = "Header names";
+pub const DIALOG_DATA_FILES_TITLE: &str = "Data Files|Fast File Flow Project";
+pub const DIALOG_DATASET_TITLE: &str = "Dataset folder";
+pub const DIALOG_JOIN_FILE_TITLE: &str = "Join file";
 
 pub const ERROR_LOAD_ICON: &str = "Failed to load icon";
 pub const ERROR_GET_FOLDER: &str = "Failed to get current folder";
@@ -13,11 +38,69 @@ pub const ERROR_QUANTITATIVE_COLUMNS: &str =
     "Columns selected must be Quantitative type to start the analysis.";
 pub const ERROR_FILE_NOT_FOUNT: &str = "Select a CSV file to use this function";
 pub const ERROR_FILE_SAVE: &str = "An error occurs on file save process";
+pub const ERROR_ENCODING_UNMAPPABLE: &str = "The character {} can not be written in {}";
+pub const ERROR_EXPORT_VALUE: &str = "Row {}, column {}: {} is not a {} value";
+pub const ERROR_EXPORT: &str = "The file could not be saved: {}";
+pub const ERROR_PREVIEW: &str = "The preview could not be built: {}";
 pub const ERROR_PROJECT_INVALID: &str = "Invalid project file";
+pub const ERROR_INVALID_ROW: &str = "Row number must be a positive integer";
+pub const ERROR_CACHE_BUDGET: &str = "Memory budget must be a positive number of MB";
+pub const ERROR_WORKBOOK_SHEET: &str = "Select a sheet of the workbook";
+pub const ERROR_HEADER_NAMES: &str = "The header names file could not be read";
+pub const ERROR_ROW_WIDTH: &str = "found {} fields, expected {}";
+pub const ERROR_MALFORMED_ROW: &str =
+    "Malformed row at {}, choose to skip or pad the bad rows to open the file";
+pub const ERROR_DIALECT_CHARACTER: &str =
+    "Delimiter, quote and escape must be a single character or TAB";
+pub const ERROR_DATASET_EMPTY: &str = "No data files found in {}";
+pub const ERROR_DATASET_MISMATCH: &str =
+    "{} files left out, their columns do not match the first file";
+pub const ERROR_JOIN_KEYS: &str = "Choose the file to join and at least one pair of key columns";
+pub const ERROR_JOIN_FILE: &str = "The join with {} could not be applied";
+pub const ERROR_JOIN_COLUMN: &str = "column {} not found";
+pub const ERROR_FILE_REWRITTEN: &str = "The file changed on disk, reload it to see the changes";
+pub const ERROR_FILE_TAIL: &str = "The appended rows could not be read: {}";
+pub const ERROR_TASK_CANCELLED: &str = "Cancelled";
+pub const ERROR_SAMPLE_OPTIONS: &str =
+    "The sample size, seed and step must be whole numbers, size and step above 0";
+pub const ERROR_SAMPLE_COLUMN: &str = "Choose the column to stratify the sample by";
+pub const ERROR_SAMPLE: &str = "The sample could not be drawn: {}";
+pub const ERROR_PROFILE: &str = "The dataset could not be profiled: {}";
+pub const ERROR_SEARCH_PATTERN: &str = "Invalid search: {}";
+pub const ERROR_SEARCH: &str = "The search stopped: {}";
+pub const ERROR_SORT_KEY: &str = "Choose the column and the direction to sort by";
+pub const ERROR_SORT: &str = "The table could not be sorted: {}";
+pub const ERROR_QUERY_EMPTY: &str = "Write a SELECT query to run";
+pub const ERROR_QUERY: &str = "The query failed: {}";
+pub const ERROR_PIVOT_OPTIONS: &str = "Choose the columns to group by and at least one value";
+pub const ERROR_PIVOT_VALUE: &str = "Choose the column and the aggregation of the value";
+pub const ERROR_PIVOT_AGGREGATION: &str =
+    "Only count and distinct count can be taken of {}, it is not a number";
+pub const ERROR_PIVOT_COLUMNS: &str = "The pivot column has more than {} values";
+pub const ERROR_PIVOT: &str = "The pivot table could not be built: {}";
+pub const ERROR_PIVOT_CHART: &str = "The chart could not be drawn: {}";
+pub const ERROR_SCHEMA_COLUMN: &str = "Choose a column and the type to give it";
+pub const ERROR_SCHEMA_DECIMAL: &str = "The decimal separator must be . or ,";
+pub const ERROR_SCHEMA_DATE_FORMAT: &str =
+    "Invalid date format, use chrono specifiers such as %d/%m/%Y";
+pub const ERROR_FILTER_MATCH_PATTERN: &str = "The expression to match {} is not valid: {}";
+pub const ERROR_FILTER_RANGE_TYPE: &str =
+    "{} is a {} column, only numbers, dates and times can be compared. Give dates such as 01/10/2026 a type and date format in the schema";
+pub const ERROR_FILTER_RANGE_VALUE: &str = "{} is not a {} value to compare {} with";
+pub const ERROR_FILTER_RANGE_UNPARSED: &str = "{} of {} is not a {} value";
+pub const ERROR_DATABASE_SOURCE: &str = "Select a table or write a SELECT query";
+pub const ERROR_DATABASE_QUERY: &str = "Only SELECT queries can be read from a database";
+pub const ERROR_FILE_LOAD: &str = "The file could not be read: {}";
 pub const ERROR_FILE_SINTAXIS: &str =
-    "File sintaxis {}, it is not supported yet, please use a valid csv";
+    "File sintaxis {}, it is not supported yet, please use a valid csv, json, parquet, workbook or sqlite database";
 
 pub const INFORMATIVE_STADISTIC_ALREADY_EXISTS: &str = "Column's stadistics already exists";
+pub const INFORMATIVE_ROWS_APPENDED: &str = "{} rows appended";
+pub const INFORMATIVE_TASK_CANCELLED: &str = "Task cancelled";
+pub const INFORMATIVE_SEARCH_FILTER: &str = "The export keeps only the rows matching the search";
+pub const INFORMATIVE_SEARCH_LIMIT: &str = "The search stopped at the first {} matches";
+pub const INFORMATIVE_QUERY_LIMIT: &str =
+    "The table shows the first {} rows of the query, the export writes all of them";
 
 pub const LOAD_ICON: &str = "Load";
 pub const USER_ICON: &str = "User";
@@ -34,13 +117,191 @@ pub const PREVIEW_ICON: &str = "Preview";
 pub const SAVE_ICON: &str = "Save Project";
 pub const EXPORT_ICON: &str = "Export File";
 pub const OPEN_ICON: &str = "Open FIle/Project";
+pub const DATASET_ICON: &str = "Open Folder/Glob";
+pub const SCHEMA_ICON: &str = "Schema";
+pub const PROFILE_ICON: &str = "Profile dataset";
+pub const SAMPLE_ICON: &str = "Sampling";
+pub const SORT_ICON: &str = "Sort";
+pub const QUERY_ICON: &str = "SQL query";
+pub const PIVOT_ICON: &str = "Pivot table";
 
 pub const PROCESSED_FILENAME: &str = "processed";
 pub const AI_CLUSTER_CENTER: &str = "AI Analysis Info:";
 pub const PREVIEW_TITLE: &str = "Preview";
 pub const UNKNOWN_LABEL: &str = "Unknown";
+pub const JSON_VALUE_HEADER: &str = "value";
+pub const GENERATED_HEADER_PREFIX: &str = "column_";
+pub const DIALECT_TITLE: &str = "Dialect";
+pub const DIALECT_TAB_LABEL: &str = "TAB";
+pub const DIALECT_DELIMITER_PLACEHOLDER: &str = "Delimiter";
+pub const DIALECT_QUOTE_PLACEHOLDER: &str = "Quote";
+pub const DIALECT_ESCAPE_PLACEHOLDER: &str = "None";
+pub const DIALECT_TERMINATOR_PLACEHOLDER: &str = "Line terminator";
+pub const ROW_POLICY_FAIL: &str = "fail";
+pub const ROW_POLICY_SKIP: &str = "skip";
+pub const ROW_POLICY_PAD: &str = "pad";
+pub const REJECTED_TITLE: &str = "Rejected rows";
+pub const REJECTED_POLICY_PLACEHOLDER: &str = "Bad rows policy";
+pub const REJECTED_LABEL: &str = "{} rejected";
+pub const DIALOG_QUARANTINE_TITLE: &str = "Quarantine";
+pub const DIALECT_HAS_HEADER_LABEL: &str = "First row is the header";
+pub const DIALECT_HEADER_NAMES_LABEL: &str = "{} names loaded";
+pub const LINE_TERMINATOR_CRLF: &str = "CRLF";
+pub const LINE_TERMINATOR_LF: &str = "LF";
+pub const LINE_TERMINATOR_CR: &str = "CR";
+pub const ENCODING_TITLE: &str = "Encoding";
+pub const COMPRESSION_NONE: &str = "none";
+pub const COMPRESSION_GZIP: &str = "gzip";
+pub const COMPRESSION_ZSTD: &str = "zstd";
+pub const COMPRESSION_BZIP2: &str = "bzip2";
+pub const ENCODING_PLACEHOLDER: &str = "Choose an encoding";
+pub const ENCODING_UTF8_BOM: &str = "UTF-8 BOM";
+pub const EXPORT_TITLE: &str = "Export";
+pub const EXPORT_ENCODING_PLACEHOLDER: &str = "Output encoding";
+pub const EXPORT_TABLE_PLACEHOLDER: &str = "SQLite table, the file name when empty";
+pub const WORKBOOK_LABEL: &str = "workbook";
+pub const WORKBOOK_TITLE: &str = "Workbook";
+pub const WORKBOOK_SHEET_PLACEHOLDER: &str = "Choose a sheet";
+pub const WORKBOOK_HEADER_ROW_PLACEHOLDER: &str = "Header row";
+pub const DATABASE_LABEL: &str = "database";
+pub const DATABASE_TITLE: &str = "Database";
+pub const DATABASE_TABLE_PLACEHOLDER: &str = "Choose a table";
+pub const DATABASE_QUERY_PLACEHOLDER: &str = "SELECT ... FROM ...";
+pub const DATABASE_QUERY_LABEL: &str = "query";
+pub const CACHE_TITLE: &str = "Column cache";
+pub const CACHE_ENABLED_LABEL: &str = "Keep columns in memory";
+pub const CACHE_ON_LOAD_LABEL: &str = "Build when the file is loaded";
+pub const CACHE_BUDGET_PLACEHOLDER: &str = "MB";
+pub const CACHE_STATUS_BUILDING: &str = "caching";
+pub const CACHE_STATUS_STREAMING: &str = "streaming";
+pub const DATASET_TITLE: &str = "Dataset";
+pub const DATASET_PATTERN_PLACEHOLDER: &str = "Folder or glob, e.g. data/sales_*.csv";
+pub const DATASET_SOURCE_COLUMN_LABEL: &str = "Add a source_file column";
+pub const DATASET_FILES_LABEL: &str = "{} files";
+pub const DATASET_SOURCE_HEADER: &str = "source_file";
+pub const DATASET_MISSING_COLUMNS: &str = "missing {}";
+pub const DATASET_EXTRA_COLUMNS: &str = "unexpected {}";
+pub const DATASET_COLUMN_ORDER: &str = "columns in a different order";
+pub const DATASET_FORMAT_MISMATCH: &str = "format {} instead of {}";
+pub const JOIN_TITLE: &str = "Join";
+pub const JOIN_INNER: &str = "inner";
+pub const JOIN_LEFT: &str = "left";
+pub const JOIN_ANTI: &str = "anti";
+pub const JOIN_TYPE_PLACEHOLDER: &str = "Join type";
+pub const JOIN_LEFT_KEY_PLACEHOLDER: &str = "Key";
+pub const JOIN_RIGHT_KEY_PLACEHOLDER: &str = "Joined key";
+pub const JOIN_FILE_PLACEHOLDER: &str = "Choose a csv to join";
+pub const JOIN_COLUMNS_LABEL: &str = "{} joined";
+pub const SCHEMA_TITLE: &str = "Schema";
+pub const SCHEMA_COLUMN_PLACEHOLDER: &str = "Choose a column";
+pub const SCHEMA_TYPE_PLACEHOLDER: &str = "Type";
+pub const SCHEMA_DATE_FORMAT_PLACEHOLDER: &str = "Date format, e.g. %d/%m/%Y";
+pub const SCHEMA_DECIMAL_PLACEHOLDER: &str = ".";
+pub const SCHEMA_INFERRED_LABEL: &str = "inferred {}";
+pub const PROFILE_TITLE: &str = "Profile";
+pub const PROFILE_COLUMNS_LABEL: &str = "{} columns";
+pub const PROFILE_COLUMN_LABEL: &str = "Column";
+pub const PROFILE_TYPE_LABEL: &str = "Type";
+pub const PROFILE_NULLS_LABEL: &str = "Nulls";
+pub const PROFILE_DISTINCT_LABEL: &str = "Distinct";
+pub const PROFILE_MINIMUM_LABEL: &str = "Min";
+pub const PROFILE_MAXIMUM_LABEL: &str = "Max";
+pub const PROFILE_MEAN_LABEL: &str = "Mean";
+pub const PROFILE_ASCENDING_LABEL: &str = "{} ↑";
+pub const PROFILE_DESCENDING_LABEL: &str = "{} ↓";
+pub const SORT_TITLE: &str = "Sort";
+pub const SORT_ASCENDING: &str = "ascending";
+pub const SORT_DESCENDING: &str = "descending";
+pub const SORT_COLUMN_PLACEHOLDER: &str = "Sort by";
+pub const SORT_DIRECTION_PLACEHOLDER: &str = "Direction";
+pub const SORT_KEYS_EMPTY: &str = "The table keeps the order of the file";
+pub const SORT_TABLE_LABEL: &str = " (sorted)";
+pub const EXPORT_SORTED_LABEL: &str = "Export in the order of the sort";
+pub const QUERY_TITLE: &str = "SQL query";
+pub const QUERY_DEFAULT: &str = "SELECT * FROM {} LIMIT 100";
+pub const QUERY_TABLES_LABEL: &str = "Tables: {}";
+pub const QUERY_TABLES_HINT: &str = "Every file is a table named after it, loaded on the first run";
+pub const QUERY_FILES_LABEL: &str = "Other files: {}";
+pub const QUERY_ROWS_LABEL: &str = "Row {} of {} shown, {} rows in the result";
+pub const EXPORT_QUERY_LABEL: &str = "Export the result of the query";
+pub const PIVOT_TITLE: &str = "Pivot table";
+pub const PIVOT_GROUP_PLACEHOLDER: &str = "Group by";
+pub const PIVOT_COLUMN_PLACEHOLDER: &str = "Pivot column";
+pub const PIVOT_VALUE_PLACEHOLDER: &str = "Value";
+pub const PIVOT_AGGREGATION_PLACEHOLDER: &str = "Aggregation";
+pub const PIVOT_DATE_GROUP_LABEL: &str = "Group dates by";
+pub const PIVOT_CHART_PLACEHOLDER: &str = "Column to chart";
+pub const PIVOT_GROUPS_LABEL: &str = "Groups: {}";
+pub const PIVOT_COLUMN_LABEL: &str = "Pivot: {}";
+pub const PIVOT_VALUES_LABEL: &str = "Values: {}";
+pub const PIVOT_NONE: &str = "none";
+pub const PIVOT_VALUE_HEADER: &str = "{} of {}";
+pub const PIVOT_COLUMN_HEADER: &str = "{}: {}";
+pub const PIVOT_EMPTY_VALUE: &str = "(empty)";
+pub const AGGREGATION_COUNT: &str = "count";
+pub const AGGREGATION_SUM: &str = "sum";
+pub const AGGREGATION_MEAN: &str = "mean";
+pub const AGGREGATION_MIN: &str = "min";
+pub const AGGREGATION_MAX: &str = "max";
+pub const AGGREGATION_DISTINCT_COUNT: &str = "distinct count";
+pub const AGGREGATION_MEDIAN: &str = "median";
+pub const DATE_GROUP_VALUE: &str = "value";
+pub const DATE_GROUP_YEAR: &str = "year";
+pub const DATE_GROUP_MONTH: &str = "month";
+pub const DATE_GROUP_DAY: &str = "day";
+pub const EXPORT_PIVOT_LABEL: &str = "Export the pivot table";
+pub const FILTER_RANGE_LABEL: &str = "Keep row if value";
+pub const FILTER_RANGE_FROM_PLACEHOLDER: &str = "value";
+pub const FILTER_RANGE_TO_PLACEHOLDER: &str = "and";
+pub const FILTER_UNPARSED_LABEL: &str = "Not a number or date";
+pub const COMPARISON_GREATER: &str = ">";
+pub const COMPARISON_GREATER_OR_EQUAL: &str = ">=";
+pub const COMPARISON_LESS: &str = "<";
+pub const COMPARISON_LESS_OR_EQUAL: &str = "<=";
+pub const COMPARISON_BETWEEN: &str = "between";
+pub const COMPARISON_NOT_BETWEEN: &str = "not between";
+pub const UNPARSED_POLICY_SKIP: &str = "ignore row";
+pub const UNPARSED_POLICY_KEEP: &str = "keep row";
+pub const UNPARSED_POLICY_FAIL: &str = "stop the export";
+pub const SAMPLE_TITLE: &str = "Sampling";
+pub const SAMPLE_ALL: &str = "all rows";
+pub const SAMPLE_HEAD: &str = "head";
+pub const SAMPLE_TAIL: &str = "tail";
+pub const SAMPLE_RANDOM: &str = "random";
+pub const SAMPLE_STRATIFIED: &str = "stratified";
+pub const SAMPLE_EVERY_NTH: &str = "every nth";
+pub const SAMPLE_MODE_PLACEHOLDER: &str = "Sampling";
+pub const SAMPLE_SIZE_PLACEHOLDER: &str = "Rows";
+pub const SAMPLE_SEED_PLACEHOLDER: &str = "Seed";
+pub const SAMPLE_STEP_PLACEHOLDER: &str = "Every n rows";
+pub const SAMPLE_COLUMN_PLACEHOLDER: &str = "Stratify by";
+pub const SAMPLE_MODELS_LABEL: &str = "Feed the AI models with the sample";
+pub const SAMPLE_ROWS_LABEL: &str = "{} of {} rows sampled";
 
 pub const BUTTON_CLOSE: &str = "Close";
+pub const BUTTON_APPLY: &str = "Apply";
+pub const BUTTON_EXPORT: &str = "Export";
+pub const BUTTON_LOAD_NAMES: &str = "Load names";
+pub const BUTTON_CLEAR: &str = "Clear";
+pub const BUTTON_QUARANTINE: &str = "Export quarantine";
+pub const BUTTON_BROWSE: &str = "Browse";
+pub const BUTTON_LOAD: &str = "Load";
+pub const BUTTON_ADD_KEY: &str = "Add key";
+pub const BUTTON_REMOVE: &str = "Remove";
+pub const BUTTON_SET: &str = "Set";
+pub const BUTTON_RESET: &str = "Reset";
+pub const BUTTON_RELOAD: &str = "Reload";
+pub const BUTTON_CANCEL: &str = "Cancel";
+pub const BUTTON_RECOMPUTE: &str = "Recompute";
+pub const BUTTON_PREVIOUS: &str = "<";
+pub const BUTTON_NEXT: &str = ">";
+pub const BUTTON_SEARCH_FILTER: &str = "Keep matches on export";
+pub const BUTTON_RUN: &str = "Run";
+pub const BUTTON_ADD_FILE: &str = "Add file";
+pub const BUTTON_ADD: &str = "Add";
+pub const BUTTON_CHART: &str = "Chart";
+pub const BUTTON_TABLE: &str = "Table";
+pub const WATCH_FILE_LABEL: &str = "Watch";
 
 pub const DATA_CLASSIFICATION_QUALITATIVE: &str = "Qualitative";
 pub const DATA_CLASSIFICATION_QUANTITATIVE: &str = "Quantitative";
@@ -1,3 +1,5 @@
+use super::english::ENCODING_UTF8_BOM;
+
 pub const ICON_PATH: &str = "resources/images/icon.png";
 pub const LOGO_PRIMARY_PATH: &str = "\\src\\resources\\images\\logo-black.png";
 pub const LOGO_SECONDARY_PATH: &str = "\\src\\resources\\images\\logo-white.png";
@@ -5,5 +7,58 @@ pub const KMEANS_IMAGE_RESULT: &str = "output/kmeans_plot.png";
 pub const PCA_IMAGE_RESULT: &str = "output/pca_plot.png";
 pub const DBSCAN_IMAGE_RESULT: &str = "output/dbscan_plot.png";
 pub const LR_IMAGE_RESULT: &str = "output/linear_regression_plot_plot.png";
+pub const PIVOT_IMAGE_RESULT: &str = "output/pivot_plot.png";
 pub const CSV: &str = "CSV";
+pub const JSON: &str = "JSON";
+pub const NDJSON: &str = "NDJSON";
+pub const JSONL: &str = "JSONL";
+pub const PARQUET: &str = "PARQUET";
+pub const PARQUET_MAGIC: &[u8] = b"PAR1";
+pub const XLSX: &str = "XLSX";
+pub const XLSM: &str = "XLSM";
+pub const XLS: &str = "XLS";
+pub const ODS: &str = "ODS";
+pub const WORKBOOK_FORMATS: [&str; 4] = [XLSX, XLSM, XLS, ODS];
+pub const SQLITE: &str = "SQLITE";
+pub const SQLITE3: &str = "SQLITE3";
+pub const DB: &str = "DB";
+pub const DATABASE_FORMATS: [&str; 3] = [SQLITE, SQLITE3, DB];
+pub const SQLITE_MAGIC: &[u8] = b"SQLite format 3\0";
+pub const ZIP_MAGIC: &[u8] = b"PK\x03\x04";
+pub const OLE_MAGIC: &[u8] = b"\xD0\xCF\x11\xE0";
+pub const GZIP_MAGIC: &[u8] = b"\x1F\x8B";
+pub const ZSTD_MAGIC: &[u8] = b"\x28\xB5\x2F\xFD";
+pub const BZIP2_MAGIC: &[u8] = b"BZh";
+pub const COMPRESSED_FORMATS: [&str; 5] = ["GZ", "GZIP", "ZST", "ZSTD", "BZ2"];
+pub const DATA_FORMATS: [&str; 12] = [
+    CSV, JSON, NDJSON, JSONL, PARQUET, XLSX, XLSM, XLS, ODS, SQLITE, SQLITE3, DB,
+];
 pub const FFFLOW: &str = "FFFLOW";
+pub const ROW_INDEX_EXTENSION: &str = "ffidx";
+pub const ROW_INDEX_FOLDER: &str = "fast_file_flow/index";
+pub const PARTIAL_EXTENSION: &str = "part";
+pub const SORT_EXTENSION: &str = "ffsort";
+pub const ROW_INDEX_MAGIC: &[u8] = b"FFIDX3";
+pub const ENCODINGS: [&str; 14] = [
+    "UTF-8",
+    "UTF-16LE",
+    "UTF-16BE",
+    "WINDOWS-1252",
+    "ISO-8859-1",
+    "ISO-8859-15",
+    "WINDOWS-1250",
+    "WINDOWS-1251",
+    "KOI8-R",
+    "SHIFT_JIS",
+    "EUC-JP",
+    "GBK",
+    "BIG5",
+    "EUC-KR",
+];
+pub const EXPORT_ENCODINGS: [&str; 5] = [
+    "UTF-8",
+    ENCODING_UTF8_BOM,
+    "UTF-16LE",
+    "WINDOWS-1252",
+    "ISO-8859-15",
+];
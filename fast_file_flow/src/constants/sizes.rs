@@ -8,7 +8,36 @@ pub const PANEL_HEIGHT: f32 = 170.0;
 pub const PANEL_WIDTH: f32 = 350.0;
 pub const SEARCH_TEXTBOX_WIDTH: f32 = 300.0;
 pub const PANEL_FONT_SIZE: f32 = 12.0;
+pub const PROFILE_CELL_WIDTH: f32 = 105.0;
+pub const QUERY_EDITOR_HEIGHT: f32 = 110.0;
 
 pub const IMAGE_HEIGHT: u32 = 768;
 pub const IMAGE_WIDTH: u32 = 1024;
 pub const IMAGE_POINT_SIZE: u32 = 2;
+
+pub const TABLE_ROW_HEIGHT: f32 = 32.0;
+pub const TABLE_HEADER_HEIGHT: f32 = 48.0;
+pub const TABLE_WINDOW_ROWS: usize = 200;
+pub const TABLE_VISIBLE_ROWS_MAX: u64 = 40;
+pub const TABLE_SCROLL_STEP: f32 = 3.0;
+pub const TABLE_SLIDER_WIDTH: f32 = 16.0;
+
+pub const DIALECT_SNIFF_BYTES: usize = 8192;
+pub const DIALECT_SNIFF_LINES: usize = 50;
+
+pub const COLUMN_CACHE_BUDGET_MB: usize = 512;
+pub const ROW_INDEX_STRIDE: u64 = 1024;
+pub const FILE_STAMP_TAIL_BYTES: u64 = 1024;
+pub const FILE_WATCH_INTERVAL_MS: u64 = 1000;
+pub const REJECTED_ROWS_MAX: usize = 1000;
+pub const SAMPLE_SIZE_DEFAULT: usize = 1000;
+pub const SAMPLE_SEED_DEFAULT: u64 = 42;
+pub const SAMPLE_STEP_DEFAULT: u64 = 100;
+pub const PROFILE_SAMPLE_VALUES: usize = 10000;
+pub const PROFILE_DISTINCT_SKETCH: usize = 4096;
+pub const SEARCH_BATCH_ROWS: usize = 10000;
+pub const SEARCH_MATCHES_MAX: usize = 100000;
+pub const SORT_RUN_MB: usize = 64;
+pub const QUERY_ROWS_MAX: usize = 100000;
+pub const PIVOT_COLUMNS_MAX: usize = 50;
+pub const PIVOT_CHART_BARS_MAX: usize = 50;
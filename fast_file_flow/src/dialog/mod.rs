@@ -1,8 +1,33 @@
 use native_dialog::FileDialog;
 
+use crate::constants::english::{
+    DIALOG_DATA_FILES_TITLE, DIALOG_FILE_EXTENSION_CSV, DIALOG_FILE_EXTENSION_DB,
+    DIALOG_FILE_EXTENSION_JSON, DIALOG_FILE_EXTENSION_JSONL, DIALOG_FILE_EXTENSION_NDJSON,
+    DIALOG_FILE_EXTENSION_ODS, DIALOG_FILE_EXTENSION_PARQUET, DIALOG_FILE_EXTENSION_SQLITE,
+    DIALOG_FILE_EXTENSION_SQLITE3, DIALOG_FILE_EXTENSION_XLS, DIALOG_FILE_EXTENSION_XLSM,
+    DIALOG_FILE_EXTENSION_XLSX, DIALOG_PROJECT_EXTENSION,
+};
+
 pub fn load_csv() -> String {
     let path = FileDialog::new()
-        .add_filter("CSV Files|Fast File Flow Project", &["csv", "ffflow"])
+        .add_filter(
+            DIALOG_DATA_FILES_TITLE,
+            &[
+                DIALOG_FILE_EXTENSION_CSV,
+                DIALOG_FILE_EXTENSION_JSON,
+                DIALOG_FILE_EXTENSION_NDJSON,
+                DIALOG_FILE_EXTENSION_JSONL,
+                DIALOG_FILE_EXTENSION_PARQUET,
+                DIALOG_FILE_EXTENSION_XLSX,
+                DIALOG_FILE_EXTENSION_XLSM,
+                DIALOG_FILE_EXTENSION_XLS,
+                DIALOG_FILE_EXTENSION_ODS,
+                DIALOG_FILE_EXTENSION_SQLITE,
+                DIALOG_FILE_EXTENSION_SQLITE3,
+                DIALOG_FILE_EXTENSION_DB,
+                DIALOG_PROJECT_EXTENSION,
+            ],
+        )
         .set_location("~")
         .show_open_single_file()
         .unwrap();
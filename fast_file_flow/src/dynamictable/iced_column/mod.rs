@@ -1,4 +1,4 @@
-use crate::stadistics::Stadistics;
+use crate::stadistics::{data_type::DataType, Stadistics};
 
 #[derive(Debug, Clone)]
 pub struct IcedColumn {
@@ -6,6 +6,7 @@ pub struct IcedColumn {
     pub width: f32,
     pub resize_offset: Option<f32>,
     pub is_checked: bool,
+    pub data_type: DataType,
     pub stadistics: Stadistics,
 }
 
@@ -21,6 +22,7 @@ impl IcedColumn {
             width,
             resize_offset: None,
             is_checked: false,
+            data_type: DataType::Unknown,
             stadistics: Stadistics::default(),
         }
     }
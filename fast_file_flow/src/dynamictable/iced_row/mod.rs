@@ -3,6 +3,10 @@ pub struct IcedRow {
     pub values: Vec<String>,
     pub is_enabled: bool,
     pub row_index: usize,
+    /// Columns whose value matched the search.
+    pub highlighted: Vec<usize>,
+    /// Column of the match the search navigation is at.
+    pub selected: Option<usize>,
 }
 
 impl IcedRow {
@@ -11,6 +15,8 @@ impl IcedRow {
             is_enabled: true,
             values: vec![],
             row_index: 0,
+            highlighted: vec![],
+            selected: None,
         }
     }
     pub fn new(values: Vec<String>, row: usize) -> Self {
@@ -18,6 +24,8 @@ impl IcedRow {
             is_enabled: true,
             values,
             row_index: row,
+            highlighted: vec![],
+            selected: None,
         }
     }
 }
@@ -1,7 +1,7 @@
 use crate::fast_file_flow::FastFileFlowMessage;
 use crate::util::wrap_tooltip_with_position;
 use iced::widget::{container, text};
-use iced::{Element, Length, Padding, Pixels, Theme};
+use iced::{Background, Color, Element, Length, Padding, Pixels, Theme};
 use iced_column::IcedColumn;
 use iced_row::IcedRow;
 use iced_table::table;
@@ -56,11 +56,24 @@ impl<'a> table::Column<'a, FastFileFlowMessage, Theme, iced::Renderer> for IcedC
             .clone();
         let content: Element<_> = text(value.clone()).size(10.0).into();
 
-        container(content)
+        let mut cell = container(content)
             .width(Length::Fill)
             .height(24.0)
-            .center_y()
-            .into()
+            .center_y();
+        let highlight = match _row.selected == Some(_col_index) {
+            true => Some(Color::from_rgb(1.0, 0.6, 0.2)),
+            false if _row.highlighted.contains(&_col_index) => {
+                Some(Color::from_rgb(1.0, 0.87, 0.4))
+            }
+            false => None,
+        };
+        if let Some(color) = highlight {
+            cell = cell.style(container::Appearance {
+                background: Some(Background::Color(color)),
+                ..Default::default()
+            });
+        }
+        cell.into()
     }
 
     fn footer(
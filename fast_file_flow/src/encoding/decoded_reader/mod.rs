@@ -0,0 +1,71 @@
+use encoding_rs::{Decoder, Encoding};
+use std::{
+    io::Result,
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use tokio::io::{AsyncRead, ReadBuf};
+
+const BUFFER_SIZE: usize = 8192;
+
+/// Transcodes any supported charset into the UTF-8 expected by the readers, a leading BOM
+/// is removed and takes precedence over the given encoding.
+pub struct DecodedReader<R> {
+    inner: R,
+    decoder: Decoder,
+    input: Vec<u8>,
+    output: Vec<u8>,
+    position: usize,
+    finished: bool,
+}
+
+impl<R: AsyncRead + Unpin> DecodedReader<R> {
+    pub fn new(inner: R, encoding: &'static Encoding) -> Self {
+        Self {
+            inner,
+            decoder: encoding.new_decoder(),
+            input: vec![0; BUFFER_SIZE],
+            output: vec![],
+            position: 0,
+            finished: false,
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for DecodedReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<Result<()>> {
+        let this = self.get_mut();
+
+        loop {
+            if this.position < this.output.len() {
+                let length = buf.remaining().min(this.output.len() - this.position);
+                buf.put_slice(&this.output[this.position..this.position + length]);
+                this.position += length;
+                return Poll::Ready(Ok(()));
+            }
+
+            if this.finished {
+                return Poll::Ready(Ok(()));
+            }
+
+            let mut input = ReadBuf::new(&mut this.input);
+            ready!(Pin::new(&mut this.inner).poll_read(cx, &mut input))?;
+            let input = input.filled();
+            let last = input.is_empty();
+
+            let capacity = this
+                .decoder
+                .max_utf8_buffer_length(input.len())
+                .unwrap_or(BUFFER_SIZE * 3);
+            this.output.resize(capacity, 0);
+            let (_, _, written, _) = this.decoder.decode_to_utf8(input, &mut this.output, last);
+            this.output.truncate(written);
+            this.position = 0;
+            this.finished = last;
+        }
+    }
+}
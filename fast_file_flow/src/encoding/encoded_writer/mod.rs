@@ -0,0 +1,80 @@
+use super::get_encoding;
+use crate::constants::english::{ENCODING_UTF8_BOM, ERROR_ENCODING_UNMAPPABLE};
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE, UTF_8};
+use std::io::{Error, ErrorKind, Result, Write};
+
+/// Receives the UTF-8 written by the csv writer and stores it in the chosen charset.
+pub struct EncodedWriter<W: Write> {
+    inner: W,
+    encoding: &'static Encoding,
+    pending: Vec<u8>,
+}
+
+impl<W: Write> EncodedWriter<W> {
+    pub fn new(mut inner: W, label: &str) -> Result<Self> {
+        let encoding = get_encoding(label);
+
+        if label == ENCODING_UTF8_BOM {
+            inner.write_all(b"\xEF\xBB\xBF")?;
+        } else if encoding == UTF_16LE {
+            inner.write_all(b"\xFF\xFE")?;
+        } else if encoding == UTF_16BE {
+            inner.write_all(b"\xFE\xFF")?;
+        }
+
+        Ok(Self {
+            inner,
+            encoding,
+            pending: vec![],
+        })
+    }
+
+    /// Characters the charset does not have fail the write, instead of being written as
+    /// html references that would change the data.
+    fn encode(&self, text: &str) -> Result<Vec<u8>> {
+        if self.encoding == UTF_8 {
+            Ok(text.as_bytes().to_vec())
+        } else if self.encoding == UTF_16LE {
+            Ok(text.encode_utf16().flat_map(u16::to_le_bytes).collect())
+        } else if self.encoding == UTF_16BE {
+            Ok(text.encode_utf16().flat_map(u16::to_be_bytes).collect())
+        } else {
+            let (bytes, _, had_unmappable_characters) = self.encoding.encode(text);
+            if had_unmappable_characters {
+                let character = text
+                    .chars()
+                    .find(|c| self.encoding.encode(c.encode_utf8(&mut [0; 4])).2)
+                    .unwrap_or_default();
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    ERROR_ENCODING_UNMAPPABLE
+                        .replacen("{}", &character.to_string(), 1)
+                        .replacen("{}", self.encoding.name(), 1),
+                ));
+            }
+            Ok(bytes.into_owned())
+        }
+    }
+}
+
+impl<W: Write> Write for EncodedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.pending.extend_from_slice(buf);
+
+        // A multi byte character may be split between two writes
+        let valid = match std::str::from_utf8(&self.pending) {
+            Ok(text) => text.len(),
+            Err(e) => e.valid_up_to(),
+        };
+        let text = String::from_utf8_lossy(&self.pending[..valid]).into_owned();
+        let bytes = self.encode(&text)?;
+        self.inner.write_all(&bytes)?;
+        self.pending.drain(..valid);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.inner.flush()
+    }
+}
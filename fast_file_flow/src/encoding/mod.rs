@@ -0,0 +1,13 @@
+pub mod decoded_reader;
+pub mod encoded_writer;
+
+use crate::constants::english::ENCODING_UTF8_BOM;
+use encoding_rs::{Encoding, UTF_8};
+
+/// Unknown or empty labels fall back to UTF-8.
+pub fn get_encoding(label: &str) -> &'static Encoding {
+    if label == ENCODING_UTF8_BOM {
+        return UTF_8;
+    }
+    Encoding::for_label(label.trim().as_bytes()).unwrap_or(UTF_8)
+}
@@ -1,19 +1,49 @@
-use csv::WriterBuilder;
+pub mod parquet_writer;
+pub mod sqlite_writer;
+
 use futures::stream::StreamExt;
 use rayon::prelude::*;
-use std::{collections::HashMap, fs::remove_file, time::Instant};
-use tokio::fs::File;
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{remove_file, rename, File},
+    path::Path,
+    sync::Arc,
+    time::Instant,
+};
 
 use crate::{
-    constants::english::ERROR_FILE_SAVE,
+    constants::{
+        english::{
+            DIALOG_FILE_EXTENSION_CSV, ERROR_EXPORT, ERROR_FILTER_MATCH_PATTERN, ERROR_PREVIEW,
+        },
+        path::{DATABASE_FORMATS, PARQUET, PARTIAL_EXTENSION},
+    },
     dynamictable::{iced_column::IcedColumn, iced_row::IcedRow, simple_column::SimpleColumn},
-    stored_file::StoredFile,
+    encoding::encoded_writer::EncodedWriter,
+    save_options::filter::range_filter::RangeFilter,
+    stadistics::{data_type::DataType, Stadistics},
+    stored_file::{
+        dialect::Dialect, pivot::PivotTable, query::SqlQuery, schema::ColumnType, StoredFile,
+    },
 };
+use parquet_writer::ParquetWriter;
+use regex::Regex;
+use sqlite_writer::SqliteWriter;
 
 pub struct Export {
     pub stored_file: StoredFile,
     pub simple_column: Vec<SimpleColumn>,
     pub preview: (Vec<IcedColumn>, Vec<IcedRow>),
+    pub output_encoding: String,
+    /// Table written by a sqlite export, the name of the file when empty.
+    pub output_table: String,
+    /// The rows are written in the order of the sort keys of the file.
+    pub sorted: bool,
+    /// The rows of a query are written instead of the ones of the file, the columns are
+    /// the ones of the query.
+    pub query: Option<SqlQuery>,
+    /// The rows of a pivot table are written instead of the ones of the file.
+    pub pivot_table: Option<Arc<PivotTable>>,
     preview_enabled: bool,
     max_preview_rows: usize,
 }
@@ -24,6 +54,11 @@ impl Export {
             simple_column,
             stored_file,
             preview: (vec![], vec![]),
+            output_encoding: String::new(),
+            output_table: String::new(),
+            sorted: false,
+            query: None,
+            pivot_table: None,
             preview_enabled: false,
             max_preview_rows: 70,
         }
@@ -34,36 +69,186 @@ impl Export {
             simple_column: vec![],
             stored_file: StoredFile::default(),
             preview: (vec![], vec![]),
+            output_encoding: String::new(),
+            output_table: String::new(),
+            sorted: false,
+            query: None,
+            pivot_table: None,
             preview_enabled: false,
             max_preview_rows: 0,
         }
     }
 
-    pub async fn save_file(&mut self, file_path: &str) -> String {
+    /// A csv or parquet export is written next to the file and only takes its name once it
+    /// is complete, so a failed or cancelled export leaves nothing behind.
+    pub async fn save_file(&mut self, file_path: &str) -> Result<String, String> {
         self.preview_enabled = false;
 
-        match self
-            .write_csv(file_path.to_string(), self.stored_file.file_path.clone())
+        let extension = StoredFile::get_file_extension(file_path);
+        let result = if DATABASE_FORMATS.contains(&extension.as_str()) {
+            self.write_sqlite(file_path).await
+        } else {
+            let partial_path = format!("{}.{}", file_path, PARTIAL_EXTENSION);
+            let result = match extension == PARQUET {
+                true => self.write_parquet(&partial_path).await,
+                false => {
+                    let dialect = self.stored_file.read_options.dialect.clone();
+                    self.write_csv(
+                        partial_path.clone(),
+                        &dialect,
+                        &self.output_encoding,
+                        HashMap::new(),
+                        HashMap::new(),
+                    )
+                    .await
+                    .map(|_| ())
+                }
+            };
+            match result {
+                Ok(_) => rename(&partial_path, file_path),
+                Err(error) => {
+                    let _ = remove_file(&partial_path);
+                    Err(error)
+                }
+            }
+        };
+
+        match result {
+            Ok(_) => Ok(file_path.to_string()),
+            Err(e) => Err(ERROR_EXPORT.replace("{}", &e.to_string())),
+        }
+    }
+
+    /// Columns whose type was inferred become text when one of their values does not fit
+    /// the type, the values of a type chosen in the schema have to fit it.
+    async fn write_parquet(&self, save_path: &str) -> Result<(), std::io::Error> {
+        let start = Instant::now();
+        let staging_path = format!("{}.{}", save_path, DIALOG_FILE_EXTENSION_CSV);
+        let export_types = self.get_export_types().await?;
+        let column_types = self.get_column_types();
+        let type_checks: HashMap<usize, DataType> = export_types
+            .iter()
+            .filter(|(index, _)| !column_types.contains_key(index))
+            .cloned()
+            .collect();
+
+        let result = match self
+            .write_csv(
+                staging_path.clone(),
+                &Dialect::default(),
+                "",
+                column_types,
+                type_checks,
+            )
             .await
         {
-            Ok(_) => file_path.to_string(),
-            Err(_) => ERROR_FILE_SAVE.to_owned(),
+            Ok((_, _, unfit)) => {
+                let data_types: Vec<DataType> = export_types
+                    .into_iter()
+                    .map(|(index, data_type)| match unfit.contains(&index) {
+                        true => DataType::Text,
+                        false => data_type,
+                    })
+                    .collect();
+                let task = self.stored_file.task.clone();
+                task.start(self.get_total_rows());
+                let csv_path = staging_path.clone();
+                let save_path = save_path.to_string();
+                tokio::task::spawn_blocking(move || {
+                    ParquetWriter::from_csv(&csv_path, &save_path, &data_types, &task)
+                })
+                .await?
+            }
+            Err(e) => Err(e),
+        };
+
+        let _ = remove_file(&staging_path);
+        crate::util::print_timer("Export Parquet", start);
+        result
+    }
+
+    /// The rows are inserted in one transaction, a database created by a failed export is
+    /// removed.
+    async fn write_sqlite(&self, save_path: &str) -> Result<(), std::io::Error> {
+        let start = Instant::now();
+        let is_new = !Path::new(save_path).exists();
+        let staging_path = format!("{}.{}", save_path, DIALOG_FILE_EXTENSION_CSV);
+        let table = match self.output_table.trim() {
+            "" => Path::new(save_path)
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .into_owned(),
+            table => table.to_string(),
+        };
+
+        let result = match self
+            .write_csv(
+                staging_path.clone(),
+                &Dialect::default(),
+                "",
+                self.get_column_types(),
+                HashMap::new(),
+            )
+            .await
+        {
+            Ok(_) => {
+                let data_types: Vec<DataType> = self
+                    .get_export_types()
+                    .await?
+                    .into_iter()
+                    .map(|(_, data_type)| data_type)
+                    .collect();
+                let task = self.stored_file.task.clone();
+                task.start(self.get_total_rows());
+                let csv_path = staging_path.clone();
+                let save_path = save_path.to_string();
+                tokio::task::spawn_blocking(move || {
+                    SqliteWriter::from_csv(&csv_path, &save_path, &table, &data_types, &task)
+                })
+                .await?
+            }
+            Err(e) => Err(e),
+        };
+
+        let _ = remove_file(&staging_path);
+        if result.is_err() && is_new {
+            let _ = remove_file(save_path);
         }
+        crate::util::print_timer("Export SQLite", start);
+        result
     }
 
     async fn write_csv(
         &self,
         save_path: String,
-        open_path: String,
-    ) -> Result<(Vec<String>, Vec<Vec<String>>), std::io::Error> {
+        dialect: &Dialect,
+        output_encoding: &str,
+        column_types: HashMap<usize, ColumnType>,
+        mut type_checks: HashMap<usize, DataType>,
+    ) -> Result<(Vec<String>, Vec<Vec<String>>, HashSet<usize>), std::io::Error> {
         let start = Instant::now();
-        let mut wtr = WriterBuilder::new().from_path(save_path)?;
-
-        let mut rdr = csv_async::AsyncReader::from_reader(File::open(&open_path).await.unwrap());
+        let file = EncodedWriter::new(File::create(save_path)?, output_encoding)?;
+        let mut wtr = dialect.get_writer_builder().from_writer(file);
+
+        let mut records = match (&self.query, &self.pivot_table) {
+            (Some(query), _) => query.get_records(),
+            (None, Some(pivot_table)) => pivot_table.clone().get_records(),
+            (None, None) if self.sorted && !self.stored_file.read_options.sort.is_empty() => {
+                self.stored_file.get_sorted_records().await?
+            }
+            (None, None) => self.stored_file.get_records().await?,
+        };
+        // The preview reads a few rows aside from any task
+        if !self.preview_enabled {
+            records = self.stored_file.task.track(records, self.get_total_rows());
+        }
 
         let columns_ignore = self.get_ignore_column();
         let row_ignore_if_empty = self.get_ignored_row_if_empty_indexes();
         let row_ignore_if_value = self.get_ignored_row_if_value_indexes();
+        let row_keep_if_match = self.get_keep_row_if_match()?;
+        let row_keep_if_range = self.get_range_filters()?;
         let replace_with = self.get_replace_value_with();
         let replace_if_empty = self.get_replace_value_if_empty();
         let replace_with_trim = self.get_do_trim();
@@ -79,25 +264,23 @@ impl Export {
         let mut counter = self.max_preview_rows;
         let preview_enabled = self.preview_enabled;
         let mut preview_rows: Vec<Vec<String>> = vec![];
+        let mut unfit: HashSet<usize> = HashSet::new();
         let headers_clone = headers.clone();
 
         let handle_records = tokio::spawn(async move {
-            let _ = wtr.serialize(&headers_clone);
+            wtr.serialize(&headers_clone)?;
 
-            let mut records = rdr.records();
             let mut row_buffer = Vec::new();
 
             while let Some(record) = records.next().await {
-                let record = record.unwrap();
+                let record = record?;
 
-                let mut values: Vec<(usize, String)> = record
-                    .iter()
-                    .enumerate()
-                    .map(|(i, v)| (i, v.to_string()))
-                    .collect();
+                let mut values: Vec<(usize, String)> = record.into_iter().enumerate().collect();
 
                 if ignore_row_if_empty(&values, &row_ignore_if_empty)
                     || ignore_row_if_value(&values, &row_ignore_if_value)
+                    || !keep_row_if_match(&values, &row_keep_if_match)
+                    || !keep_row_if_range(&values, &row_keep_if_range)?
                 {
                     continue;
                 }
@@ -128,9 +311,24 @@ impl Export {
                             *val = new_value.clone();
                         }
                     }
+
+                    if let Some(column_type) = column_types.get(&index) {
+                        *val = column_type.normalize(val);
+                    }
+
+                    if type_checks.get(&index).is_some_and(|data_type| {
+                        !val.is_empty() && !ParquetWriter::fits(data_type, val)
+                    }) {
+                        type_checks.remove(&index);
+                        unfit.insert(index);
+                    }
                 }
 
-                let finals: Vec<String> = values.into_iter().map(|(_, v)| v).collect();
+                let finals: Vec<String> = values
+                    .into_iter()
+                    .filter(|(index, _)| !columns_ignore.contains(index))
+                    .map(|(_, v)| v)
+                    .collect();
                 if preview_enabled {
                     preview_rows.push(finals.clone());
                     counter -= 1;
@@ -142,24 +340,103 @@ impl Export {
                 row_buffer.push(finals);
                 if row_buffer.len() >= 1000 {
                     for row in row_buffer.drain(..) {
-                        let _ = wtr.serialize(&row);
+                        wtr.serialize(&row)?;
                     }
                 }
             }
 
             for row in row_buffer {
-                let _ = wtr.serialize(&row);
+                wtr.serialize(&row)?;
             }
 
-            _ = wtr.flush();
-            Ok((headers, preview_rows))
+            // A full disk is only found once the buffered rows are written
+            wtr.flush()?;
+            Ok::<_, std::io::Error>((headers, preview_rows, unfit))
         });
 
-        let result = handle_records.await.unwrap();
+        let result = handle_records.await?;
         crate::util::print_timer("Export CSV", start);
         result
     }
 
+    /// The columns are the ones of a query or a pivot table, not the ones of the file.
+    fn is_result(&self) -> bool {
+        self.query.is_some() || self.pivot_table.is_some()
+    }
+
+    fn get_total_rows(&self) -> u64 {
+        match (&self.query, &self.pivot_table) {
+            (Some(query), _) => query.total,
+            (None, Some(pivot_table)) => pivot_table.rows.len() as u64,
+            (None, None) => self.stored_file.rows.total,
+        }
+    }
+
+    /// Types chosen in the schema, to parse the values of a typed export.
+    fn get_column_types(&self) -> HashMap<usize, ColumnType> {
+        if self.is_result() {
+            return HashMap::new();
+        }
+        self.simple_column
+            .iter()
+            .filter_map(|item| {
+                self.stored_file
+                    .get_column_type(item.index)
+                    .map(|column_type| (item.index, column_type.clone()))
+            })
+            .collect()
+    }
+
+    /// Index and type of every exported column, the one chosen in the schema, the one the source
+    /// declares or the one the statistics infer from the loaded rows. The columns of a query
+    /// are typed by the tables they come from and the ones of a pivot table by their values.
+    /// Shared by the parquet and sqlite writers so both give a column the same type.
+    async fn get_export_types(&self) -> Result<Vec<(usize, DataType)>, std::io::Error> {
+        let types: Vec<DataType> = match (&self.query, &self.pivot_table) {
+            (Some(query), _) => query
+                .database
+                .get_columns(&query.sql)
+                .await?
+                .into_iter()
+                .map(|column| column.data_type)
+                .collect(),
+            (None, Some(pivot_table)) => (0..pivot_table.headers.len())
+                .map(|index| {
+                    let values: Vec<String> = pivot_table
+                        .rows
+                        .iter()
+                        .filter_map(|row| row.get(index))
+                        .filter(|value| !value.is_empty())
+                        .cloned()
+                        .collect();
+                    Stadistics::get_column_analysis(&values).1
+                })
+                .collect(),
+            (None, None) => self
+                .stored_file
+                .get_inferred_types()
+                .into_iter()
+                .enumerate()
+                .map(|(index, inferred)| {
+                    self.stored_file
+                        .get_column_type(index)
+                        .map_or(inferred, |column_type| column_type.data_type.clone())
+                })
+                .collect(),
+        };
+
+        let columns_ignore = self.get_ignore_column();
+        Ok(self
+            .simple_column
+            .iter()
+            .filter(|item| !columns_ignore.contains(&item.index))
+            .map(|item| {
+                let data_type = types.get(item.index).cloned().unwrap_or(DataType::Text);
+                (item.index, data_type)
+            })
+            .collect())
+    }
+
     fn get_ignore_column(&self) -> Vec<usize> {
         self.simple_column
             .par_iter()
@@ -189,6 +466,58 @@ impl Export {
             .collect()
     }
 
+    /// The expressions of the columns. Fails on one that is not valid, it would keep every row.
+    pub fn get_keep_row_if_match(&self) -> Result<HashMap<usize, Regex>, std::io::Error> {
+        self.simple_column
+            .iter()
+            .filter(|f| f.save_options.filter.keep_row_if_match)
+            .map(|item| {
+                Regex::new(&item.save_options.filter.keep_row_if_match_text)
+                    .map(|matcher| (item.index, matcher))
+                    .map_err(|e| {
+                        std::io::Error::new(
+                            std::io::ErrorKind::InvalidInput,
+                            ERROR_FILTER_MATCH_PATTERN
+                                .replacen("{}", &item.header, 1)
+                                .replacen("{}", &e.to_string(), 1),
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    /// The comparisons of the columns, parsed as the type of each one. Fails on a column
+    /// that can not be compared or a value of the filter that does not parse.
+    pub fn get_range_filters(&self) -> Result<HashMap<usize, RangeFilter>, std::io::Error> {
+        let columns: Vec<&SimpleColumn> = self
+            .simple_column
+            .iter()
+            .filter(|f| f.save_options.filter.keep_row_if_range)
+            .collect();
+        if columns.is_empty() || self.is_result() {
+            return Ok(HashMap::new());
+        }
+
+        let types = self.stored_file.get_inferred_types();
+        columns
+            .into_iter()
+            .map(|item| {
+                let column_type = self.stored_file.get_column_type(item.index).cloned();
+                let data_type = match &column_type {
+                    Some(column_type) => column_type.data_type.clone(),
+                    None => types.get(item.index).cloned().unwrap_or(DataType::Text),
+                };
+                RangeFilter::new(
+                    &item.header,
+                    &item.save_options.filter,
+                    data_type,
+                    column_type,
+                )
+                .map(|range_filter| (item.index, range_filter))
+            })
+            .collect()
+    }
+
     fn get_replace_value_with(&self) -> HashMap<usize, String> {
         self.simple_column
             .par_iter()
@@ -239,16 +568,23 @@ impl Export {
             .collect()
     }
 
-    pub async fn get_preview(&mut self) -> (Vec<IcedColumn>, Vec<IcedRow>) {
+    /// The first rows as the export writes them, or the error that would stop it.
+    pub async fn get_preview(&mut self) -> Result<(Vec<IcedColumn>, Vec<IcedRow>), String> {
         let start = Instant::now();
         self.preview_enabled = true;
         let save_path = self.stored_file.get_export_path();
-        let (columns, rows) = self
-            .write_csv(save_path.clone(), self.stored_file.file_path.clone())
-            .await
-            .unwrap();
+        let result = self
+            .write_csv(
+                save_path.clone(),
+                &Dialect::default(),
+                "",
+                HashMap::new(),
+                HashMap::new(),
+            )
+            .await;
 
         let _ = remove_file(save_path);
+        let (columns, rows, _) = result.map_err(|e| ERROR_PREVIEW.replace("{}", &e.to_string()))?;
 
         let iced_preview_columns: Vec<IcedColumn> = columns
             .par_iter()
@@ -263,7 +599,7 @@ impl Export {
 
         crate::util::print_timer("Process and Preview", start);
 
-        (iced_preview_columns, iced_preview_rows)
+        Ok((iced_preview_columns, iced_preview_rows))
     }
 }
 
@@ -291,3 +627,29 @@ fn ignore_row_if_value(
         }
     })
 }
+
+fn keep_row_if_match(row: &[(usize, String)], keep_enabled_index: &HashMap<usize, Regex>) -> bool {
+    if keep_enabled_index.is_empty() {
+        return true;
+    }
+
+    row.iter().any(|(i, val)| {
+        keep_enabled_index
+            .get(i)
+            .is_some_and(|matcher| matcher.is_match(val))
+    })
+}
+
+/// Every column with a comparison has to meet it, a missing value is an empty one.
+fn keep_row_if_range(
+    row: &[(usize, String)],
+    keep_enabled_index: &HashMap<usize, RangeFilter>,
+) -> Result<bool, std::io::Error> {
+    for (i, range_filter) in keep_enabled_index {
+        let val = row.get(*i).map_or("", |(_, val)| val.as_str());
+        if !range_filter.is_kept(val)? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
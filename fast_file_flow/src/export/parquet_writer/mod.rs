@@ -0,0 +1,194 @@
+use crate::{
+    constants::english::ERROR_EXPORT_VALUE, stadistics::data_type::DataType,
+    task_progress::TaskProgress,
+};
+use arrow_array::{
+    ArrayRef, Date32Array, Float64Array, Int64Array, RecordBatch, StringArray,
+    TimestampMicrosecondArray,
+};
+use arrow_schema::{DataType as ArrowType, Field, Schema, SchemaRef, TimeUnit};
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use csv::StringRecord;
+use parquet::{
+    arrow::ArrowWriter,
+    basic::{Compression, ZstdLevel},
+    file::properties::WriterProperties,
+};
+use std::{
+    io::{Error, ErrorKind},
+    sync::Arc,
+};
+
+const BATCH_SIZE: usize = 8192;
+const DATE_FORMAT: &str = "%Y-%m-%d";
+// The seconds are optional, as in the values the statistics take for dates and times
+const DATETIME_FORMATS: [&str; 4] = [
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M",
+];
+
+pub struct ParquetWriter {}
+
+impl ParquetWriter {
+    /// Converts an already processed csv into a zstd compressed parquet file, every column
+    /// gets the type given in `data_types` and text when it is unknown. The rows written are
+    /// reported to the task, which is checked between batches.
+    pub fn from_csv(
+        csv_path: &str,
+        save_path: &str,
+        data_types: &[DataType],
+        task: &TaskProgress,
+    ) -> Result<(), Error> {
+        let mut rdr = csv::Reader::from_path(csv_path)?;
+        let schema: SchemaRef = Arc::new(Schema::new(
+            rdr.headers()?
+                .iter()
+                .zip(data_types.iter())
+                .map(|(header, data_type)| Field::new(header, Self::to_arrow_type(data_type), true))
+                .collect::<Vec<Field>>(),
+        ));
+
+        let properties = WriterProperties::builder()
+            .set_compression(Compression::ZSTD(ZstdLevel::default()))
+            .build();
+        let mut writer = ArrowWriter::try_new(
+            std::fs::File::create(save_path)?,
+            schema.clone(),
+            Some(properties),
+        )?;
+
+        let mut rows: Vec<StringRecord> = Vec::with_capacity(BATCH_SIZE);
+        let mut first_row = 1;
+        for record in rdr.records() {
+            rows.push(record?);
+            if rows.len() >= BATCH_SIZE {
+                task.check()?;
+                writer.write(&Self::to_batch(&schema, data_types, &rows, first_row)?)?;
+                task.advance(rows.len() as u64);
+                first_row += rows.len();
+                rows.clear();
+            }
+        }
+
+        if !rows.is_empty() {
+            task.check()?;
+            writer.write(&Self::to_batch(&schema, data_types, &rows, first_row)?)?;
+            task.advance(rows.len() as u64);
+        }
+
+        writer.close()?;
+        Ok(())
+    }
+
+    fn to_arrow_type(data_type: &DataType) -> ArrowType {
+        match data_type {
+            DataType::Integer => ArrowType::Int64,
+            DataType::Float => ArrowType::Float64,
+            DataType::Date => ArrowType::Date32,
+            DataType::DateTime => ArrowType::Timestamp(TimeUnit::Microsecond, None),
+            _ => ArrowType::Utf8,
+        }
+    }
+
+    /// Whether a non empty value can be written in a column of the type.
+    pub fn fits(data_type: &DataType, value: &str) -> bool {
+        match data_type {
+            DataType::Integer => value.parse::<i64>().is_ok(),
+            DataType::Float => value.parse::<f64>().is_ok(),
+            DataType::Date => parse_date(value).is_some(),
+            DataType::DateTime => parse_datetime(value).is_some(),
+            _ => true,
+        }
+    }
+
+    /// Empty values are nulls. A value that does not parse as the type of its column fails
+    /// the export, `first_row` numbers the rows of the batch in the message. The columns
+    /// with an inferred type are checked while the csv is written, so only the types of the
+    /// schema can fail.
+    fn to_batch(
+        schema: &SchemaRef,
+        data_types: &[DataType],
+        rows: &[StringRecord],
+        first_row: usize,
+    ) -> Result<RecordBatch, Error> {
+        let columns: Vec<ArrayRef> = data_types
+            .iter()
+            .enumerate()
+            .map(|(index, data_type)| {
+                let values = rows
+                    .iter()
+                    .map(move |row| row.get(index).filter(|value| !value.is_empty()));
+                let invalid = |row: usize, value: &str| {
+                    Error::new(
+                        ErrorKind::InvalidData,
+                        ERROR_EXPORT_VALUE
+                            .replacen("{}", &(first_row + row).to_string(), 1)
+                            .replacen("{}", schema.field(index).name(), 1)
+                            .replacen("{}", value, 1)
+                            .replacen("{}", data_type.to_string(), 1),
+                    )
+                };
+
+                Ok(match data_type {
+                    DataType::Integer => Arc::new(
+                        parse_values(values, |v| v.parse::<i64>().ok(), invalid)?
+                            .into_iter()
+                            .collect::<Int64Array>(),
+                    ) as ArrayRef,
+                    DataType::Float => Arc::new(
+                        parse_values(values, |v| v.parse::<f64>().ok(), invalid)?
+                            .into_iter()
+                            .collect::<Float64Array>(),
+                    ),
+                    DataType::Date => Arc::new(
+                        parse_values(values, |v| parse_date(v).map(days_since_epoch), invalid)?
+                            .into_iter()
+                            .collect::<Date32Array>(),
+                    ),
+                    DataType::DateTime => Arc::new(
+                        parse_values(
+                            values,
+                            |v| parse_datetime(v).map(|v| v.and_utc().timestamp_micros()),
+                            invalid,
+                        )?
+                        .into_iter()
+                        .collect::<TimestampMicrosecondArray>(),
+                    ),
+                    _ => Arc::new(values.collect::<StringArray>()),
+                })
+            })
+            .collect::<Result<Vec<ArrayRef>, Error>>()?;
+
+        RecordBatch::try_new(schema.clone(), columns).map_err(Error::other)
+    }
+}
+
+fn parse_values<'a, T>(
+    values: impl Iterator<Item = Option<&'a str>>,
+    parse: impl Fn(&str) -> Option<T>,
+    invalid: impl Fn(usize, &str) -> Error,
+) -> Result<Vec<Option<T>>, Error> {
+    values
+        .enumerate()
+        .map(|(row, value)| match value {
+            Some(value) => parse(value).map(Some).ok_or_else(|| invalid(row, value)),
+            None => Ok(None),
+        })
+        .collect()
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, DATE_FORMAT).ok()
+}
+
+fn parse_datetime(value: &str) -> Option<NaiveDateTime> {
+    DATETIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+}
+
+fn days_since_epoch(date: NaiveDate) -> i32 {
+    (date - DateTime::UNIX_EPOCH.date_naive()).num_days() as i32
+}
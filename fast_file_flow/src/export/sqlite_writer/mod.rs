@@ -0,0 +1,140 @@
+use crate::{stadistics::data_type::DataType, task_progress::TaskProgress};
+use rusqlite::{params_from_iter, types::Value, Connection};
+use std::{collections::HashSet, io::Error};
+
+/// Rows inserted between two checks of the task.
+const INSERT_BATCH_ROWS: usize = 8192;
+
+pub struct SqliteWriter {}
+
+impl SqliteWriter {
+    /// Inserts an already processed csv into a table of a sqlite database, in one transaction.
+    /// A new table is created with the types given in `data_types`; an existing one gets the
+    /// columns it is missing. A cancelled task rolls the transaction back.
+    pub fn from_csv(
+        csv_path: &str,
+        save_path: &str,
+        table: &str,
+        data_types: &[DataType],
+        task: &TaskProgress,
+    ) -> Result<(), Error> {
+        let mut rdr = csv::Reader::from_path(csv_path)?;
+        let headers: Vec<String> = rdr.headers()?.iter().map(str::to_string).collect();
+
+        let mut connection = Connection::open(save_path).map_err(Error::other)?;
+        let transaction = connection.transaction().map_err(Error::other)?;
+        Self::prepare_table(&transaction, table, &headers, data_types)?;
+
+        {
+            let insert = format!(
+                "INSERT INTO {} ({}) VALUES ({})",
+                quote(table),
+                headers
+                    .iter()
+                    .map(|h| quote(h))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                vec!["?"; headers.len()].join(", ")
+            );
+            let mut statement = transaction.prepare(&insert).map_err(Error::other)?;
+            let mut rows = 0;
+            for (row, record) in rdr.records().enumerate() {
+                if row % INSERT_BATCH_ROWS == 0 {
+                    task.set_done(row as u64);
+                    task.check()?;
+                }
+                let record = record?;
+                let values = data_types
+                    .iter()
+                    .zip(record.iter())
+                    .map(|(data_type, value)| Self::to_value(data_type, value));
+                statement
+                    .execute(params_from_iter(values))
+                    .map_err(Error::other)?;
+                rows = row + 1;
+            }
+            task.set_done(rows as u64);
+        }
+
+        transaction.commit().map_err(Error::other)
+    }
+
+    fn prepare_table(
+        connection: &Connection,
+        table: &str,
+        headers: &[String],
+        data_types: &[DataType],
+    ) -> Result<(), Error> {
+        // Column names are case insensitive in sqlite
+        let existing: HashSet<String> = connection
+            .prepare(&format!("PRAGMA table_info({})", quote(table)))
+            .and_then(|mut statement| {
+                statement
+                    .query_map([], |row| row.get::<_, String>(1))?
+                    .map(|name| name.map(|name| name.to_lowercase()))
+                    .collect()
+            })
+            .map_err(Error::other)?;
+
+        let columns: Vec<String> = headers
+            .iter()
+            .zip(data_types)
+            .filter(|(header, _)| !existing.contains(&header.to_lowercase()))
+            .map(|(header, data_type)| {
+                format!("{} {}", quote(header), Self::to_sql_type(data_type))
+            })
+            .collect();
+
+        let statements = if existing.is_empty() {
+            vec![format!(
+                "CREATE TABLE {} ({})",
+                quote(table),
+                columns.join(", ")
+            )]
+        } else {
+            columns
+                .iter()
+                .map(|column| format!("ALTER TABLE {} ADD COLUMN {}", quote(table), column))
+                .collect()
+        };
+
+        for statement in statements {
+            connection.execute(&statement, []).map_err(Error::other)?;
+        }
+        Ok(())
+    }
+
+    pub fn to_sql_type(data_type: &DataType) -> &str {
+        match data_type {
+            DataType::Integer => "INTEGER",
+            DataType::Float => "REAL",
+            DataType::Date => "DATE",
+            DataType::Time => "TIME",
+            DataType::DateTime => "DATETIME",
+            _ => "TEXT",
+        }
+    }
+
+    /// Empty values are stored as NULL, values that do not fit the column type as text.
+    pub fn to_value(data_type: &DataType, value: &str) -> Value {
+        if value.is_empty() {
+            return Value::Null;
+        }
+
+        match data_type {
+            DataType::Integer => match value.parse::<i64>() {
+                Ok(number) => Value::Integer(number),
+                Err(_) => Value::Text(value.to_string()),
+            },
+            DataType::Float => match value.parse::<f64>() {
+                Ok(number) => Value::Real(number),
+                Err(_) => Value::Text(value.to_string()),
+            },
+            _ => Value::Text(value.to_string()),
+        }
+    }
+}
+
+pub fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
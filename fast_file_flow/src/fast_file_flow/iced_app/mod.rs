@@ -1,21 +1,43 @@
 use crate::ai::AiModel;
 use crate::constants::english;
 use crate::constants::english::*;
-use crate::constants::path::CSV;
 use crate::constants::path::DBSCAN_IMAGE_RESULT;
-use crate::constants::path::FFFLOW;
 use crate::constants::path::KMEANS_IMAGE_RESULT;
 use crate::constants::path::LR_IMAGE_RESULT;
 use crate::constants::path::PCA_IMAGE_RESULT;
+use crate::constants::sizes::{
+    FILE_WATCH_INTERVAL_MS, QUERY_ROWS_MAX, SEARCH_BATCH_ROWS, SEARCH_MATCHES_MAX,
+    TABLE_ROW_HEIGHT, TABLE_SCROLL_STEP, TABLE_WINDOW_ROWS,
+};
 use crate::correlation_analysis::CorrelationAnalysis;
 use crate::dynamictable::simple_column::SimpleColumn;
-use crate::export::Export;
+use crate::save_options::filter::comparison::Comparison;
+use crate::save_options::filter::unparsed_policy::UnparsedPolicy;
 use crate::save_options::option_type::OptionType;
 use crate::save_options::SaveOptions;
 use crate::stadistics::data_classification::DataClassification;
+use crate::stadistics::data_type::DataType;
 use crate::stadistics::Stadistics;
+use crate::stored_file::dialect::line_terminator::LineTerminator;
+use crate::stored_file::dialect::Dialect;
+use crate::stored_file::file_stamp::FileChange;
+use crate::stored_file::file_type::FileType;
+use crate::stored_file::join::join_type::JoinType;
+use crate::stored_file::join::Join;
+use crate::stored_file::pivot::aggregation::Aggregation;
+use crate::stored_file::pivot::date_group::DateGroup;
+use crate::stored_file::pivot::{Pivot, PivotValue};
+use crate::stored_file::query::get_file_table_name;
+use crate::stored_file::read_options::ReadOptions;
+use crate::stored_file::row_policy::RowPolicy;
+use crate::stored_file::sampling::sample_mode::SampleMode;
+use crate::stored_file::schema::ColumnType;
+use crate::stored_file::search::{Search, SearchCursor};
+use crate::stored_file::sort::SortKey;
 use crate::stored_file::StoredFile;
-use crate::util::print_timer;
+use crate::task_progress::TaskProgress;
+use iced::event;
+use iced::mouse;
 use iced::widget::combo_box;
 use iced::widget::scrollable;
 use iced::Subscription;
@@ -23,6 +45,7 @@ use iced::{Command, Element};
 use iced_futures::subscription;
 use iced_widget::text_editor::Content;
 use native_dialog::FileDialog;
+use num_format::{Locale, ToFormattedString};
 use std::time::Duration;
 use std::time::Instant;
 
@@ -30,6 +53,13 @@ use super::FastFileFlow;
 use super::FastFileFlowMessage;
 use super::Page;
 
+/// Where the search of the subscription is at.
+enum SearchState {
+    Start(Box<StoredFile>, Search),
+    Reading(SearchCursor),
+    Done,
+}
+
 impl iced::Application for FastFileFlow {
     type Executor = iced::executor::Default;
     type Flags = ();
@@ -45,7 +75,39 @@ impl iced::Application for FastFileFlow {
     }
 
     fn update(&mut self, message: Self::Message) -> Command<Self::Message> {
-        self.notification_message = String::from("");
+        // What a cancelled task still returns is left out, the state before it is kept
+        let is_cancelled_result = match &message {
+            FastFileFlowMessage::SetSelectedFile(selected_file) => {
+                selected_file.task.is_cancelled()
+            }
+            FastFileFlowMessage::SetStadisticsFile(..)
+            | FastFileFlowMessage::SetCorrelationFile(_)
+            | FastFileFlowMessage::AnalysisCompleted(_)
+            | FastFileFlowMessage::ExportCompletedEvent(_)
+            | FastFileFlowMessage::AICompleted(..)
+            | FastFileFlowMessage::SetProfile(_)
+            | FastFileFlowMessage::SetSample(_)
+            | FastFileFlowMessage::SearchBatch(_)
+            | FastFileFlowMessage::SetSortedFile(_)
+            | FastFileFlowMessage::QueryCompleted(_)
+            | FastFileFlowMessage::SetPivotTable(_) => self.task.is_cancelled(),
+            _ => false,
+        };
+        if is_cancelled_result {
+            return Command::none();
+        }
+
+        // The file watch runs in the background, the message shown is only replaced when
+        // the file changed
+        let is_file_watch = matches!(
+            message,
+            FastFileFlowMessage::WatchFileTick()
+                | FastFileFlowMessage::FileChanged(_)
+                | FastFileFlowMessage::SetTailedFile(_)
+        );
+        if !is_file_watch {
+            self.notification_message = String::from("");
+        }
 
         match message {
             FastFileFlowMessage::TextBoxChange(search_value) => {
@@ -72,46 +134,15 @@ impl iced::Application for FastFileFlow {
 
                 #[allow(unused_assignments)]
                 let mut path = String::from("");
+                let mut read_options = ReadOptions::default();
                 if is_refresh {
                     path = String::from(self.file_loaded.clone());
+                    read_options = self.selected_file.read_options.clone();
                 } else {
                     path = crate::dialog::load_csv();
                 }
 
-                if !path.is_empty() {
-                    let extension = StoredFile::get_file_extension(&path);
-
-                    if extension == CSV {
-                        self.file_loaded = path.clone();
-                        Command::perform(
-                            async move {
-                                let start = Instant::now();
-                                let file = StoredFile::new(path.clone()).await;
-                                print_timer("File Loading", start);
-                                file
-                            },
-                            |stored_file| FastFileFlowMessage::SetSelectedFile(stored_file),
-                        )
-                    } else if extension == FFFLOW {
-                        match self.load_from_file(path.as_str()) {
-                            Ok(_) => Command::perform(async move {}, |_| {
-                                FastFileFlowMessage::SetLoadedProject()
-                            }),
-                            Err(_) => {
-                                self.enable_loading(false);
-                                self.set_error(&ERROR_PROJECT_INVALID.to_owned());
-                                self.reset_state();
-                                Command::none()
-                            }
-                        }
-                    } else {
-                        Command::none()
-                    }
-                } else {
-                    self.enable_loading(false);
-                    self.set_file_not_found_error();
-                    Command::none()
-                }
+                self.get_load_message(path, read_options)
             }
 
             FastFileFlowMessage::Tick(progress) => {
@@ -120,24 +151,89 @@ impl iced::Application for FastFileFlow {
                 }
                 Command::none()
             }
+            FastFileFlowMessage::TaskCancelClick() => {
+                self.task.cancel();
+                // The matches found so far stay in the table
+                self.searching = false;
+                self.enable_loading(false);
+                self.selected_file.task = TaskProgress::default();
+                // A file that was being opened leaves the one loaded before
+                self.file_loaded = self.selected_file.get_load_path().to_string();
+                self.notification_message = INFORMATIVE_TASK_CANCELLED.to_string();
+                Command::none()
+            }
 
             FastFileFlowMessage::SetSelectedFile(selected_file) => {
-                if selected_file.sintaxis.clone() != crate::stored_file::file_type::FileType::CSV {
-                    self.notification_message = format!(
-                        "File sintaxis {}, it is not supported yet, please use a valid csv",
-                        &selected_file.sintaxis.to_string()
-                    )
-                    .to_string();
+                if !selected_file.is_supported() {
+                    self.notification_message = selected_file.get_unsupported_error();
                 }
+                let is_load_failed = selected_file.load_error.is_some();
+                let is_new_workbook = selected_file.sintaxis == FileType::Excel
+                    && selected_file.is_supported()
+                    && selected_file.file_path != self.selected_file.file_path;
+                let is_new_database = selected_file.sintaxis == FileType::SQLite
+                    && selected_file.is_supported()
+                    && selected_file.file_path != self.selected_file.file_path;
                 self.reset_state();
                 self.rows = selected_file.rows.sample.clone();
                 self.columns = selected_file.columns.headers.clone();
                 self.column_options = selected_file.get_simple_columns();
                 self.column_options_state = combo_box::State::new(self.column_options.clone());
 
+                // The fail policy stops at the first bad row and loads no rows
+                let malformed_row = match selected_file.rows.total {
+                    0 => selected_file.rejected_rows.rows.first().cloned(),
+                    _ => None,
+                };
+
+                let dataset = selected_file.dataset.clone();
+                let is_empty_dataset = selected_file.is_dataset() && dataset.sources.is_empty();
+                let join_file = selected_file.read_options.join.file_path.clone();
+                let is_join_failed = !join_file.is_empty() && selected_file.join_table.is_none();
+
                 self.selected_file = selected_file;
+                self.set_read_options();
+                if let Some(malformed_row) = malformed_row {
+                    self.set_error(&ERROR_MALFORMED_ROW.replace("{}", &malformed_row.to_string()));
+                    self.router(Page::Rejected);
+                } else if is_empty_dataset {
+                    self.set_error(&ERROR_DATASET_EMPTY.replace("{}", &dataset.path));
+                    self.router(Page::Dataset);
+                } else if !dataset.mismatches.is_empty() {
+                    self.set_error(
+                        &ERROR_DATASET_MISMATCH
+                            .replace("{}", &dataset.mismatches.len().to_string()),
+                    );
+                    self.router(Page::Dataset);
+                } else if is_join_failed {
+                    self.set_error(&ERROR_JOIN_FILE.replace("{}", &join_file));
+                    self.router(Page::Join);
+                } else if is_load_failed {
+                    // The page of the options the file failed with stays open to change them
+                } else if is_new_workbook {
+                    self.router(Page::Workbook);
+                } else if is_new_database {
+                    self.router(Page::Database);
+                } else if [
+                    Page::Workbook,
+                    Page::Database,
+                    Page::Dialect,
+                    Page::Encoding,
+                    Page::Rejected,
+                    Page::Dataset,
+                    Page::Join,
+                    Page::Schema,
+                ]
+                .contains(&self.page)
+                {
+                    self.router(Page::Main);
+                }
                 self.enable_loading(false);
-                Command::none()
+                Command::batch(vec![
+                    self.get_cache_message(),
+                    self.get_sample_message(),
+                    self.get_sort_message(),
+                ])
             }
             FastFileFlowMessage::SetLoadedProject() => {
                 self.selected_file.file_name = StoredFile::get_file_name(&self.file_loaded);
@@ -151,7 +247,13 @@ impl iced::Application for FastFileFlow {
                         self.columns = self.selected_file.columns.headers.clone();
                         self.column_options_state =
                             combo_box::State::new(self.column_options.clone());
+                        self.set_read_options();
                         self.enable_loading(false);
+                        Command::batch(vec![
+                            self.get_cache_message(),
+                            self.get_sample_message(),
+                            self.get_sort_message(),
+                        ])
                     }
                     Err(e) => {
                         self.selected_file = StoredFile::default();
@@ -159,9 +261,9 @@ impl iced::Application for FastFileFlow {
                         self.reset_state();
                         self.set_error(&e.to_string());
                         self.enable_loading(false);
+                        Command::none()
                     }
                 }
-                Command::none()
             }
 
             FastFileFlowMessage::HeaderClicked(column_index) => {
@@ -309,6 +411,11 @@ impl iced::Application for FastFileFlow {
                 Command::none()
             }
             FastFileFlowMessage::ColumnOptionSelected(option) => {
+                let filter = &option.save_options.filter;
+                self.filter_comparison =
+                    Some(filter.keep_row_if_range_comparison.to_string().to_string());
+                self.filter_unparsed =
+                    Some(filter.keep_row_if_range_unparsed.to_string().to_string());
                 self.column_option_selected = Some(option.clone());
                 if option.classification == DataClassification::Unknown {
                     self.get_column_stadistics_message(option.index.clone(), false)
@@ -365,6 +472,36 @@ impl iced::Application for FastFileFlow {
                                 .filter
                                 .ignore_column = checked;
                         }
+                        OptionType::FilterKeepIfMatch => {
+                            self.column_option_selected
+                                .as_mut()
+                                .unwrap()
+                                .save_options
+                                .filter
+                                .keep_row_if_match = checked;
+
+                            self.column_options
+                                .get_mut(index)
+                                .unwrap()
+                                .save_options
+                                .filter
+                                .keep_row_if_match = checked;
+                        }
+                        OptionType::FilterKeepIfRange => {
+                            self.column_option_selected
+                                .as_mut()
+                                .unwrap()
+                                .save_options
+                                .filter
+                                .keep_row_if_range = checked;
+
+                            self.column_options
+                                .get_mut(index)
+                                .unwrap()
+                                .save_options
+                                .filter
+                                .keep_row_if_range = checked;
+                        }
                         _ => {}
                     };
 
@@ -390,6 +527,85 @@ impl iced::Application for FastFileFlow {
                                 .filter
                                 .ignore_row_if_text = value.clone();
                         }
+                        OptionType::FilterKeepIfMatch => {
+                            self.column_option_selected
+                                .as_mut()
+                                .unwrap()
+                                .save_options
+                                .filter
+                                .keep_row_if_match_text = value.clone();
+
+                            self.column_options
+                                .get_mut(index)
+                                .unwrap()
+                                .save_options
+                                .filter
+                                .keep_row_if_match_text = value.clone();
+                        }
+                        OptionType::FilterKeepIfRange => {
+                            self.column_option_selected
+                                .as_mut()
+                                .unwrap()
+                                .save_options
+                                .filter
+                                .keep_row_if_range_from = value.clone();
+
+                            self.column_options
+                                .get_mut(index)
+                                .unwrap()
+                                .save_options
+                                .filter
+                                .keep_row_if_range_from = value.clone();
+                        }
+                        OptionType::FilterRangeTo => {
+                            self.column_option_selected
+                                .as_mut()
+                                .unwrap()
+                                .save_options
+                                .filter
+                                .keep_row_if_range_to = value.clone();
+
+                            self.column_options
+                                .get_mut(index)
+                                .unwrap()
+                                .save_options
+                                .filter
+                                .keep_row_if_range_to = value.clone();
+                        }
+                        OptionType::FilterRangeComparison => {
+                            let comparison = Comparison::from_string(&value);
+                            self.column_option_selected
+                                .as_mut()
+                                .unwrap()
+                                .save_options
+                                .filter
+                                .keep_row_if_range_comparison = comparison.clone();
+
+                            self.column_options
+                                .get_mut(index)
+                                .unwrap()
+                                .save_options
+                                .filter
+                                .keep_row_if_range_comparison = comparison.clone();
+                            self.filter_comparison = Some(value.clone());
+                        }
+                        OptionType::FilterRangeUnparsed => {
+                            let unparsed = UnparsedPolicy::from_string(&value);
+                            self.column_option_selected
+                                .as_mut()
+                                .unwrap()
+                                .save_options
+                                .filter
+                                .keep_row_if_range_unparsed = unparsed.clone();
+
+                            self.column_options
+                                .get_mut(index)
+                                .unwrap()
+                                .save_options
+                                .filter
+                                .keep_row_if_range_unparsed = unparsed.clone();
+                            self.filter_unparsed = Some(value.clone());
+                        }
                         _ => {}
                     };
                     self.column_options_state = combo_box::State::new(self.column_options.clone());
@@ -536,13 +752,18 @@ impl iced::Application for FastFileFlow {
             }
             FastFileFlowMessage::ShowPreviewButtonClick() => {
                 if self.is_file_loaded() {
-                    let mut export_file =
-                        Export::new(self.selected_file.clone(), self.column_options.clone());
+                    let mut export_file = self.get_export();
+                    if let Err(e) = export_file
+                        .get_keep_row_if_match()
+                        .and_then(|_| export_file.get_range_filters())
+                    {
+                        self.set_error(&e.to_string());
+                        self.enable_loading(false);
+                        return Command::none();
+                    }
                     Command::perform(
                         async move { export_file.get_preview().await },
-                        |(preview_headers, preview_rows)| {
-                            FastFileFlowMessage::PreviewCompleted(preview_headers, preview_rows)
-                        },
+                        FastFileFlowMessage::PreviewCompleted,
                     )
                 } else {
                     self.set_file_not_found_error();
@@ -573,20 +794,69 @@ impl iced::Application for FastFileFlow {
                 Command::none()
             }
             FastFileFlowMessage::ExportButtonClick() => {
+                if self.is_file_loaded() {
+                    self.router(Page::Export);
+                } else {
+                    self.set_file_not_found_error();
+                }
+                Command::none()
+            }
+            FastFileFlowMessage::ExportEncodingSelected(encoding) => {
+                self.export_encoding = Some(encoding);
+                Command::none()
+            }
+            FastFileFlowMessage::ExportSortedToggled(value) => {
+                self.export_sorted = value;
+                Command::none()
+            }
+            FastFileFlowMessage::ExportQueryToggled(value) => {
+                self.export_query = value;
+                if value {
+                    self.export_pivot = false;
+                }
+                Command::none()
+            }
+            FastFileFlowMessage::ExportTableChange(table) => {
+                self.export_table = table;
+                Command::none()
+            }
+            FastFileFlowMessage::ExportSaveClick() => {
                 self.enable_loading(true);
                 if self.is_file_loaded() {
+                    let mut export_file = self.get_export();
+                    if let Err(e) = export_file
+                        .get_keep_row_if_match()
+                        .and_then(|_| export_file.get_range_filters())
+                    {
+                        self.set_error(&e.to_string());
+                        self.enable_loading(false);
+                        return Command::none();
+                    }
                     if let Some(path) = FileDialog::new()
                         .add_filter(
                             english::DIALOG_FILE_EXTENSION_CSV,
                             &[DIALOG_FILE_EXTENSION_CSV],
                         )
+                        .add_filter(
+                            english::DIALOG_FILE_EXTENSION_PARQUET,
+                            &[DIALOG_FILE_EXTENSION_PARQUET],
+                        )
+                        .add_filter(
+                            english::DIALOG_FILE_EXTENSION_SQLITE,
+                            &[
+                                DIALOG_FILE_EXTENSION_SQLITE,
+                                DIALOG_FILE_EXTENSION_SQLITE3,
+                                DIALOG_FILE_EXTENSION_DB,
+                            ],
+                        )
                         .set_filename(format!(".{}", DIALOG_FILE_EXTENSION_CSV).as_str())
                         .show_save_single_file()
                         .ok()
                         .flatten()
                     {
-                        let mut export_file =
-                            Export::new(self.selected_file.clone(), self.column_options.clone());
+                        export_file.output_encoding =
+                            self.export_encoding.clone().unwrap_or_default();
+                        export_file.output_table = self.export_table.clone();
                         Command::perform(
                             async move { export_file.save_file(path.to_str().unwrap()).await },
                             |saved_file| FastFileFlowMessage::ExportCompletedEvent(saved_file),
@@ -603,11 +873,128 @@ impl iced::Application for FastFileFlow {
             }
             FastFileFlowMessage::ExportCompletedEvent(file_saved) => {
                 self.enable_loading(false);
-                self.notification_message = format!("File Saved: {file_saved}");
+                match file_saved {
+                    Ok(file_saved) => {
+                        self.router(Page::Main);
+                        self.notification_message = format!("File Saved: {file_saved}");
+                    }
+                    // The export stays open to try again
+                    Err(e) => self.set_error(&e),
+                }
                 Command::none()
             }
             FastFileFlowMessage::SearchOnSubmit() => {
-                self.clicked_button = String::from("Search On Submit");
+                if !self.is_file_loaded() {
+                    self.set_file_not_found_error();
+                    return Command::none();
+                }
+                self.clear_search();
+                let Some(search) = self.get_search() else {
+                    self.rows = self.get_file_rows();
+                    self.rows_offset = 0;
+                    return self.move_table_cursor(0);
+                };
+                if let Err(e) = search.get_matcher() {
+                    self.set_error(&ERROR_SEARCH_PATTERN.replace("{}", &e.to_string()));
+                    return Command::none();
+                }
+
+                self.search_counts = vec![0; self.columns.len()];
+                self.search = Some(search);
+                self.rows = vec![];
+                self.rows_offset = 0;
+                self.row_cursor = 0;
+                self.fetching_window = false;
+                self.searching = true;
+                self.enable_loading(true);
+                self.router(Page::Main);
+                Command::none()
+            }
+            FastFileFlowMessage::SearchColumnsClick() => {
+                if self.is_file_loaded() {
+                    self.router(Page::Search);
+                } else {
+                    self.set_file_not_found_error();
+                }
+                Command::none()
+            }
+            FastFileFlowMessage::SearchColumnToggled(header, checked) => {
+                self.search_columns.retain(|column| column != &header);
+                if checked {
+                    self.search_columns.push(header);
+                }
+                Command::none()
+            }
+            FastFileFlowMessage::SearchPreviousClick() => self.move_search_match(-1),
+            FastFileFlowMessage::SearchNextClick() => self.move_search_match(1),
+            FastFileFlowMessage::SearchToFilterClick() => {
+                if let Some(search) = self.search.clone() {
+                    self.set_search_filter(&search);
+                    self.notification_message = INFORMATIVE_SEARCH_FILTER.to_string();
+                }
+                Command::none()
+            }
+            FastFileFlowMessage::SearchCaseSensitiveToggled(value) => {
+                self.search_case_sensitive = value;
+                Command::none()
+            }
+            FastFileFlowMessage::SearchWholeWordToggled(value) => {
+                self.search_whole_word = value;
+                Command::none()
+            }
+            FastFileFlowMessage::SearchRegexToggled(value) => {
+                self.search_regex = value;
+                Command::none()
+            }
+            FastFileFlowMessage::SearchClearClick() => {
+                self.search_value = String::from("");
+                self.clear_search();
+                self.rows = self.get_file_rows();
+                self.rows_offset = 0;
+                self.move_table_cursor(0)
+            }
+            FastFileFlowMessage::SearchBatch(batch) => {
+                // The search was cleared or cancelled while the rows were read
+                if !self.searching {
+                    return Command::none();
+                }
+
+                match batch {
+                    Some(Ok(mut matches)) => {
+                        let left = SEARCH_MATCHES_MAX.saturating_sub(self.search_rows.len());
+                        let is_full = matches.len() >= left;
+                        matches.truncate(left);
+                        for row in matches.iter() {
+                            for column in row.highlighted.iter() {
+                                if let Some(count) = self.search_counts.get_mut(*column) {
+                                    *count += 1;
+                                }
+                            }
+                        }
+                        // The preview keeps its rows until it is closed
+                        if !self.is_result_table() {
+                            self.rows.extend(matches.iter().cloned());
+                        }
+                        self.search_rows.extend(matches);
+                        if is_full {
+                            self.searching = false;
+                            self.enable_loading(false);
+                            self.notification_message = INFORMATIVE_SEARCH_LIMIT.replace(
+                                "{}",
+                                &SEARCH_MATCHES_MAX.to_formatted_string(&Locale::en),
+                            );
+                        }
+                    }
+                    Some(Err(e)) => {
+                        self.searching = false;
+                        self.enable_loading(false);
+                        self.set_error(&ERROR_SEARCH.replace("{}", &e));
+                    }
+                    None => {
+                        self.searching = false;
+                        self.enable_loading(false);
+                    }
+                }
                 Command::none()
             }
             FastFileFlowMessage::SyncHeader(offset) => {
@@ -630,11 +1017,21 @@ impl iced::Application for FastFileFlow {
                 });
                 Command::none()
             }
-            FastFileFlowMessage::PreviewCompleted(headers, rows) => {
+            FastFileFlowMessage::PreviewCompleted(preview) => {
+                let (headers, rows) = match preview {
+                    Ok(preview) => preview,
+                    Err(e) => {
+                        self.set_error(&e);
+                        self.enable_loading(false);
+                        return Command::none();
+                    }
+                };
                 self.columns_backup = self.columns.clone();
                 self.header_checked_backup = self.header_checked.clone();
                 self.columns = headers;
                 self.rows = rows;
+                self.rows_offset = 0;
+                self.row_cursor = 0;
                 self.router(Page::Preview);
                 Command::none()
             }
@@ -738,7 +1135,9 @@ impl iced::Application for FastFileFlow {
                 self.columns_backup = vec![];
                 self.header_checked = self.header_checked_backup.clone();
                 self.header_checked_backup = vec![];
-                self.rows = self.selected_file.rows.sample.to_owned();
+                self.rows = self.get_file_rows();
+                self.rows_offset = 0;
+                self.row_cursor = 0;
                 self.router(Page::Main);
                 Command::none()
             }
@@ -746,36 +1145,1067 @@ impl iced::Application for FastFileFlow {
                 self.result_content.perform(action);
                 Command::none()
             }
-        }
-    }
+            FastFileFlowMessage::TableWheelScrolled(rows) => {
+                if self.page != Page::Main && !self.is_result_table() {
+                    return Command::none();
+                }
 
-    fn view(&self) -> Element<'_, Self::Message> {
-        match self.page {
-            Page::Main => self.show_main_screen(),
-            Page::Filter => self.show_filter_screen(),
-            Page::Process => self.show_process_screen(),
-            Page::AI => self.show_ai_screen(),
-            Page::Preview => self.show_preview_screen(),
-            Page::UserAboutIt => self.show_user_screen(),
-        }
-    }
+                self.row_scroll_delta += rows;
+                let step = self.row_scroll_delta.trunc();
+                self.row_scroll_delta -= step;
 
-    fn theme(&self) -> Self::Theme {
-        self.theme.clone()
-    }
+                if step < 0.0 {
+                    self.move_table_cursor(self.row_cursor.saturating_sub(-step as u64))
+                } else if step > 0.0 {
+                    self.move_table_cursor(self.row_cursor + step as u64)
+                } else {
+                    Command::none()
+                }
+            }
+            FastFileFlowMessage::TableSliderChanged(row_index) => {
+                self.row_cursor = row_index as u64;
+                Command::none()
+            }
+            FastFileFlowMessage::TableSliderReleased() => self.move_table_cursor(self.row_cursor),
+            FastFileFlowMessage::GoToRowChange(value) => {
+                self.go_to_row = value;
+                Command::none()
+            }
+            FastFileFlowMessage::GoToRowSubmit() => match self.go_to_row.trim().parse::<u64>() {
+                Ok(row_number) if row_number > 0 => self.move_table_cursor(row_number - 1),
+                _ => {
+                    self.set_error(ERROR_INVALID_ROW);
+                    Command::none()
+                }
+            },
+            FastFileFlowMessage::SetTableWindow(start, rows) => {
+                if !self.fetching_window {
+                    return Command::none();
+                }
+                self.fetching_window = false;
 
-    fn subscription(&self) -> Subscription<Self::Message> {
-        if self.running {
-            subscription::unfold("progress", self.progress, move |progress| async move {
-                tokio::time::sleep(Duration::from_millis(50)).await;
-                let mut new_progress = progress + 1.0 as f32;
-                if new_progress == 100.0 {
-                    new_progress = 1.0 as f32;
-                }
-                (FastFileFlowMessage::Tick(new_progress), new_progress)
-            })
-        } else {
-            Subscription::none()
-        }
+                match rows {
+                    Ok(rows) => {
+                        self.rows_offset = start;
+                        self.rows = rows;
+                        self.get_table_window_message()
+                    }
+                    Err(e) => {
+                        self.set_error(&e);
+                        Command::none()
+                    }
+                }
+            }
+
+            FastFileFlowMessage::WorkbookSheetSelected(sheet) => {
+                self.workbook_sheet = Some(sheet);
+                Command::none()
+            }
+            FastFileFlowMessage::WorkbookHeaderRowChange(value) => {
+                self.workbook_header_row = value;
+                Command::none()
+            }
+            FastFileFlowMessage::WorkbookApplyClick() => {
+                let header_row = match self.workbook_header_row.trim().parse::<usize>() {
+                    Ok(row) if row > 0 => row - 1,
+                    _ => {
+                        self.set_error(ERROR_INVALID_ROW);
+                        return Command::none();
+                    }
+                };
+                let Some(sheet) = self.workbook_sheet.clone() else {
+                    self.set_error(ERROR_WORKBOOK_SHEET);
+                    return Command::none();
+                };
+
+                let mut read_options = self.selected_file.read_options.clone();
+                read_options.sheet = sheet;
+                read_options.header_row = header_row;
+                self.get_reload_message(read_options)
+            }
+
+            FastFileFlowMessage::DatabaseTableSelected(table) => {
+                self.database_table = Some(table);
+                self.database_query = String::from("");
+                Command::none()
+            }
+            FastFileFlowMessage::DatabaseQueryChange(value) => {
+                self.database_query = value;
+                Command::none()
+            }
+            FastFileFlowMessage::DatabaseApplyClick() => {
+                let query = self.database_query.trim().to_string();
+                let mut read_options = self.selected_file.read_options.clone();
+                if !query.is_empty() {
+                    read_options.query = query;
+                } else if let Some(table) = self.database_table.clone() {
+                    read_options.sheet = table;
+                    read_options.query = String::from("");
+                } else {
+                    self.set_error(ERROR_DATABASE_SOURCE);
+                    return Command::none();
+                }
+                self.get_reload_message(read_options)
+            }
+
+            FastFileFlowMessage::DialectDelimiterChange(value) => {
+                self.dialect_delimiter = value;
+                Command::none()
+            }
+            FastFileFlowMessage::DialectQuoteChange(value) => {
+                self.dialect_quote = value;
+                Command::none()
+            }
+            FastFileFlowMessage::DialectEscapeChange(value) => {
+                self.dialect_escape = value;
+                Command::none()
+            }
+            FastFileFlowMessage::DialectTerminatorSelected(value) => {
+                self.dialect_terminator = Some(value);
+                Command::none()
+            }
+            FastFileFlowMessage::CacheEnabledToggled(is_checked) => {
+                self.cache_enabled = is_checked;
+                Command::none()
+            }
+            FastFileFlowMessage::CacheOnLoadToggled(is_checked) => {
+                self.cache_on_load = is_checked;
+                Command::none()
+            }
+            FastFileFlowMessage::CacheBudgetChange(value) => {
+                self.cache_budget = value;
+                Command::none()
+            }
+            FastFileFlowMessage::CacheApplyClick() => {
+                match self.cache_budget.trim().parse::<usize>() {
+                    Ok(budget) if budget > 0 => {
+                        self.router(Page::Main);
+                        self.get_cache_message()
+                    }
+                    _ => {
+                        self.set_error(ERROR_CACHE_BUDGET);
+                        Command::none()
+                    }
+                }
+            }
+            FastFileFlowMessage::CacheCompleted() => Command::none(),
+            FastFileFlowMessage::DatasetButtonClick() => {
+                self.dataset_pattern = self.selected_file.dataset.path.clone();
+                self.router(Page::Dataset);
+                Command::none()
+            }
+            FastFileFlowMessage::DatasetPatternChange(pattern) => {
+                self.dataset_pattern = pattern;
+                Command::none()
+            }
+            FastFileFlowMessage::DatasetBrowseClick() => {
+                if let Some(path) = FileDialog::new()
+                    .set_location("~")
+                    .show_open_single_dir()
+                    .ok()
+                    .flatten()
+                {
+                    self.dataset_pattern = path.to_string_lossy().into_owned();
+                }
+                Command::none()
+            }
+            FastFileFlowMessage::DatasetSourceColumnToggled(source_column) => {
+                self.dataset_source_column = source_column;
+                Command::none()
+            }
+            FastFileFlowMessage::JoinBrowseClick() => {
+                let Some(path) = FileDialog::new()
+                    .add_filter(DIALOG_JOIN_FILE_TITLE, &[DIALOG_FILE_EXTENSION_CSV])
+                    .show_open_single_file()
+                    .ok()
+                    .flatten()
+                else {
+                    return Command::none();
+                };
+
+                self.enable_loading(true);
+                let path = path.to_string_lossy().into_owned();
+                Command::perform(
+                    async move {
+                        let join_file = StoredFile::new(path.clone()).await;
+                        let headers = join_file
+                            .columns
+                            .headers
+                            .into_iter()
+                            .map(|column| column.column_header)
+                            .collect();
+                        (path, headers)
+                    },
+                    |(path, headers)| FastFileFlowMessage::JoinFileLoaded(path, headers),
+                )
+            }
+            FastFileFlowMessage::JoinFileLoaded(path, headers) => {
+                self.enable_loading(false);
+                if headers.is_empty() {
+                    self.set_error(&ERROR_JOIN_FILE.replace("{}", &path));
+                    return Command::none();
+                }
+
+                // Same named columns are suggested as the key
+                let left_headers = self.selected_file.get_join_key_headers();
+                self.join_keys = headers
+                    .iter()
+                    .filter(|header| left_headers.contains(header))
+                    .take(1)
+                    .map(|header| (header.clone(), header.clone()))
+                    .collect();
+                self.join_columns = headers
+                    .iter()
+                    .filter(|header| !left_headers.contains(header))
+                    .cloned()
+                    .collect();
+                self.join_right_key = None;
+                self.join_right_key_state = combo_box::State::new(headers.clone());
+                self.join_headers = headers;
+                self.join_file = path;
+                Command::none()
+            }
+            FastFileFlowMessage::JoinTypeSelected(join_type) => {
+                self.join_type = Some(join_type);
+                Command::none()
+            }
+            FastFileFlowMessage::JoinLeftKeySelected(key) => {
+                self.join_left_key = Some(key);
+                Command::none()
+            }
+            FastFileFlowMessage::JoinRightKeySelected(key) => {
+                self.join_right_key = Some(key);
+                Command::none()
+            }
+            FastFileFlowMessage::JoinAddKeyClick() => {
+                if let (Some(left), Some(right)) =
+                    (self.join_left_key.take(), self.join_right_key.take())
+                {
+                    self.join_keys.push((left, right));
+                }
+                Command::none()
+            }
+            FastFileFlowMessage::JoinClearKeysClick() => {
+                self.join_keys.clear();
+                Command::none()
+            }
+            FastFileFlowMessage::JoinColumnToggled(header, checked) => {
+                self.join_columns.retain(|column| *column != header);
+                if checked {
+                    self.join_columns.push(header);
+                }
+                // Columns are brought over in the order of the joined file
+                let join_columns = &self.join_columns;
+                self.join_columns = self
+                    .join_headers
+                    .iter()
+                    .filter(|column| join_columns.contains(column))
+                    .cloned()
+                    .collect();
+                Command::none()
+            }
+            FastFileFlowMessage::JoinApplyClick() => {
+                if self.join_file.is_empty() || self.join_keys.is_empty() {
+                    self.set_error(ERROR_JOIN_KEYS);
+                    return Command::none();
+                }
+
+                let (left_keys, right_keys) = self.join_keys.iter().cloned().unzip();
+                let mut read_options = self.selected_file.read_options.clone();
+                read_options.join = Join::new(
+                    self.join_file.clone(),
+                    JoinType::from_string(&self.join_type.clone().unwrap_or_default()),
+                    left_keys,
+                    right_keys,
+                    self.join_columns.clone(),
+                );
+                self.get_reload_message(read_options)
+            }
+            FastFileFlowMessage::JoinRemoveClick() => {
+                let mut read_options = self.selected_file.read_options.clone();
+                read_options.join = Join::default();
+                self.get_reload_message(read_options)
+            }
+            FastFileFlowMessage::SchemaButtonClick() => {
+                if self.is_file_loaded() {
+                    self.router(Page::Schema);
+                } else {
+                    self.set_file_not_found_error();
+                }
+                Command::none()
+            }
+            FastFileFlowMessage::SchemaColumnSelected(header) => {
+                let column_type = self
+                    .schema_types
+                    .iter()
+                    .find(|column_type| column_type.header == header)
+                    .cloned()
+                    .unwrap_or(ColumnType {
+                        data_type: self
+                            .get_schema_inferred(Some(&header))
+                            .cloned()
+                            .unwrap_or(DataType::Unknown),
+                        ..ColumnType::default()
+                    });
+                self.schema_type = Some(column_type.data_type.to_string().to_string())
+                    .filter(|_| column_type.data_type != DataType::Unknown);
+                self.schema_date_format = column_type.date_format;
+                self.schema_decimal_separator = column_type.decimal_separator.to_string();
+                self.schema_column = Some(header);
+                Command::none()
+            }
+            FastFileFlowMessage::SchemaTypeSelected(data_type) => {
+                self.schema_type = Some(data_type);
+                Command::none()
+            }
+            FastFileFlowMessage::SchemaDateFormatChange(value) => {
+                self.schema_date_format = value;
+                Command::none()
+            }
+            FastFileFlowMessage::SchemaDecimalSeparatorChange(value) => {
+                self.schema_decimal_separator = value;
+                Command::none()
+            }
+            FastFileFlowMessage::SchemaSetClick() => {
+                let data_type = DataType::from_string(self.schema_type.as_deref().unwrap_or(""));
+                let Some(header) = self.schema_column.clone() else {
+                    self.set_error(ERROR_SCHEMA_COLUMN);
+                    return Command::none();
+                };
+                if data_type == DataType::Unknown {
+                    self.set_error(ERROR_SCHEMA_COLUMN);
+                    return Command::none();
+                }
+                let decimal_separator = match self.schema_decimal_separator.trim() {
+                    "" | "." => '.',
+                    "," => ',',
+                    _ => {
+                        self.set_error(ERROR_SCHEMA_DECIMAL);
+                        return Command::none();
+                    }
+                };
+                let date_format = self.schema_date_format.trim().to_string();
+                if !ColumnType::is_valid_date_format(&date_format) {
+                    self.set_error(ERROR_SCHEMA_DATE_FORMAT);
+                    return Command::none();
+                }
+
+                self.schema_types
+                    .retain(|column_type| column_type.header != header);
+                self.schema_types.push(ColumnType::new(
+                    header,
+                    data_type,
+                    date_format,
+                    decimal_separator,
+                ));
+                Command::none()
+            }
+            FastFileFlowMessage::SchemaResetClick() => {
+                if let Some(header) = self.schema_column.clone() {
+                    self.schema_types
+                        .retain(|column_type| column_type.header != header);
+                    return self.update(FastFileFlowMessage::SchemaColumnSelected(header));
+                }
+                Command::none()
+            }
+            FastFileFlowMessage::SchemaClearClick() => {
+                self.schema_types.clear();
+                Command::none()
+            }
+            FastFileFlowMessage::SchemaApplyClick() => {
+                let mut read_options = self.selected_file.read_options.clone();
+                read_options.schema = self.schema_types.clone();
+                self.get_reload_message(read_options)
+            }
+            FastFileFlowMessage::ProfileButtonClick() => {
+                if !self.is_file_loaded() {
+                    self.set_file_not_found_error();
+                    return Command::none();
+                }
+                if self.profile.is_empty() {
+                    return self.get_profile_message();
+                }
+                self.router(Page::Profile);
+                Command::none()
+            }
+            FastFileFlowMessage::ProfileRecomputeClick() => self.get_profile_message(),
+            FastFileFlowMessage::SetProfile(profile) => {
+                self.enable_loading(false);
+                let mut profile = match profile {
+                    Ok(profile) => profile,
+                    Err(e) => {
+                        self.set_error(&ERROR_PROFILE.replace("{}", &e));
+                        return Command::none();
+                    }
+                };
+                // The file was loaded again while it was profiled
+                if profile.len() != self.columns.len() {
+                    return Command::none();
+                }
+
+                // Clicking a header afterwards reuses the statistics instead of a new read
+                for (column, stadistics) in self.columns.iter_mut().zip(profile.iter()) {
+                    column.stadistics = stadistics.clone();
+                }
+                self.profile_sort.sort(&mut profile, self.profile_ascending);
+                self.profile = profile;
+                self.router(Page::Profile);
+                Command::none()
+            }
+            FastFileFlowMessage::ProfileSortClick(profile_sort) => {
+                self.profile_ascending =
+                    profile_sort != self.profile_sort || !self.profile_ascending;
+                self.profile_sort = profile_sort;
+                self.profile_sort
+                    .sort(&mut self.profile, self.profile_ascending);
+                Command::none()
+            }
+            FastFileFlowMessage::ProfileRowClick(header) => {
+                self.column_stadistics = self
+                    .profile
+                    .iter()
+                    .find(|stadistics| stadistics.header == header)
+                    .cloned()
+                    .unwrap_or(Stadistics::default());
+                self.profile_selected = Some(header);
+                Command::none()
+            }
+            FastFileFlowMessage::WatchFileToggled(watch_file) => {
+                self.watch_file = watch_file;
+                self.file_rewritten = false;
+                Command::none()
+            }
+            FastFileFlowMessage::WatchFileTick() => {
+                if self.watching_file || self.running || !self.is_file_loaded() {
+                    return Command::none();
+                }
+                self.watching_file = true;
+                let file_stamp = self.selected_file.file_stamp.clone();
+                let file_path = self.selected_file.file_path.clone();
+                let can_tail = self.selected_file.can_tail();
+                Command::perform(
+                    async move { file_stamp.get_change(&file_path, can_tail).await },
+                    FastFileFlowMessage::FileChanged,
+                )
+            }
+            FastFileFlowMessage::FileChanged(file_change) => match file_change {
+                FileChange::Unchanged => {
+                    self.watching_file = false;
+                    Command::none()
+                }
+                FileChange::Appended => {
+                    let mut selected_file = self.selected_file.clone();
+                    Command::perform(
+                        async move {
+                            selected_file
+                                .tail_rows()
+                                .await
+                                .map(|_| selected_file)
+                                .map_err(|e| e.to_string())
+                        },
+                        FastFileFlowMessage::SetTailedFile,
+                    )
+                }
+                FileChange::Rewritten => {
+                    self.watching_file = false;
+                    self.file_rewritten = true;
+                    self.set_error(ERROR_FILE_REWRITTEN);
+                    Command::none()
+                }
+            },
+            FastFileFlowMessage::SetTailedFile(selected_file) => {
+                // The file was loaded again while the rows were read
+                if !self.watching_file {
+                    return Command::none();
+                }
+                self.watching_file = false;
+
+                let selected_file = match selected_file {
+                    Ok(selected_file) => selected_file,
+                    Err(e) => {
+                        self.file_rewritten = true;
+                        self.set_error(&ERROR_FILE_TAIL.replace("{}", &e));
+                        return Command::none();
+                    }
+                };
+                let appended = selected_file
+                    .rows
+                    .total
+                    .saturating_sub(self.selected_file.rows.total);
+                let is_window_open = self.page == Page::Main
+                    && !self.is_sampled()
+                    && !self.is_searched()
+                    && !self.fetching_window
+                    && self.rows.len() < TABLE_WINDOW_ROWS
+                    && self.rows_offset + self.rows.len() as u64 >= self.selected_file.rows.total;
+                self.selected_file = selected_file;
+                if appended == 0 {
+                    return Command::none();
+                }
+                // The next query loads the file again with the appended rows
+                self.query_database = None;
+
+                self.notification_message = INFORMATIVE_ROWS_APPENDED
+                    .replace("{}", &appended.to_formatted_string(&Locale::en));
+                // The appended rows take their place in the order of a sorted table
+                if !self.selected_file.read_options.sort.is_empty() {
+                    return Command::batch(vec![
+                        self.get_sample_message(),
+                        self.get_sort_message(),
+                    ]);
+                }
+                if is_window_open {
+                    self.get_table_fetch_message(self.rows_offset)
+                } else {
+                    // The appended rows can change the sample
+                    self.get_sample_message()
+                }
+            }
+            FastFileFlowMessage::QueryButtonClick() => {
+                if !self.is_file_loaded() {
+                    self.set_file_not_found_error();
+                    return Command::none();
+                }
+                if self.query_content.text().trim().is_empty() {
+                    let table = get_file_table_name(self.selected_file.get_load_path());
+                    self.query_content = Content::with_text(&QUERY_DEFAULT.replace("{}", &table));
+                }
+                self.show_query_table();
+                Command::none()
+            }
+            FastFileFlowMessage::QueryActionPerformed(action) => {
+                self.query_content.perform(action);
+                Command::none()
+            }
+            FastFileFlowMessage::QueryAddFileClick() => {
+                let path = crate::dialog::load_csv();
+                if !path.is_empty() && !self.query_files.contains(&path) {
+                    self.query_files.push(path);
+                    self.query_database = None;
+                }
+                Command::none()
+            }
+            FastFileFlowMessage::QueryRemoveFilesClick() => {
+                self.query_files.clear();
+                self.query_database = None;
+                Command::none()
+            }
+            FastFileFlowMessage::QueryRunClick() => self.get_query_message(),
+            FastFileFlowMessage::QueryCompleted(result) => {
+                self.enable_loading(false);
+                match result {
+                    Ok((database, query_result)) => {
+                        if query_result.query.total > QUERY_ROWS_MAX as u64 {
+                            self.notification_message = INFORMATIVE_QUERY_LIMIT
+                                .replace("{}", &QUERY_ROWS_MAX.to_formatted_string(&Locale::en));
+                        }
+                        self.query_database = Some(database);
+                        self.query_result = Some(query_result);
+                        if self.page == Page::Query {
+                            self.show_query_table();
+                        }
+                    }
+                    Err(e) => self.set_error(&ERROR_QUERY.replace("{}", &e)),
+                }
+                Command::none()
+            }
+            FastFileFlowMessage::QueryExportClick() => {
+                self.close_result_table();
+                self.export_query = true;
+                self.export_pivot = false;
+                self.router(Page::Export);
+                Command::none()
+            }
+            FastFileFlowMessage::QueryCloseClick() => {
+                self.close_result_table();
+                Command::none()
+            }
+            FastFileFlowMessage::PivotButtonClick() => {
+                if !self.is_file_loaded() {
+                    self.set_file_not_found_error();
+                    return Command::none();
+                }
+                if self.pivot.group_by.is_empty() && self.pivot.values.is_empty() {
+                    self.pivot = self.get_default_pivot();
+                }
+                self.show_pivot_table();
+                Command::none()
+            }
+            FastFileFlowMessage::PivotGroupColumnSelected(header) => {
+                self.pivot_group_column = Some(header);
+                Command::none()
+            }
+            FastFileFlowMessage::PivotAddGroupClick() => {
+                if let Some(header) = self.pivot_group_column.take() {
+                    if !self.pivot.group_by.contains(&header) {
+                        self.pivot.group_by.push(header);
+                    }
+                }
+                Command::none()
+            }
+            FastFileFlowMessage::PivotColumnSelected(header) => {
+                self.pivot.pivot_column = header.clone();
+                self.pivot_column = Some(header);
+                Command::none()
+            }
+            FastFileFlowMessage::PivotColumnClearClick() => {
+                self.pivot.pivot_column = String::new();
+                self.pivot_column = None;
+                Command::none()
+            }
+            FastFileFlowMessage::PivotValueColumnSelected(header) => {
+                // Numbers are summed and the rest counted, unless another aggregation is chosen
+                let aggregation = match self.get_column_classification(&header) {
+                    DataClassification::Quantitative => Aggregation::Sum,
+                    _ => Aggregation::Count,
+                };
+                self.pivot_aggregation = Some(aggregation.to_string().to_string());
+                self.pivot_value_column = Some(header);
+                Command::none()
+            }
+            FastFileFlowMessage::PivotAggregationSelected(aggregation) => {
+                self.pivot_aggregation = Some(aggregation);
+                Command::none()
+            }
+            FastFileFlowMessage::PivotAddValueClick() => {
+                let (Some(header), Some(aggregation)) = (
+                    self.pivot_value_column.clone(),
+                    self.pivot_aggregation.clone(),
+                ) else {
+                    self.set_error(ERROR_PIVOT_VALUE);
+                    return Command::none();
+                };
+                let aggregation = Aggregation::from_string(&aggregation);
+                if aggregation.is_numeric()
+                    && self.get_column_classification(&header) == DataClassification::Qualitative
+                {
+                    self.set_error(&ERROR_PIVOT_AGGREGATION.replace("{}", &header));
+                    return Command::none();
+                }
+                let value = PivotValue::new(header, aggregation);
+                if !self.pivot.values.contains(&value) {
+                    self.pivot.values.push(value);
+                }
+                self.pivot_value_column = None;
+                Command::none()
+            }
+            FastFileFlowMessage::PivotDateGroupSelected(date_group) => {
+                self.pivot.date_group = DateGroup::from_string(&date_group);
+                self.pivot_date_group = Some(date_group);
+                Command::none()
+            }
+            FastFileFlowMessage::PivotClearClick() => {
+                self.pivot = Pivot::default();
+                self.pivot_column = None;
+                self.pivot_date_group = Some(DateGroup::default().to_string().to_string());
+                Command::none()
+            }
+            FastFileFlowMessage::PivotRunClick() => self.get_pivot_message(),
+            FastFileFlowMessage::SetPivotTable(pivot_table) => {
+                self.enable_loading(false);
+                match pivot_table {
+                    Ok(pivot_table) => {
+                        self.pivot_chart_column_state = combo_box::State::new(
+                            pivot_table.headers[pivot_table.group_columns..].to_vec(),
+                        );
+                        self.pivot_chart_column = None;
+                        self.pivot_chart = None;
+                        self.pivot_table = Some(pivot_table);
+                        if self.page == Page::Pivot {
+                            self.show_pivot_table();
+                        }
+                    }
+                    Err(e) => self.set_error(&ERROR_PIVOT.replace("{}", &e)),
+                }
+                Command::none()
+            }
+            FastFileFlowMessage::PivotChartColumnSelected(header) => {
+                self.pivot_chart_column = Some(header);
+                match self.pivot_chart.is_some() {
+                    true => self.get_pivot_chart_message(),
+                    false => Command::none(),
+                }
+            }
+            FastFileFlowMessage::PivotChartClick() => self.get_pivot_chart_message(),
+            FastFileFlowMessage::SetPivotChart(chart) => {
+                self.enable_loading(false);
+                match chart {
+                    Ok(bytes) => {
+                        self.pivot_chart = Some(iced::widget::image::Handle::from_memory(bytes))
+                    }
+                    Err(e) => self.set_error(&ERROR_PIVOT_CHART.replace("{}", &e)),
+                }
+                Command::none()
+            }
+            FastFileFlowMessage::PivotTableClick() => {
+                self.pivot_chart = None;
+                Command::none()
+            }
+            FastFileFlowMessage::PivotExportClick() => {
+                self.close_result_table();
+                self.export_pivot = true;
+                self.export_query = false;
+                self.router(Page::Export);
+                Command::none()
+            }
+            FastFileFlowMessage::PivotCloseClick() => {
+                self.pivot_chart = None;
+                self.close_result_table();
+                Command::none()
+            }
+            FastFileFlowMessage::ExportPivotToggled(value) => {
+                self.export_pivot = value;
+                if value {
+                    self.export_query = false;
+                }
+                Command::none()
+            }
+            FastFileFlowMessage::SortButtonClick() => {
+                if self.is_file_loaded() {
+                    self.router(Page::Sort);
+                } else {
+                    self.set_file_not_found_error();
+                }
+                Command::none()
+            }
+            FastFileFlowMessage::SortColumnSelected(header) => {
+                self.sort_column = Some(header);
+                Command::none()
+            }
+            FastFileFlowMessage::SortDirectionSelected(direction) => {
+                self.sort_direction = Some(direction);
+                Command::none()
+            }
+            FastFileFlowMessage::SortAddKeyClick() => {
+                let (Some(header), Some(direction)) =
+                    (self.sort_column.take(), self.sort_direction.clone())
+                else {
+                    self.set_error(ERROR_SORT_KEY);
+                    return Command::none();
+                };
+                // A column already sorted by takes the new direction in its place
+                let key = SortKey::new(header, direction == SORT_ASCENDING);
+                match self.sort_keys.iter_mut().find(|k| k.header == key.header) {
+                    Some(sort_key) => *sort_key = key,
+                    None => self.sort_keys.push(key),
+                }
+                Command::none()
+            }
+            FastFileFlowMessage::SortClearClick() => {
+                self.sort_keys.clear();
+                Command::none()
+            }
+            FastFileFlowMessage::SortApplyClick() => {
+                self.selected_file.read_options.sort = self.sort_keys.clone();
+                self.router(Page::Main);
+                match self.sort_keys.is_empty() {
+                    true => {
+                        Command::batch(vec![self.get_sort_message(), self.reset_table_window()])
+                    }
+                    false => self.get_sort_message(),
+                }
+            }
+            FastFileFlowMessage::SetSortedFile(sorted_file) => {
+                self.enable_loading(false);
+                match sorted_file {
+                    // The keys changed while the table was sorted
+                    Ok(sorted_file) if sorted_file.keys == self.selected_file.read_options.sort => {
+                        self.selected_file.sorted_file = Some(sorted_file);
+                        self.reset_table_window()
+                    }
+                    Ok(_) => Command::none(),
+                    Err(e) => {
+                        self.set_error(&ERROR_SORT.replace("{}", &e));
+                        Command::none()
+                    }
+                }
+            }
+            FastFileFlowMessage::SampleButtonClick() => {
+                if self.is_file_loaded() {
+                    self.router(Page::Sample);
+                } else {
+                    self.set_file_not_found_error();
+                }
+                Command::none()
+            }
+            FastFileFlowMessage::SampleModeSelected(sample_mode) => {
+                self.sample_mode = Some(sample_mode);
+                Command::none()
+            }
+            FastFileFlowMessage::SampleSizeChange(value) => {
+                self.sample_size = value;
+                Command::none()
+            }
+            FastFileFlowMessage::SampleSeedChange(value) => {
+                self.sample_seed = value;
+                Command::none()
+            }
+            FastFileFlowMessage::SampleStepChange(value) => {
+                self.sample_step = value;
+                Command::none()
+            }
+            FastFileFlowMessage::SampleColumnSelected(header) => {
+                self.sample_column = Some(header);
+                Command::none()
+            }
+            FastFileFlowMessage::SampleForModelsToggled(value) => {
+                self.sample_for_models = value;
+                Command::none()
+            }
+            FastFileFlowMessage::SampleApplyClick() => {
+                let Some(sampling) = self.get_sampling() else {
+                    self.set_error(ERROR_SAMPLE_OPTIONS);
+                    return Command::none();
+                };
+                if sampling.mode == SampleMode::Stratified && sampling.column.is_empty() {
+                    self.set_error(ERROR_SAMPLE_COLUMN);
+                    return Command::none();
+                }
+
+                self.selected_file.read_options.sampling = sampling;
+                self.clear_search();
+                self.rows = self.selected_file.rows.sample.clone();
+                self.rows_offset = 0;
+                self.row_cursor = 0;
+                self.fetching_window = false;
+                self.get_sample_message()
+            }
+            FastFileFlowMessage::SetSample(sample) => {
+                self.enable_loading(false);
+                match sample {
+                    Ok(rows) => {
+                        self.selected_file.sampled_rows = std::sync::Arc::new(rows);
+                        // The preview keeps its rows until it is closed, the matches stay
+                        if !self.is_result_table() && !self.is_searched() {
+                            self.rows = self.get_file_rows();
+                            self.rows_offset = 0;
+                            self.row_cursor = 0;
+                            self.fetching_window = false;
+                        }
+                    }
+                    Err(e) => self.set_error(&ERROR_SAMPLE.replace("{}", &e)),
+                }
+                Command::none()
+            }
+            FastFileFlowMessage::DatasetLoadClick() => {
+                let path = self.dataset_pattern.trim().to_string();
+                if path.is_empty() {
+                    return Command::none();
+                }
+
+                // Reloading the same dataset keeps the options chosen for it
+                let mut read_options = match path == self.selected_file.dataset.path {
+                    true => self.selected_file.read_options.clone(),
+                    false => ReadOptions::default(),
+                };
+                read_options.source_column = self.dataset_source_column;
+                self.enable_loading(true);
+                self.get_load_message(path, read_options)
+            }
+            FastFileFlowMessage::EncodingSelected(encoding) => {
+                self.encoding_selected = Some(encoding);
+                Command::none()
+            }
+            FastFileFlowMessage::EncodingApplyClick() => {
+                let mut read_options = self.selected_file.read_options.clone();
+                read_options.encoding = self.encoding_selected.clone().unwrap_or_default();
+                self.get_reload_message(read_options)
+            }
+            FastFileFlowMessage::RejectedPolicySelected(row_policy) => {
+                self.rejected_policy = Some(row_policy);
+                Command::none()
+            }
+            FastFileFlowMessage::RejectedApplyClick() => {
+                let mut read_options = self.selected_file.read_options.clone();
+                read_options.row_policy =
+                    RowPolicy::from_string(&self.rejected_policy.clone().unwrap_or_default());
+                self.get_reload_message(read_options)
+            }
+            FastFileFlowMessage::RejectedExportClick() => {
+                if let Some(path) = FileDialog::new()
+                    .add_filter(DIALOG_QUARANTINE_TITLE, &[DIALOG_FILE_EXTENSION_CSV])
+                    .set_filename(format!("quarantine.{}", DIALOG_FILE_EXTENSION_CSV).as_str())
+                    .show_save_single_file()
+                    .ok()
+                    .flatten()
+                {
+                    let path = path.to_string_lossy().into_owned();
+                    match self.selected_file.rejected_rows.write_csv(&path) {
+                        Ok(_) => self.notification_message = format!("File Saved: {path}"),
+                        Err(_) => self.set_error(ERROR_FILE_SAVE),
+                    }
+                }
+                Command::none()
+            }
+            FastFileFlowMessage::DialectHasHeaderToggled(is_checked) => {
+                self.dialect_has_header = is_checked;
+                Command::none()
+            }
+            FastFileFlowMessage::DialectHeadersLoadClick() => {
+                if let Some(path) = FileDialog::new()
+                    .add_filter(DIALOG_HEADER_NAMES_TITLE, &[DIALOG_FILE_EXTENSION_TXT])
+                    .show_open_single_file()
+                    .ok()
+                    .flatten()
+                {
+                    let delimiter = Dialect::parse_char(&self.dialect_delimiter).unwrap_or(b',');
+                    match std::fs::read_to_string(path) {
+                        Ok(text) => {
+                            self.dialect_headers = ReadOptions::parse_headers(&text, delimiter)
+                        }
+                        Err(_) => self.set_error(ERROR_HEADER_NAMES),
+                    }
+                }
+                Command::none()
+            }
+            FastFileFlowMessage::DialectHeadersClearClick() => {
+                self.dialect_headers = vec![];
+                Command::none()
+            }
+            FastFileFlowMessage::DialectApplyClick() => {
+                let escape = self.dialect_escape.trim();
+                let (Some(delimiter), Some(quote), Some(escape)) = (
+                    Dialect::parse_char(&self.dialect_delimiter),
+                    Dialect::parse_char(&self.dialect_quote),
+                    match escape.is_empty() {
+                        true => Some(None),
+                        false => Dialect::parse_char(escape).map(Some),
+                    },
+                ) else {
+                    self.set_error(ERROR_DIALECT_CHARACTER);
+                    return Command::none();
+                };
+                let terminator = LineTerminator::from_string(
+                    &self.dialect_terminator.clone().unwrap_or_default(),
+                );
+
+                let mut read_options = self.selected_file.read_options.clone();
+                read_options.dialect = Dialect::new(delimiter, quote, escape, terminator);
+                read_options.has_header = self.dialect_has_header;
+                read_options.headers = self.dialect_headers.clone();
+                self.get_reload_message(read_options)
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        match self.page {
+            Page::Main => self.show_main_screen(),
+            Page::Filter => self.show_filter_screen(),
+            Page::Process => self.show_process_screen(),
+            Page::AI => self.show_ai_screen(),
+            Page::Preview => self.show_preview_screen(),
+            Page::Workbook => self.show_options_screen(self.build_workbook_panel()),
+            Page::Database => self.show_options_screen(self.build_database_panel()),
+            Page::Dialect => self.show_options_screen(self.build_dialect_panel()),
+            Page::Encoding => self.show_options_screen(self.build_encoding_panel()),
+            Page::Cache => self.show_options_screen(self.build_cache_panel()),
+            Page::Rejected => self.show_options_screen(self.build_rejected_panel()),
+            Page::Dataset => self.show_options_screen(self.build_dataset_panel()),
+            Page::Join => self.show_options_screen(self.build_join_panel()),
+            Page::Schema => self.show_options_screen(self.build_schema_panel()),
+            Page::Profile => self.show_options_screen(self.build_profile_panel()),
+            Page::Search => self.show_options_screen(self.build_search_panel()),
+            Page::Sort => self.show_options_screen(self.build_sort_panel()),
+            Page::Query => {
+                self.show_result_screen(self.build_query_panel(), self.build_table().into())
+            }
+            Page::Pivot => {
+                self.show_result_screen(self.build_pivot_panel(), self.build_pivot_body())
+            }
+            Page::Sample => self.show_options_screen(self.build_sample_panel()),
+            Page::Export => self.show_options_screen(self.build_export_panel()),
+            Page::UserAboutIt => self.show_user_screen(),
+        }
+    }
+
+    fn theme(&self) -> Self::Theme {
+        self.theme.clone()
+    }
+
+    fn subscription(&self) -> Subscription<Self::Message> {
+        // Every change reported by the running task, one subscription per task
+        let progress = if self.running {
+            subscription::unfold(
+                ("progress", self.task.id),
+                self.task.subscribe(),
+                |mut receiver| async move {
+                    if receiver.changed().await.is_err() {
+                        std::future::pending::<()>().await;
+                    }
+                    let progress = *receiver.borrow_and_update();
+                    (FastFileFlowMessage::Tick(progress), receiver)
+                },
+            )
+        } else {
+            Subscription::none()
+        };
+
+        // The table body captures the wheel, it only holds the visible rows so the
+        // captured delta drives the row cursor over the whole file.
+        let table_scroll = event::listen_with(|event, status| match (event, status) {
+            (
+                iced::Event::Mouse(mouse::Event::WheelScrolled { delta }),
+                event::Status::Captured,
+            ) => {
+                let rows = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => -y * TABLE_SCROLL_STEP,
+                    mouse::ScrollDelta::Pixels { y, .. } => -y / TABLE_ROW_HEIGHT,
+                };
+                Some(FastFileFlowMessage::TableWheelScrolled(rows))
+            }
+            _ => None,
+        });
+
+        // Polled, a change is only looked at once the previous one was handled
+        let file_watch = if self.watch_file && self.is_file_loaded() && !self.file_rewritten {
+            subscription::unfold("file_watch", (), |_| async {
+                tokio::time::sleep(Duration::from_millis(FILE_WATCH_INTERVAL_MS)).await;
+                (FastFileFlowMessage::WatchFileTick(), ())
+            })
+        } else {
+            Subscription::none()
+        };
+
+        // The file is read once, the matches of every batch of rows are shown as they come
+        let search = match (&self.search, self.searching) {
+            (Some(search), true) => subscription::unfold(
+                ("search", self.task.id),
+                SearchState::Start(Box::new(self.selected_file.clone()), search.clone()),
+                |state| async move {
+                    match state {
+                        SearchState::Start(selected_file, search) => {
+                            match selected_file.get_search_cursor(search).await {
+                                Ok(cursor) => (
+                                    FastFileFlowMessage::SearchBatch(Some(Ok(vec![]))),
+                                    SearchState::Reading(cursor),
+                                ),
+                                Err(e) => (
+                                    FastFileFlowMessage::SearchBatch(Some(Err(e.to_string()))),
+                                    SearchState::Done,
+                                ),
+                            }
+                        }
+                        SearchState::Reading(mut cursor) => {
+                            match cursor.next_batch(SEARCH_BATCH_ROWS).await {
+                                Some(Ok(matches)) => (
+                                    FastFileFlowMessage::SearchBatch(Some(Ok(matches))),
+                                    SearchState::Reading(cursor),
+                                ),
+                                Some(Err(e)) => (
+                                    FastFileFlowMessage::SearchBatch(Some(Err(e.to_string()))),
+                                    SearchState::Done,
+                                ),
+                                None => (FastFileFlowMessage::SearchBatch(None), SearchState::Done),
+                            }
+                        }
+                        SearchState::Done => std::future::pending().await,
+                    }
+                },
+            ),
+            _ => Subscription::none(),
+        };
+
+        Subscription::batch(vec![progress, table_scroll, file_watch, search])
     }
 }
@@ -5,26 +5,59 @@ use crate::ai::pca::PrincipalComponentsAnalisys;
 use crate::ai::AiModel;
 use crate::constants::english::*;
 use crate::constants::icons::*;
+use crate::constants::path::{
+    DATA_FORMATS, ENCODINGS, EXPORT_ENCODINGS, FFFLOW, PIVOT_IMAGE_RESULT,
+};
 use crate::constants::sizes::{
-    FONT_NAME, PANEL_FONT_SIZE, PANEL_HEIGHT, PANEL_WIDTH, SEARCH_TEXTBOX_WIDTH,
+    COLUMN_CACHE_BUDGET_MB, FONT_NAME, IMAGE_HEIGHT, IMAGE_WIDTH, PANEL_FONT_SIZE, PANEL_HEIGHT,
+    PANEL_WIDTH, PROFILE_CELL_WIDTH, QUERY_EDITOR_HEIGHT, QUERY_ROWS_MAX, SEARCH_TEXTBOX_WIDTH,
+    TABLE_HEADER_HEIGHT, TABLE_ROW_HEIGHT, TABLE_SLIDER_WIDTH, TABLE_VISIBLE_ROWS_MAX,
+    TABLE_WINDOW_ROWS,
 };
 use crate::correlation_analysis::CorrelationAnalysis;
 use crate::dynamictable::iced_column::IcedColumn;
 use crate::dynamictable::iced_row::IcedRow;
 use crate::dynamictable::simple_column::SimpleColumn;
+use crate::export::Export;
+use crate::save_options::filter::comparison::Comparison;
+use crate::save_options::filter::unparsed_policy::UnparsedPolicy;
 use crate::save_options::filter::FilterOption;
 use crate::save_options::option_type::OptionType;
 use crate::save_options::process::ProcessOption;
 use crate::save_options::SaveOptions;
 use crate::stadistics::data_classification::DataClassification;
+use crate::stadistics::data_type::DataType;
+use crate::stadistics::profile_sort::ProfileSort;
 use crate::stadistics::Stadistics;
+use crate::stored_file::compression::Compression;
+use crate::stored_file::dataset::Dataset;
+use crate::stored_file::dialect::line_terminator::LineTerminator;
+use crate::stored_file::dialect::Dialect;
+use crate::stored_file::file_stamp::FileChange;
 use crate::stored_file::file_type::FileType;
+use crate::stored_file::join::join_type::JoinType;
+use crate::stored_file::join::Join;
+use crate::stored_file::pivot::aggregation::Aggregation;
+use crate::stored_file::pivot::date_group::DateGroup;
+use crate::stored_file::pivot::{Pivot, PivotTable, PivotValue};
+use crate::stored_file::query::{QueryDatabase, QueryResult};
+use crate::stored_file::read_options::ReadOptions;
+use crate::stored_file::row_index::RowIndex;
+use crate::stored_file::row_policy::RowPolicy;
+use crate::stored_file::sampling::sample_mode::SampleMode;
+use crate::stored_file::sampling::Sampling;
+use crate::stored_file::schema::ColumnType;
+use crate::stored_file::search::Search;
+use crate::stored_file::sort::external_sort::SortedFile;
+use crate::stored_file::sort::SortKey;
 use crate::stored_file::StoredFile;
+use crate::task_progress::TaskProgress;
 use crate::util::get_full_directory;
+use crate::util::print_timer;
 use crate::util::{get_logo, get_menu_button, get_text, get_text_size, wrap_tooltip};
 use iced::widget::{
-    column, container, horizontal_space, responsive, row, scrollable, text_input, tooltip, Button,
-    Column, Container, Row, Text, TextInput,
+    column, container, horizontal_space, progress_bar, responsive, row, scrollable, text_input,
+    tooltip, Button, Column, Container, Row, Text, TextInput,
 };
 use iced::Length::Fixed;
 use iced::{Border, Color, Command, Font, Length, Padding, Pixels, Theme};
@@ -34,6 +67,7 @@ use iced_widget::combo_box;
 use iced_widget::core::Element;
 use iced_widget::text_editor;
 use iced_widget::text_editor::Content;
+use iced_widget::vertical_slider;
 use iced_widget::vertical_space;
 use iced_widget::Image;
 use linear::Linear;
@@ -41,8 +75,9 @@ use num_format::{Locale, ToFormattedString};
 use std::fs::File;
 use std::io::BufRead;
 use std::io::{self, BufReader, BufWriter, Write};
+use std::sync::Arc;
 
-use std::time::Duration;
+use std::time::{Duration, Instant};
 mod easing;
 mod iced_app;
 mod linear;
@@ -59,16 +94,127 @@ pub struct FastFileFlow {
     footer: scrollable::Id,
     columns: Vec<IcedColumn>,
     rows: Vec<IcedRow>,
+    rows_offset: u64,
+    row_cursor: u64,
+    row_scroll_delta: f32,
+    fetching_window: bool,
+    go_to_row: String,
     file_loaded: String,
     progress: f32,
     running: bool,
+    task: TaskProgress,
     header_checked: Vec<SimpleColumn>,
     notification_message: String,
     column_options: Vec<SimpleColumn>,
     column_option_selected: Option<SimpleColumn>,
     column_options_state: combo_box::State<SimpleColumn>,
+    filter_comparison: Option<String>,
+    filter_comparison_state: combo_box::State<String>,
+    filter_unparsed: Option<String>,
+    filter_unparsed_state: combo_box::State<String>,
+    workbook_sheet: Option<String>,
+    workbook_sheet_state: combo_box::State<String>,
+    workbook_header_row: String,
+    database_table: Option<String>,
+    database_table_state: combo_box::State<String>,
+    database_query: String,
+    dialect_delimiter: String,
+    dialect_quote: String,
+    dialect_escape: String,
+    dialect_terminator: Option<String>,
+    dialect_terminator_state: combo_box::State<String>,
+    dialect_has_header: bool,
+    dialect_headers: Vec<String>,
+    rejected_policy: Option<String>,
+    rejected_policy_state: combo_box::State<String>,
+    encoding_selected: Option<String>,
+    encoding_state: combo_box::State<String>,
+    export_encoding: Option<String>,
+    export_encoding_state: combo_box::State<String>,
+    export_table: String,
+    cache_enabled: bool,
+    cache_on_load: bool,
+    cache_budget: String,
+    dataset_pattern: String,
+    dataset_source_column: bool,
+    join_file: String,
+    join_headers: Vec<String>,
+    join_type: Option<String>,
+    join_type_state: combo_box::State<String>,
+    join_left_key: Option<String>,
+    join_left_key_state: combo_box::State<String>,
+    join_right_key: Option<String>,
+    join_right_key_state: combo_box::State<String>,
+    join_keys: Vec<(String, String)>,
+    join_columns: Vec<String>,
+    schema_column: Option<String>,
+    schema_column_state: combo_box::State<String>,
+    schema_type: Option<String>,
+    schema_type_state: combo_box::State<String>,
+    schema_date_format: String,
+    schema_decimal_separator: String,
+    schema_types: Vec<ColumnType>,
+    schema_inferred: Vec<DataType>,
+    watch_file: bool,
+    watching_file: bool,
+    file_rewritten: bool,
+    profile: Vec<Stadistics>,
+    profile_sort: ProfileSort,
+    profile_ascending: bool,
+    profile_selected: Option<String>,
+    sample_mode: Option<String>,
+    sample_mode_state: combo_box::State<String>,
+    sample_size: String,
+    sample_seed: String,
+    sample_step: String,
+    sample_column: Option<String>,
+    sample_column_state: combo_box::State<String>,
+    sample_for_models: bool,
+    sort_column: Option<String>,
+    sort_column_state: combo_box::State<String>,
+    sort_direction: Option<String>,
+    sort_direction_state: combo_box::State<String>,
+    sort_keys: Vec<SortKey>,
+    export_sorted: bool,
+    query_content: Content,
+    /// Files queried as tables next to the loaded one.
+    query_files: Vec<String>,
+    /// The files loaded for the queries, `None` until the next query loads them again.
+    query_database: Option<Arc<QueryDatabase>>,
+    query_result: Option<QueryResult>,
+    export_query: bool,
+    pivot_group_column: Option<String>,
+    pivot_column: Option<String>,
+    pivot_value_column: Option<String>,
+    /// Every column of the file, to choose the group, pivot and value columns.
+    pivot_columns_state: combo_box::State<String>,
+    pivot_aggregation: Option<String>,
+    pivot_aggregation_state: combo_box::State<String>,
+    pivot_date_group: Option<String>,
+    pivot_date_group_state: combo_box::State<String>,
+    /// The pivot being built, run once it is complete.
+    pivot: Pivot,
+    pivot_table: Option<Arc<PivotTable>>,
+    pivot_chart_column: Option<String>,
+    pivot_chart_column_state: combo_box::State<String>,
+    /// The chart shown instead of the pivot table, `None` while the table is shown.
+    pivot_chart: Option<iced::widget::image::Handle>,
+    export_pivot: bool,
     theme: Theme,
     search_value: String,
+    /// Columns the search looks into, every column when it is empty.
+    search_columns: Vec<String>,
+    search_case_sensitive: bool,
+    search_whole_word: bool,
+    search_regex: bool,
+    /// The search whose matches the table shows, `None` when it shows the file.
+    search: Option<Search>,
+    search_rows: Vec<IcedRow>,
+    /// Matches of every column, in the order of the headers.
+    search_counts: Vec<u64>,
+    /// The match the navigation is at, among the matched cells.
+    search_match: Option<usize>,
+    searching: bool,
     ai_result: String,
     ai_image: String,
     columns_backup: Vec<IcedColumn>,
@@ -106,17 +252,140 @@ pub enum FastFileFlowMessage {
     ShowPreviewButtonClick(),
     SaveProjectButtonClick(),
     ExportButtonClick(),
-    ExportCompletedEvent(String),
+    ExportCompletedEvent(Result<String, String>),
     SearchOnSubmit(),
+    SearchColumnsClick(),
+    SearchColumnToggled(String, bool),
+    SearchPreviousClick(),
+    SearchNextClick(),
+    SearchToFilterClick(),
+    SearchCaseSensitiveToggled(bool),
+    SearchWholeWordToggled(bool),
+    SearchRegexToggled(bool),
+    SearchClearClick(),
+    /// Matches of the next rows read, `None` once the whole file was searched.
+    SearchBatch(Option<Result<Vec<IcedRow>, String>>),
     SyncHeader(scrollable::AbsoluteOffset),
     Resizing(usize, f32),
     Resized,
-    PreviewCompleted(Vec<IcedColumn>, Vec<IcedRow>),
+    PreviewCompleted(Result<(Vec<IcedColumn>, Vec<IcedRow>), String>),
     ShowAIButtonClick(),
     AICompleted(AiModel, String, bool),
     AIAnalysisEvent(AiModel),
     PreviewButtonCloseClick(),
     ActionPerformed(text_editor::Action),
+    TableWheelScrolled(f32),
+    TableSliderChanged(f64),
+    TableSliderReleased(),
+    GoToRowChange(String),
+    GoToRowSubmit(),
+    SetTableWindow(u64, Result<Vec<IcedRow>, String>),
+    WorkbookSheetSelected(String),
+    WorkbookHeaderRowChange(String),
+    WorkbookApplyClick(),
+    DatabaseTableSelected(String),
+    DatabaseQueryChange(String),
+    DatabaseApplyClick(),
+    DialectDelimiterChange(String),
+    DialectQuoteChange(String),
+    DialectEscapeChange(String),
+    DialectTerminatorSelected(String),
+    DialectHasHeaderToggled(bool),
+    DialectHeadersLoadClick(),
+    DialectHeadersClearClick(),
+    DialectApplyClick(),
+    RejectedPolicySelected(String),
+    RejectedApplyClick(),
+    RejectedExportClick(),
+    EncodingSelected(String),
+    EncodingApplyClick(),
+    ExportEncodingSelected(String),
+    ExportTableChange(String),
+    ExportSaveClick(),
+    CacheEnabledToggled(bool),
+    CacheOnLoadToggled(bool),
+    CacheBudgetChange(String),
+    CacheApplyClick(),
+    CacheCompleted(),
+    DatasetButtonClick(),
+    DatasetPatternChange(String),
+    DatasetBrowseClick(),
+    DatasetSourceColumnToggled(bool),
+    DatasetLoadClick(),
+    JoinBrowseClick(),
+    JoinFileLoaded(String, Vec<String>),
+    JoinTypeSelected(String),
+    JoinLeftKeySelected(String),
+    JoinRightKeySelected(String),
+    JoinAddKeyClick(),
+    JoinClearKeysClick(),
+    JoinColumnToggled(String, bool),
+    JoinApplyClick(),
+    JoinRemoveClick(),
+    SchemaButtonClick(),
+    SchemaColumnSelected(String),
+    SchemaTypeSelected(String),
+    SchemaDateFormatChange(String),
+    SchemaDecimalSeparatorChange(String),
+    SchemaSetClick(),
+    SchemaResetClick(),
+    SchemaClearClick(),
+    SchemaApplyClick(),
+    WatchFileToggled(bool),
+    WatchFileTick(),
+    FileChanged(FileChange),
+    SetTailedFile(Result<StoredFile, String>),
+    ProfileButtonClick(),
+    ProfileRecomputeClick(),
+    SetProfile(Result<Vec<Stadistics>, String>),
+    ProfileSortClick(ProfileSort),
+    ProfileRowClick(String),
+    TaskCancelClick(),
+    SampleButtonClick(),
+    SampleModeSelected(String),
+    SampleSizeChange(String),
+    SampleSeedChange(String),
+    SampleStepChange(String),
+    SampleColumnSelected(String),
+    SampleForModelsToggled(bool),
+    SampleApplyClick(),
+    SetSample(Result<Vec<IcedRow>, String>),
+    SortButtonClick(),
+    SortColumnSelected(String),
+    SortDirectionSelected(String),
+    SortAddKeyClick(),
+    SortClearClick(),
+    SortApplyClick(),
+    SetSortedFile(Result<Arc<SortedFile>, String>),
+    ExportSortedToggled(bool),
+    QueryButtonClick(),
+    QueryActionPerformed(text_editor::Action),
+    QueryAddFileClick(),
+    QueryRemoveFilesClick(),
+    QueryRunClick(),
+    QueryCompleted(Result<(Arc<QueryDatabase>, QueryResult), String>),
+    QueryExportClick(),
+    QueryCloseClick(),
+    ExportQueryToggled(bool),
+    PivotButtonClick(),
+    PivotGroupColumnSelected(String),
+    PivotAddGroupClick(),
+    PivotColumnSelected(String),
+    PivotColumnClearClick(),
+    PivotValueColumnSelected(String),
+    PivotAggregationSelected(String),
+    PivotAddValueClick(),
+    PivotDateGroupSelected(String),
+    PivotClearClick(),
+    PivotRunClick(),
+    SetPivotTable(Result<Arc<PivotTable>, String>),
+    PivotChartColumnSelected(String),
+    PivotChartClick(),
+    SetPivotChart(Result<Vec<u8>, String>),
+    PivotTableClick(),
+    PivotExportClick(),
+    PivotCloseClick(),
+    ExportPivotToggled(bool),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -126,6 +395,22 @@ pub enum Page {
     Process = 3,
     AI,
     Preview,
+    Workbook,
+    Database,
+    Dialect,
+    Encoding,
+    Export,
+    Cache,
+    Rejected,
+    Dataset,
+    Join,
+    Schema,
+    Profile,
+    Sample,
+    Search,
+    Sort,
+    Query,
+    Pivot,
     UserAboutIt,
 }
 
@@ -135,6 +420,15 @@ impl FastFileFlow {
             page: Page::Main,
             theme: Theme::GruvboxLight,
             search_value: String::from(""),
+            search_columns: vec![],
+            search_case_sensitive: false,
+            search_whole_word: false,
+            search_regex: false,
+            search: None,
+            search_rows: vec![],
+            search_counts: vec![],
+            search_match: None,
+            searching: false,
             is_primary_logo: true,
             clicked_button: String::from(""),
             selected_file: StoredFile::default(),
@@ -145,14 +439,112 @@ impl FastFileFlow {
             footer: scrollable::Id::unique(),
             columns: vec![],
             rows: vec![],
+            rows_offset: 0,
+            row_cursor: 0,
+            row_scroll_delta: 0.0,
+            fetching_window: false,
+            go_to_row: String::from(""),
             file_loaded: String::from(""),
             progress: 0.0,
             running: false,
+            task: TaskProgress::default(),
             header_checked: vec![],
             notification_message: String::from(""),
             column_options: vec![],
             column_option_selected: None,
             column_options_state: combo_box::State::new(vec![]),
+            filter_comparison: None,
+            filter_comparison_state: combo_box::State::new(Comparison::all()),
+            filter_unparsed: None,
+            filter_unparsed_state: combo_box::State::new(UnparsedPolicy::all()),
+            workbook_sheet: None,
+            workbook_sheet_state: combo_box::State::new(vec![]),
+            workbook_header_row: String::from(""),
+            database_table: None,
+            database_table_state: combo_box::State::new(vec![]),
+            database_query: String::from(""),
+            dialect_delimiter: String::from(""),
+            dialect_quote: String::from(""),
+            dialect_escape: String::from(""),
+            dialect_terminator: None,
+            dialect_terminator_state: combo_box::State::new(LineTerminator::all()),
+            dialect_has_header: true,
+            dialect_headers: vec![],
+            rejected_policy: None,
+            rejected_policy_state: combo_box::State::new(RowPolicy::all()),
+            encoding_selected: None,
+            encoding_state: combo_box::State::new(vec![]),
+            export_encoding: ENCODINGS.first().map(|encoding| encoding.to_string()),
+            export_encoding_state: combo_box::State::new(
+                EXPORT_ENCODINGS.iter().map(|s| s.to_string()).collect(),
+            ),
+            export_table: String::from(""),
+            cache_enabled: true,
+            cache_on_load: false,
+            cache_budget: COLUMN_CACHE_BUDGET_MB.to_string(),
+            dataset_pattern: String::from(""),
+            dataset_source_column: true,
+            join_file: String::from(""),
+            join_headers: vec![],
+            join_type: None,
+            join_type_state: combo_box::State::new(JoinType::all()),
+            join_left_key: None,
+            join_left_key_state: combo_box::State::new(vec![]),
+            join_right_key: None,
+            join_right_key_state: combo_box::State::new(vec![]),
+            join_keys: vec![],
+            join_columns: vec![],
+            schema_column: None,
+            schema_column_state: combo_box::State::new(vec![]),
+            schema_type: None,
+            schema_type_state: combo_box::State::new(DataType::all()),
+            schema_date_format: String::from(""),
+            schema_decimal_separator: String::from(""),
+            schema_types: vec![],
+            schema_inferred: vec![],
+            watch_file: false,
+            watching_file: false,
+            file_rewritten: false,
+            profile: vec![],
+            profile_sort: ProfileSort::default(),
+            profile_ascending: true,
+            profile_selected: None,
+            sample_mode: None,
+            sample_mode_state: combo_box::State::new(SampleMode::all()),
+            sample_size: String::from(""),
+            sample_seed: String::from(""),
+            sample_step: String::from(""),
+            sample_column: None,
+            sample_column_state: combo_box::State::new(vec![]),
+            sample_for_models: false,
+            sort_column: None,
+            sort_column_state: combo_box::State::new(vec![]),
+            sort_direction: Some(SORT_ASCENDING.to_string()),
+            sort_direction_state: combo_box::State::new(vec![
+                SORT_ASCENDING.to_string(),
+                SORT_DESCENDING.to_string(),
+            ]),
+            sort_keys: vec![],
+            export_sorted: false,
+            query_content: Content::new(),
+            query_files: vec![],
+            query_database: None,
+            query_result: None,
+            export_query: false,
+            pivot_group_column: None,
+            pivot_column: None,
+            pivot_value_column: None,
+            pivot_columns_state: combo_box::State::new(vec![]),
+            pivot_aggregation: None,
+            pivot_aggregation_state: combo_box::State::new(Aggregation::all()),
+            pivot_date_group: Some(DateGroup::default().to_string().to_string()),
+            pivot_date_group_state: combo_box::State::new(DateGroup::all()),
+            pivot: Pivot::default(),
+            pivot_table: None,
+            pivot_chart_column: None,
+            pivot_chart_column_state: combo_box::State::new(vec![]),
+            pivot_chart: None,
+            export_pivot: false,
             ai_result: String::default(),
             ai_image: String::default(),
             columns_backup: vec![],
@@ -167,7 +559,7 @@ impl FastFileFlow {
         let mut writer = BufWriter::new(file);
 
         // Serializa selected_file
-        writeln!(writer, "{}", self.selected_file.file_path)?;
+        writeln!(writer, "{}", self.selected_file.get_load_path())?;
         writeln!(writer, "{}", self.selected_file.encoding)?;
         writeln!(writer, "{}", self.selected_file.size)?;
         writeln!(writer, "{}", self.selected_file.format)?;
@@ -199,6 +591,109 @@ impl FastFileFlow {
             writeln!(writer, "{}", column.save_options.process.replace_then_value)?;
         }
 
+        // Serializa read_options
+        writeln!(writer, "{}", self.selected_file.read_options.sheet)?;
+        writeln!(writer, "{}", self.selected_file.read_options.header_row)?;
+
+        let dialect = &self.selected_file.read_options.dialect;
+        writeln!(
+            writer,
+            "{}",
+            Dialect::char_to_string(Some(dialect.delimiter))
+        )?;
+        writeln!(writer, "{}", Dialect::char_to_string(Some(dialect.quote)))?;
+        writeln!(writer, "{}", Dialect::char_to_string(dialect.escape))?;
+        writeln!(writer, "{}", dialect.terminator.to_string())?;
+        writeln!(writer, "{}", self.selected_file.read_options.encoding)?;
+        writeln!(writer, "{}", self.selected_file.read_options.has_header)?;
+        writeln!(
+            writer,
+            "{}",
+            serde_json::to_string(&self.selected_file.read_options.headers)?
+        )?;
+        writeln!(
+            writer,
+            "{}",
+            self.selected_file.read_options.row_policy.to_string()
+        )?;
+        writeln!(writer, "{}", self.selected_file.read_options.source_column)?;
+
+        let join = &self.selected_file.read_options.join;
+        writeln!(writer, "{}", join.file_path)?;
+        writeln!(writer, "{}", join.join_type.to_string())?;
+        writeln!(writer, "{}", serde_json::to_string(&join.left_keys)?)?;
+        writeln!(writer, "{}", serde_json::to_string(&join.right_keys)?)?;
+        writeln!(writer, "{}", serde_json::to_string(&join.columns)?)?;
+        writeln!(writer, "{}", self.selected_file.read_options.query)?;
+
+        let schema: Vec<[String; 4]> = self
+            .selected_file
+            .read_options
+            .schema
+            .iter()
+            .map(|column_type| {
+                [
+                    column_type.header.clone(),
+                    column_type.data_type.to_string().to_string(),
+                    column_type.date_format.clone(),
+                    column_type.decimal_separator.to_string(),
+                ]
+            })
+            .collect();
+        writeln!(writer, "{}", serde_json::to_string(&schema)?)?;
+
+        let sampling = &self.selected_file.read_options.sampling;
+        writeln!(writer, "{}", sampling.mode.to_string())?;
+        writeln!(writer, "{}", sampling.size)?;
+        writeln!(writer, "{}", sampling.seed)?;
+        writeln!(writer, "{}", sampling.column)?;
+        writeln!(writer, "{}", sampling.step)?;
+        writeln!(writer, "{}", sampling.for_models)?;
+
+        // Added after the column options, the projects saved before still load
+        let keep_if_match: Vec<(usize, bool, String)> = self
+            .column_options
+            .iter()
+            .map(|column| {
+                (
+                    column.index,
+                    column.save_options.filter.keep_row_if_match,
+                    column.save_options.filter.keep_row_if_match_text.clone(),
+                )
+            })
+            .collect();
+        writeln!(writer, "{}", serde_json::to_string(&keep_if_match)?)?;
+
+        let sort: Vec<(String, bool)> = self
+            .selected_file
+            .read_options
+            .sort
+            .iter()
+            .map(|key| (key.header.clone(), key.ascending))
+            .collect();
+        writeln!(writer, "{}", serde_json::to_string(&sort)?)?;
+
+        let keep_if_range: Vec<(usize, bool, String, String, String, String)> = self
+            .column_options
+            .iter()
+            .map(|column| {
+                let filter = &column.save_options.filter;
+                (
+                    column.index,
+                    filter.keep_row_if_range,
+                    filter.keep_row_if_range_comparison.to_string().to_string(),
+                    filter.keep_row_if_range_from.clone(),
+                    filter.keep_row_if_range_to.clone(),
+                    filter.keep_row_if_range_unparsed.to_string().to_string(),
+                )
+            })
+            .collect();
+        writeln!(writer, "{}", serde_json::to_string(&keep_if_range)?)?;
+
+        // The row index is kept out of the folder of the data, where the project finds it
+        let index_directory = RowIndex::get_index_directory(&self.selected_file.read_options);
+        writeln!(writer, "{}", index_directory.to_string_lossy())?;
+
         Ok(())
     }
 
@@ -220,6 +715,11 @@ impl FastFileFlow {
             }
         }
 
+        // Projects saved before a section existed simply end earlier
+        fn next_optional_line(lines: &mut std::io::Lines<BufReader<File>>) -> Option<String> {
+            lines.next().and_then(|line| line.ok())
+        }
+
         fn parse_line<T: std::str::FromStr>(
             lines: &mut std::io::Lines<BufReader<File>>,
         ) -> std::result::Result<T, io::Error>
@@ -231,14 +731,34 @@ impl FastFileFlow {
                 .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "Parse error"))
         }
 
-        // Deserializa selected_file
+        // Deserializa selected_file, a dataset is saved with its directory or pattern
+        let load_path = next_line(&mut lines)?;
+        let dataset = match Dataset::is_dataset_path(&load_path) {
+            true => Dataset::new(load_path.clone()),
+            false => Dataset::default(),
+        };
         self.selected_file = StoredFile {
-            file_path: next_line(&mut lines)?,
+            file_path: dataset.sources.first().cloned().unwrap_or(load_path),
             encoding: next_line(&mut lines)?,
             size: parse_line::<f64>(&mut lines)?,
             format: next_line(&mut lines)?,
             sintaxis: FileType::from_string(&next_line(&mut lines)?),
+            compression: Compression::default(),
+            dataset,
+            cache: std::sync::Arc::new(crate::stored_file::column_cache::ColumnCache::default()),
+            row_index: None,
+            join_table: None,
+            rejected_rows: std::sync::Arc::new(
+                crate::stored_file::row_policy::RejectedRows::default(),
+            ),
+            file_stamp: crate::stored_file::file_stamp::FileStamp::default(),
+            load_error: None,
+            task: crate::task_progress::TaskProgress::default(),
+            sheets: vec![],
+            read_options: ReadOptions::default(),
             rows: crate::stored_file::row_stored::RowStored::empty(),
+            sampled_rows: std::sync::Arc::new(vec![]),
+            sorted_file: None,
             columns: crate::stored_file::column_stored::ColumnStored::empty(),
             file_name: String::from(""),
             k_means: KMeansClustering::new(),
@@ -260,6 +780,7 @@ impl FastFileFlow {
                 ignore_column: parse_line::<bool>(&mut lines)?,
                 ignore_row_if: parse_line::<bool>(&mut lines)?,
                 ignore_row_if_text: next_line(&mut lines)?,
+                ..FilterOption::default()
             };
 
             let process = ProcessOption {
@@ -281,7 +802,104 @@ impl FastFileFlow {
             });
         }
 
-        self.file_loaded = self.selected_file.file_path.clone();
+        // Deserializa read_options
+        let read_options = &mut self.selected_file.read_options;
+        if let Some(sheet) = next_optional_line(&mut lines) {
+            read_options.sheet = sheet;
+            read_options.header_row = parse_line::<usize>(&mut lines).unwrap_or_default();
+        }
+        if let Some(delimiter) = next_optional_line(&mut lines) {
+            read_options.dialect = Dialect::new(
+                Dialect::parse_char(&delimiter).unwrap_or(b','),
+                Dialect::parse_char(&next_line(&mut lines)?).unwrap_or(b'"'),
+                Dialect::parse_char(&next_line(&mut lines)?),
+                LineTerminator::from_string(&next_line(&mut lines)?),
+            );
+        }
+        if let Some(encoding) = next_optional_line(&mut lines) {
+            read_options.encoding = encoding;
+        }
+        if let Some(has_header) = next_optional_line(&mut lines) {
+            read_options.has_header = has_header.parse::<bool>().unwrap_or(true);
+            read_options.headers = serde_json::from_str(&next_line(&mut lines)?)?;
+        }
+        if let Some(row_policy) = next_optional_line(&mut lines) {
+            read_options.row_policy = RowPolicy::from_string(&row_policy);
+        }
+        if let Some(source_column) = next_optional_line(&mut lines) {
+            read_options.source_column = source_column.parse::<bool>().unwrap_or(true);
+        }
+        if let Some(join_file) = next_optional_line(&mut lines) {
+            read_options.join = Join::new(
+                join_file,
+                JoinType::from_string(&next_line(&mut lines)?),
+                serde_json::from_str(&next_line(&mut lines)?)?,
+                serde_json::from_str(&next_line(&mut lines)?)?,
+                serde_json::from_str(&next_line(&mut lines)?)?,
+            );
+        }
+        if let Some(query) = next_optional_line(&mut lines) {
+            read_options.query = query;
+        }
+        if let Some(schema) = next_optional_line(&mut lines) {
+            let schema: Vec<[String; 4]> = serde_json::from_str(&schema)?;
+            read_options.schema = schema
+                .into_iter()
+                .map(|[header, data_type, date_format, decimal_separator]| {
+                    ColumnType::new(
+                        header,
+                        DataType::from_string(&data_type),
+                        date_format,
+                        decimal_separator.chars().next().unwrap_or('.'),
+                    )
+                })
+                .collect();
+        }
+        if let Some(sample_mode) = next_optional_line(&mut lines) {
+            read_options.sampling = Sampling::new(
+                SampleMode::from_string(&sample_mode),
+                parse_line::<usize>(&mut lines)?,
+                parse_line::<u64>(&mut lines)?,
+                next_line(&mut lines)?,
+                parse_line::<u64>(&mut lines)?,
+                parse_line::<bool>(&mut lines)?,
+            );
+        }
+        if let Some(keep_if_match) = next_optional_line(&mut lines) {
+            let keep_if_match: Vec<(usize, bool, String)> = serde_json::from_str(&keep_if_match)?;
+            for (index, keep, text) in keep_if_match {
+                if let Some(column) = self.column_options.iter_mut().find(|c| c.index == index) {
+                    column.save_options.filter.keep_row_if_match = keep;
+                    column.save_options.filter.keep_row_if_match_text = text;
+                }
+            }
+        }
+        if let Some(sort) = next_optional_line(&mut lines) {
+            let sort: Vec<(String, bool)> = serde_json::from_str(&sort)?;
+            read_options.sort = sort
+                .into_iter()
+                .map(|(header, ascending)| SortKey::new(header, ascending))
+                .collect();
+        }
+        if let Some(keep_if_range) = next_optional_line(&mut lines) {
+            let keep_if_range: Vec<(usize, bool, String, String, String, String)> =
+                serde_json::from_str(&keep_if_range)?;
+            for (index, keep, comparison, from, to, unparsed) in keep_if_range {
+                if let Some(column) = self.column_options.iter_mut().find(|c| c.index == index) {
+                    let filter = &mut column.save_options.filter;
+                    filter.keep_row_if_range = keep;
+                    filter.keep_row_if_range_comparison = Comparison::from_string(&comparison);
+                    filter.keep_row_if_range_from = from;
+                    filter.keep_row_if_range_to = to;
+                    filter.keep_row_if_range_unparsed = UnparsedPolicy::from_string(&unparsed);
+                }
+            }
+        }
+        if let Some(index_directory) = next_optional_line(&mut lines) {
+            self.selected_file.read_options.index_directory = index_directory;
+        }
+
+        self.file_loaded = self.selected_file.get_load_path().to_string();
 
         Ok(())
     }
@@ -326,7 +944,7 @@ impl FastFileFlow {
             tooltip::Position::Right,
         );
 
-        let _search_text_input: TextInput<'_, FastFileFlowMessage> =
+        let search_text_input: TextInput<'_, FastFileFlowMessage> =
             text_input(SEARCH_PLACEHOLDER, self.search_value.as_str())
                 .on_input(FastFileFlowMessage::TextBoxChange)
                 .on_submit(FastFileFlowMessage::SearchOnSubmit())
@@ -340,6 +958,42 @@ impl FastFileFlow {
                     spacing: 10.0,
                     side: text_input::Side::Left,
                 });
+        let columns_label = match self.search_columns.len() {
+            0 => SEARCH_ALL_COLUMNS.to_string(),
+            1 => self.search_columns[0].clone(),
+            count => SEARCH_COLUMNS_LABEL.replace("{}", &count.to_string()),
+        };
+        let button_columns = Button::new(Text::new(columns_label).size(12.0))
+            .width(Length::Fixed(120.0))
+            .on_press(FastFileFlowMessage::SearchColumnsClick());
+        let search_options = row![
+            button_columns,
+            TAB_SPACE,
+            checkbox(SEARCH_CASE_LABEL, self.search_case_sensitive)
+                .size(Pixels(14.0))
+                .on_toggle(FastFileFlowMessage::SearchCaseSensitiveToggled),
+            TAB_SPACE,
+            checkbox(SEARCH_WORD_LABEL, self.search_whole_word)
+                .size(Pixels(14.0))
+                .on_toggle(FastFileFlowMessage::SearchWholeWordToggled),
+            TAB_SPACE,
+            checkbox(SEARCH_REGEX_LABEL, self.search_regex)
+                .size(Pixels(14.0))
+                .on_toggle(FastFileFlowMessage::SearchRegexToggled),
+            TAB_SPACE,
+            Button::new(Text::new(BUTTON_CLEAR).size(12.0))
+                .on_press(FastFileFlowMessage::SearchClearClick()),
+            TAB_SPACE,
+            Button::new(Text::new(BUTTON_PREVIOUS).size(12.0))
+                .on_press(FastFileFlowMessage::SearchPreviousClick()),
+            Button::new(Text::new(BUTTON_NEXT).size(12.0))
+                .on_press(FastFileFlowMessage::SearchNextClick()),
+            TAB_SPACE,
+            get_text_size(self.get_search_match_label(), false, Pixels(10.0)),
+        ]
+        .align_items(iced::Alignment::Center)
+        .padding(Padding::from([8.0, 0.0, 0.0, 10.0]));
+
         let button_user = get_menu_button(USER, FastFileFlowMessage::UserButtonClick(), USER_ICON);
 
         let button_menu = get_menu_button(MENU, FastFileFlowMessage::MenuButtonClick(), MENU_ICON);
@@ -348,7 +1002,9 @@ impl FastFileFlow {
         let header = row![
             image,
             horizontal_space(),
-            //search_text_input,
+            search_text_input,
+            search_options,
+            TAB_SPACE,
             button_user,
             TAB_SPACE,
             button_menu
@@ -358,57 +1014,16 @@ impl FastFileFlow {
 
     fn build_panels(&self) -> Row<'_, FastFileFlowMessage, Theme, iced::Renderer> {
         let panel_file_details = column![
-            row![
-                get_text("Filename:", false),
-                get_text_size(self.selected_file.file_name.as_str(), true, Pixels(9.0))
-            ],
-            row![
-                get_text("Encoding:", false),
-                get_text(self.selected_file.encoding.as_str(), true)
-            ],
-            row![
-                get_text("Size:", false),
-                get_text(self.selected_file.size_mb_as_str(), true)
-            ],
+            row![get_text("Filename:", false), self.build_file_name_detail()],
+            row![get_text("Encoding:", false), self.build_encoding_detail()],
+            row![get_text("Size:", false), self.build_size_detail()],
             row![
                 get_text("Format:", false),
-                get_text(self.selected_file.format.as_str(), true)
-            ],
-            row![
-                get_text("Sintaxis:", false),
-                get_text(
-                    format!("{}", self.selected_file.sintaxis.to_string()).as_str(),
-                    true
-                )
-            ],
-            row![
-                get_text("Rows:", false),
-                get_text(
-                    format!(
-                        "{}",
-                        self.selected_file
-                            .rows
-                            .total
-                            .to_formatted_string(&Locale::en)
-                    )
-                    .as_str(),
-                    true
-                )
+                get_text(self.selected_file.get_format_label(), true)
             ],
-            row![
-                get_text("Columns:", false),
-                get_text(
-                    format!(
-                        "{}",
-                        self.selected_file
-                            .columns
-                            .total
-                            .to_formatted_string(&Locale::en)
-                    )
-                    .as_str(),
-                    true
-                )
-            ]
+            row![get_text("Sintaxis:", false), self.build_sintaxis_detail()],
+            row![get_text("Rows:", false), self.build_rows_detail()],
+            row![get_text("Columns:", false), self.build_columns_detail()]
         ];
 
         let container_file_details = create_section_container(panel_file_details);
@@ -588,12 +1203,19 @@ impl FastFileFlow {
             OPEN_ICON,
         );
 
+        let button_dataset =
+            get_menu_button(ADD, FastFileFlowMessage::DatasetButtonClick(), DATASET_ICON);
+
         let button_refresh = get_menu_button(
             REFRESH,
             FastFileFlowMessage::LoadFileButtonClick(true),
             REFRESH_ICON,
         );
 
+        let checkbox_watch = checkbox(WATCH_FILE_LABEL, self.watch_file)
+            .size(Pixels(14.0))
+            .on_toggle(FastFileFlowMessage::WatchFileToggled);
+
         let button_filter = get_menu_button(
             FILTER,
             FastFileFlowMessage::ShowFilterButtonClick(),
@@ -623,6 +1245,36 @@ impl FastFileFlow {
             PIPELINE_ICON,
         );
 
+        let button_schema = get_menu_button(
+            SCRIPT,
+            FastFileFlowMessage::SchemaButtonClick(),
+            SCHEMA_ICON,
+        );
+
+        let button_profile = get_menu_button(
+            MENU,
+            FastFileFlowMessage::ProfileButtonClick(),
+            PROFILE_ICON,
+        );
+
+        let button_sample = get_menu_button(
+            FILTER,
+            FastFileFlowMessage::SampleButtonClick(),
+            SAMPLE_ICON,
+        );
+
+        let button_sort =
+            get_menu_button(PROCESS, FastFileFlowMessage::SortButtonClick(), SORT_ICON);
+
+        let button_query =
+            get_menu_button(SCRIPT, FastFileFlowMessage::QueryButtonClick(), QUERY_ICON);
+
+        let button_pivot = get_menu_button(
+            PIPELINE,
+            FastFileFlowMessage::PivotButtonClick(),
+            PIVOT_ICON,
+        );
+
         let button_analysis = get_menu_button(
             ANALYSIS,
             FastFileFlowMessage::AnalysisButtonClick(),
@@ -652,15 +1304,37 @@ impl FastFileFlow {
         let error_label = row![get_text(&self.notification_message, true)]
             .padding(Padding::from([10.0, 0.0, 0.0, 0.0]));
 
+        let mut button_reload = Button::new(Text::new(BUTTON_RELOAD).size(12.0));
+        if self.file_rewritten {
+            button_reload = button_reload.on_press(FastFileFlowMessage::LoadFileButtonClick(true));
+        }
+        let reload = row![button_reload].padding(Padding::from([6.0, 0.0, 0.0, 0.0]));
+
         row![
             button_open,
             TAB_SPACE,
+            button_dataset,
+            TAB_SPACE,
             button_refresh,
             TAB_SPACE,
+            row![checkbox_watch].padding(Padding::from([10.0, 0.0, 0.0, 0.0])),
+            TAB_SPACE,
             button_filter,
             TAB_SPACE,
             button_process,
             TAB_SPACE,
+            button_schema,
+            TAB_SPACE,
+            button_profile,
+            TAB_SPACE,
+            button_sample,
+            TAB_SPACE,
+            button_sort,
+            TAB_SPACE,
+            button_query,
+            TAB_SPACE,
+            button_pivot,
+            TAB_SPACE,
             // button_add,
             // TAB_SPACE,
             // button_script,
@@ -677,7 +1351,9 @@ impl FastFileFlow {
             TAB_SPACE,
             button_export,
             TAB_SPACE,
-            error_label
+            error_label,
+            TAB_SPACE,
+            reload
         ]
         .padding([10.0, 50.0, 10.0, 0.0])
         .into()
@@ -685,11 +1361,15 @@ impl FastFileFlow {
 
     fn build_table(&self) -> Row<'_, FastFileFlowMessage, Theme, iced::Renderer> {
         let table = responsive(|size| {
+            let visible_rows = ((size.height - TABLE_HEADER_HEIGHT) / TABLE_ROW_HEIGHT)
+                .floor()
+                .max(1.0) as usize;
+
             let mut table = table(
                 self.header.clone(),
                 self.body.clone(),
                 &self.columns,
-                &self.rows,
+                self.get_visible_rows(visible_rows),
                 FastFileFlowMessage::SyncHeader,
             );
 
@@ -702,7 +1382,24 @@ impl FastFileFlow {
             table.into()
         });
 
-        row![table].padding([0.0, 0.0, 5.0, 0.0])
+        row![table, self.build_table_scroller()].padding([0.0, 0.0, 5.0, 0.0])
+    }
+
+    fn build_table_scroller(&self) -> Container<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let last_row = self.get_table_total_rows().saturating_sub(1) as f64;
+        let scroller = vertical_slider(
+            0.0..=last_row,
+            last_row - self.row_cursor as f64,
+            move |value| FastFileFlowMessage::TableSliderChanged(last_row - value),
+        )
+        .step(1.0)
+        .width(TABLE_SLIDER_WIDTH)
+        .height(Length::Fill)
+        .on_release(FastFileFlowMessage::TableSliderReleased());
+
+        container(scroller)
+            .height(Length::Fill)
+            .padding(Padding::from([TABLE_HEADER_HEIGHT, 0.0, 0.0, 5.0]))
     }
 
     fn build_status(&self) -> Row<'_, FastFileFlowMessage, Theme, iced::Renderer> {
@@ -710,11 +1407,77 @@ impl FastFileFlow {
             .width(Length::Fill)
             .size(Pixels(PANEL_FONT_SIZE));
 
-        let loader = row![selected_file, horizontal_space(), self.build_linear()];
+        let loader = row![
+            selected_file,
+            horizontal_space(),
+            self.build_table_navigation(),
+            TAB_SPACE,
+            self.build_linear()
+        ];
 
         loader
     }
 
+    fn build_table_navigation(&self) -> Row<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let mut position = format!(
+            "Row {} of {}",
+            (self.row_cursor + 1).to_formatted_string(&Locale::en),
+            self.get_table_total_rows().to_formatted_string(&Locale::en)
+        );
+        if self.page == Page::Main && self.is_searched() {
+            let file_row = self
+                .rows
+                .get(self.row_cursor.saturating_sub(self.rows_offset) as usize)
+                .map(|row| (row.row_index + 1).to_formatted_string(&Locale::en))
+                .unwrap_or_default();
+            position = SEARCH_ROWS_LABEL
+                .replacen(
+                    "{}",
+                    &(self.row_cursor + 1).to_formatted_string(&Locale::en),
+                    1,
+                )
+                .replacen("{}", &self.rows.len().to_formatted_string(&Locale::en), 1)
+                .replacen("{}", &file_row, 1);
+        } else if self.page == Page::Main && self.is_sampled() {
+            position.push_str(&format!(
+                " ({} sample)",
+                self.selected_file.read_options.sampling.mode.to_string()
+            ));
+        } else if self.page == Page::Main && self.selected_file.get_sorted_file().is_some() {
+            position.push_str(SORT_TABLE_LABEL);
+        } else if let Some(query_result) = self
+            .query_result
+            .as_ref()
+            .filter(|_| self.page == Page::Query)
+        {
+            position = QUERY_ROWS_LABEL
+                .replacen(
+                    "{}",
+                    &(self.row_cursor + 1).to_formatted_string(&Locale::en),
+                    1,
+                )
+                .replacen("{}", &self.rows.len().to_formatted_string(&Locale::en), 1)
+                .replacen(
+                    "{}",
+                    &query_result.query.total.to_formatted_string(&Locale::en),
+                    1,
+                );
+        }
+
+        let go_to_row = text_input(GO_TO_ROW_PLACEHOLDER, self.go_to_row.as_str())
+            .on_input(FastFileFlowMessage::GoToRowChange)
+            .on_submit(FastFileFlowMessage::GoToRowSubmit())
+            .width(Fixed(90.0))
+            .size(PANEL_FONT_SIZE);
+
+        row![
+            Text::new(position).size(Pixels(PANEL_FONT_SIZE)),
+            TAB_SPACE,
+            go_to_row
+        ]
+        .align_items(iced::Alignment::Center)
+    }
+
     fn show_filter_screen(&self) -> Element<'_, FastFileFlowMessage, Theme, iced::Renderer> {
         let container_correlation = self.build_filter_panel().height(PANEL_HEIGHT + 50.0);
         let container_analysis = self.build_filter_statistics().height(PANEL_HEIGHT + 50.0);
@@ -822,7 +1585,7 @@ impl FastFileFlow {
         let close_button =
             Button::new(Text::new(BUTTON_CLOSE)).on_press(FastFileFlowMessage::Router(Page::Main));
 
-        let combo_box = self.build_header_combo_box();
+        let combo_column = self.build_header_combo_box();
         if self.column_option_selected != Option::None {
             let index = self
                 .column_option_selected
@@ -869,8 +1632,94 @@ impl FastFileFlow {
                 })
                 .size(10.0);
 
+            let checkbox_keep_if_match = self.build_checkbox(
+                index,
+                filter.keep_row_if_match,
+                OptionType::FilterKeepIfMatch,
+                "Keep row if matches".to_string(),
+                FastFileFlowMessage::FilterEvent,
+            );
+
+            let text_keep_if_match_value =
+                text_input("regex", &filter.keep_row_if_match_text.as_str())
+                    .on_input(move |value| {
+                        FastFileFlowMessage::FilterTextEvent(
+                            index,
+                            value,
+                            OptionType::FilterKeepIfMatch,
+                        )
+                    })
+                    .size(10.0);
+
+            let checkbox_keep_if_range = self.build_checkbox(
+                index,
+                filter.keep_row_if_range,
+                OptionType::FilterKeepIfRange,
+                FILTER_RANGE_LABEL.to_string(),
+                FastFileFlowMessage::FilterEvent,
+            );
+
+            let combo_comparison = combo_box(
+                &self.filter_comparison_state,
+                COMPARISON_GREATER_OR_EQUAL,
+                self.filter_comparison.as_ref(),
+                move |value| {
+                    FastFileFlowMessage::FilterTextEvent(
+                        index,
+                        value,
+                        OptionType::FilterRangeComparison,
+                    )
+                },
+            )
+            .size(10.0)
+            .width(Length::Fixed(90.0));
+
+            let text_range_from = text_input(
+                FILTER_RANGE_FROM_PLACEHOLDER,
+                filter.keep_row_if_range_from.as_str(),
+            )
+            .on_input(move |value| {
+                FastFileFlowMessage::FilterTextEvent(index, value, OptionType::FilterKeepIfRange)
+            })
+            .size(10.0);
+
+            let mut row_keep_if_range = row![
+                (column![checkbox_keep_if_range]).padding(Padding::from([3, 0, 0, 0])),
+                TAB_SPACE,
+                combo_comparison,
+                TAB_SPACE,
+                text_range_from
+            ];
+            // The ranges take the other end
+            if filter.keep_row_if_range_comparison.is_range() {
+                let text_range_to = text_input(
+                    FILTER_RANGE_TO_PLACEHOLDER,
+                    filter.keep_row_if_range_to.as_str(),
+                )
+                .on_input(move |value| {
+                    FastFileFlowMessage::FilterTextEvent(index, value, OptionType::FilterRangeTo)
+                })
+                .size(10.0);
+                row_keep_if_range = row_keep_if_range.push(TAB_SPACE).push(text_range_to);
+            }
+
+            let combo_unparsed = combo_box(
+                &self.filter_unparsed_state,
+                UNPARSED_POLICY_SKIP,
+                self.filter_unparsed.as_ref(),
+                move |value| {
+                    FastFileFlowMessage::FilterTextEvent(
+                        index,
+                        value,
+                        OptionType::FilterRangeUnparsed,
+                    )
+                },
+            )
+            .size(10.0)
+            .width(Length::Fixed(120.0));
+
             let panel_dropdown = column![
-                row![combo_box],
+                row![combo_column],
                 row![TAB_SPACE, horizontal_space()],
                 row![
                     (column![checkbox_ignore_column]).padding(Padding::from([3, 0, 0, 0])),
@@ -887,13 +1736,26 @@ impl FastFileFlow {
                     TAB_SPACE,
                     text_ignore_if_value
                 ],
+                row![
+                    (column![checkbox_keep_if_match]).padding(Padding::from([3, 0, 0, 0])),
+                    TAB_SPACE,
+                    text_keep_if_match_value
+                ],
+                row_keep_if_range,
+                row![
+                    get_text_size(FILTER_UNPARSED_LABEL, false, Pixels(10.0)),
+                    TAB_SPACE,
+                    combo_unparsed,
+                    horizontal_space()
+                ]
+                .padding(Padding::from([3, 0, 0, 0])),
                 row![TAB_SPACE, horizontal_space()],
                 row![TAB_SPACE, horizontal_space(), close_button],
             ];
             create_section_container_width(panel_dropdown, PANEL_WIDTH + 100.0)
         } else {
             let panel_dropdown = column![
-                row![combo_box],
+                row![combo_column],
                 row![TAB_SPACE, horizontal_space()],
                 row![TAB_SPACE, horizontal_space(), close_button],
             ];
@@ -1190,20 +2052,134 @@ impl FastFileFlow {
         container_analysis
     }
 
-    fn show_user_screen(&self) -> Element<'_, FastFileFlowMessage, Theme, iced::Renderer> {
-        let container_user = self.build_user_panel().height(PANEL_HEIGHT + 100.0);
+    fn build_sintaxis_detail(&self) -> Element<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let read_options = &self.selected_file.read_options;
+        let sintaxis = self.selected_file.sintaxis.to_string();
 
-        let render = column![
-            row![container_user, TAB_SPACE, horizontal_space(),],
-            row![TAB_SPACE],
-            row![TAB_SPACE, horizontal_space(), self.build_linear(),],
-            row![TAB_SPACE],
+        match self.selected_file.sintaxis {
+            FileType::Excel => self.build_detail_button(
+                format!("{} ({})", sintaxis, read_options.sheet),
+                Page::Workbook,
+            ),
+            FileType::SQLite => {
+                let source = match read_options.query.is_empty() {
+                    true => read_options.sheet.as_str(),
+                    false => DATABASE_QUERY_LABEL,
+                };
+                self.build_detail_button(format!("{} ({})", sintaxis, source), Page::Database)
+            }
+            FileType::CSV => self.build_detail_button(
+                format!("{} ({})", sintaxis, read_options.dialect),
+                Page::Dialect,
+            ),
+            _ => get_text(sintaxis, true).into(),
+        }
+    }
+
+    fn build_file_name_detail(&self) -> Element<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let file_name = self.selected_file.file_name.as_str();
+        if !self.selected_file.is_dataset() {
+            return get_text_size(file_name, true, Pixels(9.0)).into();
+        }
+
+        let files = DATASET_FILES_LABEL
+            .replace("{}", &self.selected_file.dataset.sources.len().to_string());
+        self.build_detail_button(format!("{} ({})", file_name, files), Page::Dataset)
+    }
+
+    fn build_columns_detail(&self) -> Element<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let columns = self
+            .selected_file
+            .columns
+            .total
+            .to_formatted_string(&Locale::en);
+
+        match &self.selected_file.join_table {
+            Some(join_table) if !join_table.headers.is_empty() => self.build_detail_button(
+                format!(
+                    "{} ({})",
+                    columns,
+                    JOIN_COLUMNS_LABEL.replace("{}", &join_table.headers.len().to_string())
+                ),
+                Page::Join,
+            ),
+            _ => self.build_detail_button(columns, Page::Join),
+        }
+    }
+
+    fn build_rows_detail(&self) -> Element<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let rows = self
+            .selected_file
+            .rows
+            .total
+            .to_formatted_string(&Locale::en);
+        let rejected = self.selected_file.rejected_rows.total;
+
+        match rejected {
+            0 => self.build_detail_button(rows, Page::Rejected),
+            _ => self.build_detail_button(
+                format!(
+                    "{} ({})",
+                    rows,
+                    REJECTED_LABEL.replace("{}", &rejected.to_formatted_string(&Locale::en))
+                ),
+                Page::Rejected,
+            ),
+        }
+    }
+
+    fn build_encoding_detail(&self) -> Element<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let encoding = self.selected_file.get_encoding_label().to_string();
+
+        match self.selected_file.sintaxis {
+            FileType::CSV | FileType::JSON | FileType::NDJSON => {
+                self.build_detail_button(encoding, Page::Encoding)
+            }
+            _ => get_text(encoding, true).into(),
+        }
+    }
+
+    fn build_size_detail(&self) -> Element<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let size = self.selected_file.size_mb_as_str();
+        let status = self.selected_file.cache.get_status();
+        let detail = match status.is_empty() {
+            true => size,
+            false => format!("{} ({})", size, status),
+        };
+
+        self.build_detail_button(detail, Page::Cache)
+    }
+
+    /// File details that can be changed open the page holding their options.
+    fn build_detail_button(
+        &self,
+        detail: String,
+        page: Page,
+    ) -> Element<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        if !self.is_file_loaded() {
+            return get_text(detail, true).into();
+        }
+
+        Button::new(get_text(detail, true))
+            .padding(0)
+            .style(iced::theme::Button::Text)
+            .on_press(FastFileFlowMessage::Router(page))
+            .into()
+    }
+
+    fn show_options_screen<'a>(
+        &'a self,
+        panel: Container<'a, FastFileFlowMessage, Theme, iced::Renderer>,
+    ) -> Element<'a, FastFileFlowMessage, Theme, iced::Renderer> {
+        let render = row![
+            panel.height(PANEL_HEIGHT + 50.0),
+            horizontal_space(),
+            column![vertical_space(), self.build_linear()]
         ];
         let border = Border {
-            color: Color::from_rgb(0.315, 0.315, 0.315).into(),
+            color: Color::from_rgb(0.315, 0.315, 0.315),
             width: 1.0,
             radius: 40.0.into(),
-            ..Default::default()
         };
 
         container(render)
@@ -1217,43 +2193,1715 @@ impl FastFileFlow {
             .into()
     }
 
-    fn build_user_panel(&self) -> Container<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+    fn build_workbook_panel(&self) -> Container<'_, FastFileFlowMessage, Theme, iced::Renderer> {
         let close_button =
             Button::new(Text::new(BUTTON_CLOSE)).on_press(FastFileFlowMessage::Router(Page::Main));
+        let apply_button = Button::new(Text::new(BUTTON_APPLY))
+            .on_press(FastFileFlowMessage::WorkbookApplyClick());
 
-        let panel_user = column![
-            row![TAB_SPACE, horizontal_space()],
-            row![get_text("About: Fast FIle Flow", true)],
+        let combo_box = combo_box(
+            &self.workbook_sheet_state,
+            WORKBOOK_SHEET_PLACEHOLDER,
+            self.workbook_sheet.as_ref(),
+            FastFileFlowMessage::WorkbookSheetSelected,
+        )
+        .size(12.0)
+        .width(Length::Fill);
+
+        let text_header_row = text_input(
+            WORKBOOK_HEADER_ROW_PLACEHOLDER,
+            self.workbook_header_row.as_str(),
+        )
+        .on_input(FastFileFlowMessage::WorkbookHeaderRowChange)
+        .on_submit(FastFileFlowMessage::WorkbookApplyClick())
+        .size(12.0)
+        .width(Length::Fixed(80.0));
+
+        let panel_workbook = column![
+            row![get_text(WORKBOOK_TITLE, true)
+                .height(Length::Fixed(24.0))
+                .width(Length::Fixed(PANEL_WIDTH))],
+            row![combo_box],
             row![TAB_SPACE, horizontal_space()],
-            row![get_text_size(
-                " Desarrollada por el alumno Marco Antonio Guzman Rodriguez para UNIR como parte de su Trabajo de Fin de Master titulado 'Big Data and Data Visualization'
-Mexico 2024",
-                true,
-                Pixels(PANEL_FONT_SIZE)
-            )],
+            row![
+                get_text("Header row:", false),
+                TAB_SPACE,
+                text_header_row,
+                horizontal_space()
+            ],
             row![TAB_SPACE, horizontal_space()],
-            row![TAB_SPACE, vertical_space()],
-            row![TAB_SPACE, horizontal_space(), close_button],
+            row![
+                TAB_SPACE,
+                horizontal_space(),
+                apply_button,
+                TAB_SPACE,
+                close_button
+            ],
         ];
-        create_section_container_width(panel_user, PANEL_WIDTH + 100.0)
+        create_section_container_width(panel_workbook, PANEL_WIDTH + 100.0)
     }
 
-    fn build_linear(&self) -> Linear<'_, Theme> {
-        if self.running {
-            Linear::new(340.0, 15.0)
-                .easing(&easing::EMPHASIZED_ACCELERATE)
-                .cycle_duration(Duration::from_secs_f32(2_f32))
-        } else {
-            Linear::default().height(15.0)
-        }
-    }
+    fn build_database_panel(&self) -> Container<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let close_button =
+            Button::new(Text::new(BUTTON_CLOSE)).on_press(FastFileFlowMessage::Router(Page::Main));
+        let apply_button = Button::new(Text::new(BUTTON_APPLY))
+            .on_press(FastFileFlowMessage::DatabaseApplyClick());
 
-    fn build_checkbox<F>(
-        &self,
-        index: usize,
-        checked: bool,
-        option_type: OptionType,
-        label: String,
+        let combo_box = combo_box(
+            &self.database_table_state,
+            DATABASE_TABLE_PLACEHOLDER,
+            self.database_table.as_ref(),
+            FastFileFlowMessage::DatabaseTableSelected,
+        )
+        .size(12.0)
+        .width(Length::Fill);
+
+        let text_query = text_input(DATABASE_QUERY_PLACEHOLDER, self.database_query.as_str())
+            .on_input(FastFileFlowMessage::DatabaseQueryChange)
+            .on_submit(FastFileFlowMessage::DatabaseApplyClick())
+            .size(12.0)
+            .width(Length::Fill);
+
+        let panel_database = column![
+            row![get_text(DATABASE_TITLE, true)
+                .height(Length::Fixed(24.0))
+                .width(Length::Fixed(PANEL_WIDTH))],
+            row![get_text("Table:", false)],
+            row![combo_box],
+            row![TAB_SPACE, horizontal_space()],
+            row![get_text("Query, read instead of the table:", false)],
+            row![text_query],
+            row![TAB_SPACE, horizontal_space()],
+            row![
+                TAB_SPACE,
+                horizontal_space(),
+                apply_button,
+                TAB_SPACE,
+                close_button
+            ],
+        ];
+        create_section_container_width(panel_database, PANEL_WIDTH + 100.0)
+    }
+
+    fn build_dialect_panel(&self) -> Container<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let close_button =
+            Button::new(Text::new(BUTTON_CLOSE)).on_press(FastFileFlowMessage::Router(Page::Main));
+        let apply_button =
+            Button::new(Text::new(BUTTON_APPLY)).on_press(FastFileFlowMessage::DialectApplyClick());
+
+        let text_delimiter = text_input(DIALECT_DELIMITER_PLACEHOLDER, &self.dialect_delimiter)
+            .on_input(FastFileFlowMessage::DialectDelimiterChange)
+            .size(12.0)
+            .width(Length::Fixed(60.0));
+        let text_quote = text_input(DIALECT_QUOTE_PLACEHOLDER, &self.dialect_quote)
+            .on_input(FastFileFlowMessage::DialectQuoteChange)
+            .size(12.0)
+            .width(Length::Fixed(60.0));
+        let text_escape = text_input(DIALECT_ESCAPE_PLACEHOLDER, &self.dialect_escape)
+            .on_input(FastFileFlowMessage::DialectEscapeChange)
+            .size(12.0)
+            .width(Length::Fixed(60.0));
+        let combo_terminator = combo_box(
+            &self.dialect_terminator_state,
+            DIALECT_TERMINATOR_PLACEHOLDER,
+            self.dialect_terminator.as_ref(),
+            FastFileFlowMessage::DialectTerminatorSelected,
+        )
+        .size(12.0)
+        .width(Length::Fixed(120.0));
+
+        let checkbox_has_header = checkbox(DIALECT_HAS_HEADER_LABEL, self.dialect_has_header)
+            .size(Pixels(14.0))
+            .on_toggle(FastFileFlowMessage::DialectHasHeaderToggled);
+        let load_names_button = Button::new(Text::new(BUTTON_LOAD_NAMES).size(12.0))
+            .on_press(FastFileFlowMessage::DialectHeadersLoadClick());
+        let clear_names_button = Button::new(Text::new(BUTTON_CLEAR).size(12.0))
+            .on_press(FastFileFlowMessage::DialectHeadersClearClick());
+        let header_names = DIALECT_HEADER_NAMES_LABEL
+            .replace("{}", &self.dialect_headers.len().to_string())
+            .to_string();
+
+        let panel_dialect = column![
+            row![get_text(DIALECT_TITLE, true)
+                .height(Length::Fixed(24.0))
+                .width(Length::Fixed(PANEL_WIDTH))],
+            row![
+                get_text("Delimiter:", false),
+                text_delimiter,
+                TAB_SPACE,
+                get_text("Quote:", false),
+                text_quote,
+                horizontal_space()
+            ],
+            row![TAB_SPACE, horizontal_space()],
+            row![
+                get_text("Escape:", false),
+                text_escape,
+                TAB_SPACE,
+                get_text("Line end:", false),
+                combo_terminator,
+                horizontal_space()
+            ],
+            row![TAB_SPACE, horizontal_space()],
+            row![checkbox_has_header],
+            row![
+                get_text(header_names, false),
+                horizontal_space(),
+                load_names_button,
+                TAB_SPACE,
+                clear_names_button
+            ],
+            row![TAB_SPACE, horizontal_space()],
+            row![
+                TAB_SPACE,
+                horizontal_space(),
+                apply_button,
+                TAB_SPACE,
+                close_button
+            ],
+        ];
+        create_section_container_width(panel_dialect, PANEL_WIDTH + 100.0)
+    }
+
+    fn build_encoding_panel(&self) -> Container<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let close_button =
+            Button::new(Text::new(BUTTON_CLOSE)).on_press(FastFileFlowMessage::Router(Page::Main));
+        let apply_button = Button::new(Text::new(BUTTON_APPLY))
+            .on_press(FastFileFlowMessage::EncodingApplyClick());
+
+        let combo_box = combo_box(
+            &self.encoding_state,
+            ENCODING_PLACEHOLDER,
+            self.encoding_selected.as_ref(),
+            FastFileFlowMessage::EncodingSelected,
+        )
+        .size(12.0)
+        .width(Length::Fill);
+
+        let panel_encoding = column![
+            row![get_text(ENCODING_TITLE, true)
+                .height(Length::Fixed(24.0))
+                .width(Length::Fixed(PANEL_WIDTH))],
+            row![
+                get_text("Detected:", false),
+                get_text(self.selected_file.encoding.as_str(), true)
+            ],
+            row![TAB_SPACE, horizontal_space()],
+            row![combo_box],
+            row![TAB_SPACE, horizontal_space()],
+            row![
+                TAB_SPACE,
+                horizontal_space(),
+                apply_button,
+                TAB_SPACE,
+                close_button
+            ],
+        ];
+        create_section_container_width(panel_encoding, PANEL_WIDTH + 100.0)
+    }
+
+    fn build_rejected_panel(&self) -> Container<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let close_button =
+            Button::new(Text::new(BUTTON_CLOSE)).on_press(FastFileFlowMessage::Router(Page::Main));
+        let apply_button = Button::new(Text::new(BUTTON_APPLY))
+            .on_press(FastFileFlowMessage::RejectedApplyClick());
+        let mut quarantine_button = Button::new(Text::new(BUTTON_QUARANTINE));
+        if !self.selected_file.rejected_rows.rows.is_empty() {
+            quarantine_button =
+                quarantine_button.on_press(FastFileFlowMessage::RejectedExportClick());
+        }
+
+        let combo_box = combo_box(
+            &self.rejected_policy_state,
+            REJECTED_POLICY_PLACEHOLDER,
+            self.rejected_policy.as_ref(),
+            FastFileFlowMessage::RejectedPolicySelected,
+        )
+        .size(12.0)
+        .width(Length::Fixed(120.0));
+
+        let rejected_rows = &self.selected_file.rejected_rows;
+        let rows = rejected_rows
+            .rows
+            .iter()
+            .map(|row| get_text_size(format!("{} - {}", row, row.raw), false, Pixels(10.0)).into());
+
+        let panel_rejected = column![
+            row![get_text(REJECTED_TITLE, true)
+                .height(Length::Fixed(24.0))
+                .width(Length::Fixed(PANEL_WIDTH))],
+            row![
+                get_text("Bad rows:", false),
+                TAB_SPACE,
+                combo_box,
+                horizontal_space(),
+                get_text(
+                    REJECTED_LABEL.replace("{}", &rejected_rows.total.to_string()),
+                    true
+                )
+            ],
+            row![TAB_SPACE, horizontal_space()],
+            scrollable(Column::with_children(rows)).height(Length::Fixed(90.0)),
+            row![TAB_SPACE, horizontal_space()],
+            row![
+                quarantine_button,
+                horizontal_space(),
+                apply_button,
+                TAB_SPACE,
+                close_button
+            ],
+        ];
+        create_section_container_width(panel_rejected, PANEL_WIDTH + 100.0)
+    }
+
+    fn build_dataset_panel(&self) -> Container<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let close_button =
+            Button::new(Text::new(BUTTON_CLOSE)).on_press(FastFileFlowMessage::Router(Page::Main));
+        let browse_button = Button::new(Text::new(BUTTON_BROWSE))
+            .on_press(FastFileFlowMessage::DatasetBrowseClick());
+        let mut load_button = Button::new(Text::new(BUTTON_LOAD));
+        if !self.dataset_pattern.trim().is_empty() {
+            load_button = load_button.on_press(FastFileFlowMessage::DatasetLoadClick());
+        }
+
+        let text_pattern = text_input(DATASET_PATTERN_PLACEHOLDER, self.dataset_pattern.as_str())
+            .on_input(FastFileFlowMessage::DatasetPatternChange)
+            .on_submit(FastFileFlowMessage::DatasetLoadClick())
+            .size(12.0)
+            .width(Length::Fill);
+        let checkbox_source = checkbox(DATASET_SOURCE_COLUMN_LABEL, self.dataset_source_column)
+            .size(Pixels(14.0))
+            .on_toggle(FastFileFlowMessage::DatasetSourceColumnToggled);
+
+        let dataset = &self.selected_file.dataset;
+        let sources = dataset
+            .sources
+            .iter()
+            .map(|source| StoredFile::get_file_name(source))
+            .chain(dataset.mismatches.iter().cloned())
+            .map(|line| get_text_size(line, false, Pixels(10.0)).into());
+
+        let panel_dataset = column![
+            row![get_text(DATASET_TITLE, true)
+                .height(Length::Fixed(24.0))
+                .width(Length::Fixed(PANEL_WIDTH))],
+            row![text_pattern, TAB_SPACE, browse_button],
+            row![
+                checkbox_source,
+                horizontal_space(),
+                get_text(
+                    DATASET_FILES_LABEL.replace("{}", &dataset.sources.len().to_string()),
+                    true
+                )
+            ],
+            scrollable(Column::with_children(sources)).height(Length::Fixed(70.0)),
+            row![
+                TAB_SPACE,
+                horizontal_space(),
+                load_button,
+                TAB_SPACE,
+                close_button
+            ],
+        ];
+        create_section_container_width(panel_dataset, PANEL_WIDTH + 100.0)
+    }
+
+    fn build_join_panel(&self) -> Container<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let close_button =
+            Button::new(Text::new(BUTTON_CLOSE)).on_press(FastFileFlowMessage::Router(Page::Main));
+        let apply_button =
+            Button::new(Text::new(BUTTON_APPLY)).on_press(FastFileFlowMessage::JoinApplyClick());
+        let browse_button =
+            Button::new(Text::new(BUTTON_BROWSE)).on_press(FastFileFlowMessage::JoinBrowseClick());
+        let clear_button = Button::new(Text::new(BUTTON_CLEAR))
+            .on_press(FastFileFlowMessage::JoinClearKeysClick());
+        let mut add_key_button = Button::new(Text::new(BUTTON_ADD_KEY));
+        if self.join_left_key.is_some() && self.join_right_key.is_some() {
+            add_key_button = add_key_button.on_press(FastFileFlowMessage::JoinAddKeyClick());
+        }
+        let mut remove_button = Button::new(Text::new(BUTTON_REMOVE));
+        if !self.selected_file.read_options.join.is_empty() {
+            remove_button = remove_button.on_press(FastFileFlowMessage::JoinRemoveClick());
+        }
+
+        let join_file = match self.join_file.is_empty() {
+            true => JOIN_FILE_PLACEHOLDER.to_string(),
+            false => StoredFile::get_file_name(&self.join_file),
+        };
+        let combo_type = combo_box(
+            &self.join_type_state,
+            JOIN_TYPE_PLACEHOLDER,
+            self.join_type.as_ref(),
+            FastFileFlowMessage::JoinTypeSelected,
+        )
+        .size(12.0)
+        .width(Length::Fixed(80.0));
+        let combo_left_key = combo_box(
+            &self.join_left_key_state,
+            JOIN_LEFT_KEY_PLACEHOLDER,
+            self.join_left_key.as_ref(),
+            FastFileFlowMessage::JoinLeftKeySelected,
+        )
+        .size(12.0)
+        .width(Length::Fixed(110.0));
+        let combo_right_key = combo_box(
+            &self.join_right_key_state,
+            JOIN_RIGHT_KEY_PLACEHOLDER,
+            self.join_right_key.as_ref(),
+            FastFileFlowMessage::JoinRightKeySelected,
+        )
+        .size(12.0)
+        .width(Length::Fixed(110.0));
+
+        let keys = self
+            .join_keys
+            .iter()
+            .map(|(left, right)| format!("{} = {}", left, right))
+            .collect::<Vec<String>>()
+            .join(", ");
+        let columns = self.join_headers.iter().map(|header| {
+            let header_toggled = header.clone();
+            checkbox(header.as_str(), self.join_columns.contains(header))
+                .size(Pixels(12.0))
+                .text_size(Pixels(10.0))
+                .on_toggle(move |checked| {
+                    FastFileFlowMessage::JoinColumnToggled(header_toggled.clone(), checked)
+                })
+                .into()
+        });
+
+        let panel_join = column![
+            row![get_text(JOIN_TITLE, true)
+                .height(Length::Fixed(24.0))
+                .width(Length::Fixed(PANEL_WIDTH))],
+            row![
+                get_text_size(join_file, true, Pixels(10.0)),
+                horizontal_space(),
+                browse_button,
+                TAB_SPACE,
+                combo_type
+            ],
+            row![
+                combo_left_key,
+                get_text(" = ", false),
+                combo_right_key,
+                horizontal_space(),
+                add_key_button,
+                TAB_SPACE,
+                clear_button
+            ],
+            row![get_text_size(keys, true, Pixels(10.0))],
+            scrollable(Column::with_children(columns)).height(Length::Fixed(50.0)),
+            row![
+                remove_button,
+                horizontal_space(),
+                apply_button,
+                TAB_SPACE,
+                close_button
+            ],
+        ];
+        create_section_container_width(panel_join, PANEL_WIDTH + 100.0)
+    }
+
+    fn build_schema_panel(&self) -> Container<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let close_button =
+            Button::new(Text::new(BUTTON_CLOSE)).on_press(FastFileFlowMessage::Router(Page::Main));
+        let apply_button =
+            Button::new(Text::new(BUTTON_APPLY)).on_press(FastFileFlowMessage::SchemaApplyClick());
+        let clear_button =
+            Button::new(Text::new(BUTTON_CLEAR)).on_press(FastFileFlowMessage::SchemaClearClick());
+        let mut set_button = Button::new(Text::new(BUTTON_SET));
+        let mut reset_button = Button::new(Text::new(BUTTON_RESET));
+        if self.schema_column.is_some() {
+            set_button = set_button.on_press(FastFileFlowMessage::SchemaSetClick());
+            reset_button = reset_button.on_press(FastFileFlowMessage::SchemaResetClick());
+        }
+
+        let combo_column = combo_box(
+            &self.schema_column_state,
+            SCHEMA_COLUMN_PLACEHOLDER,
+            self.schema_column.as_ref(),
+            FastFileFlowMessage::SchemaColumnSelected,
+        )
+        .size(12.0)
+        .width(Length::Fill);
+        let combo_type = combo_box(
+            &self.schema_type_state,
+            SCHEMA_TYPE_PLACEHOLDER,
+            self.schema_type.as_ref(),
+            FastFileFlowMessage::SchemaTypeSelected,
+        )
+        .size(12.0)
+        .width(Length::Fixed(110.0));
+        let text_date_format = text_input(
+            SCHEMA_DATE_FORMAT_PLACEHOLDER,
+            self.schema_date_format.as_str(),
+        )
+        .on_input(FastFileFlowMessage::SchemaDateFormatChange)
+        .size(12.0)
+        .width(Length::Fill);
+        let text_decimal_separator = text_input(
+            SCHEMA_DECIMAL_PLACEHOLDER,
+            self.schema_decimal_separator.as_str(),
+        )
+        .on_input(FastFileFlowMessage::SchemaDecimalSeparatorChange)
+        .size(12.0)
+        .width(Length::Fixed(30.0));
+
+        let inferred = self
+            .get_schema_inferred(self.schema_column.as_deref())
+            .map(|data_type| SCHEMA_INFERRED_LABEL.replace("{}", data_type.to_string()))
+            .unwrap_or_default();
+        let columns = self.selected_file.columns.headers.iter().map(|column| {
+            let header = column.column_header.as_str();
+            let inferred = self
+                .get_schema_inferred(Some(header))
+                .map_or(UNKNOWN_LABEL, |data_type| data_type.to_string());
+            let line = match self.schema_types.iter().find(|c| c.header == header) {
+                Some(column_type) => format!(
+                    "{}: {} -> {} {} {}",
+                    header,
+                    inferred,
+                    column_type.data_type.to_string(),
+                    column_type.date_format,
+                    column_type.decimal_separator
+                ),
+                None => format!("{}: {}", header, inferred),
+            };
+            get_text_size(line, false, Pixels(10.0)).into()
+        });
+
+        let panel_schema = column![
+            row![get_text(SCHEMA_TITLE, true)
+                .height(Length::Fixed(24.0))
+                .width(Length::Fixed(PANEL_WIDTH))],
+            row![
+                combo_column,
+                TAB_SPACE,
+                get_text_size(inferred, true, Pixels(10.0))
+            ],
+            row![
+                combo_type,
+                TAB_SPACE,
+                text_date_format,
+                TAB_SPACE,
+                text_decimal_separator,
+                TAB_SPACE,
+                set_button,
+                TAB_SPACE,
+                reset_button
+            ],
+            scrollable(Column::with_children(columns)).height(Length::Fixed(50.0)),
+            row![
+                clear_button,
+                horizontal_space(),
+                apply_button,
+                TAB_SPACE,
+                close_button
+            ],
+        ];
+        create_section_container_width(panel_schema, PANEL_WIDTH + 100.0)
+    }
+
+    fn build_profile_panel(&self) -> Container<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let close_button =
+            Button::new(Text::new(BUTTON_CLOSE)).on_press(FastFileFlowMessage::Router(Page::Main));
+        let recompute_button = Button::new(Text::new(BUTTON_RECOMPUTE))
+            .on_press(FastFileFlowMessage::ProfileRecomputeClick());
+
+        let headers = ProfileSort::all().into_iter().map(|sort| {
+            let label = match (sort == self.profile_sort, self.profile_ascending) {
+                (false, _) => sort.to_string().to_string(),
+                (true, true) => PROFILE_ASCENDING_LABEL.replace("{}", sort.to_string()),
+                (true, false) => PROFILE_DESCENDING_LABEL.replace("{}", sort.to_string()),
+            };
+            Button::new(get_text_size(label, true, Pixels(10.0)))
+                .style(iced::theme::Button::Text)
+                .padding(0)
+                .width(Length::Fixed(PROFILE_CELL_WIDTH))
+                .on_press(FastFileFlowMessage::ProfileSortClick(sort))
+                .into()
+        });
+
+        let rows = self.profile.iter().map(|stadistics| {
+            let is_selected = self.profile_selected.as_ref() == Some(&stadistics.header);
+            let cells = ProfileSort::all().into_iter().map(|sort| {
+                get_text_size(
+                    sort.get_value(stadistics).to_string(),
+                    is_selected,
+                    Pixels(10.0),
+                )
+                .width(Length::Fixed(PROFILE_CELL_WIDTH))
+                .into()
+            });
+            Button::new(Row::with_children(cells))
+                .style(iced::theme::Button::Text)
+                .padding(0)
+                .on_press(FastFileFlowMessage::ProfileRowClick(
+                    stadistics.header.clone(),
+                ))
+                .into()
+        });
+
+        let detail = self
+            .profile
+            .iter()
+            .find(|stadistics| self.profile_selected.as_ref() == Some(&stadistics.header))
+            .map(|s| {
+                format!(
+                    "{} ({}) - Median: {}  Mode: {}  Std Dev: {}  Variance: {}  Range: {}  Quartil: {}  Percentil: {}",
+                    s.header,
+                    s.classification.to_string(),
+                    s.median,
+                    s.mode,
+                    s.std_dev,
+                    s.variance,
+                    s.range,
+                    s.quartil,
+                    s.percentil
+                )
+            })
+            .unwrap_or_default();
+
+        let panel_profile = column![
+            row![
+                get_text(PROFILE_TITLE, true)
+                    .height(Length::Fixed(24.0))
+                    .width(Length::Fixed(PANEL_WIDTH)),
+                horizontal_space(),
+                get_text(
+                    PROFILE_COLUMNS_LABEL.replace("{}", &self.profile.len().to_string()),
+                    true
+                )
+            ],
+            Row::with_children(headers),
+            scrollable(Column::with_children(rows)).height(Length::Fixed(80.0)),
+            row![get_text_size(detail, false, Pixels(10.0))].height(Length::Fixed(28.0)),
+            row![
+                horizontal_space(),
+                recompute_button,
+                TAB_SPACE,
+                close_button
+            ],
+        ];
+        create_section_container_width(
+            panel_profile,
+            PROFILE_CELL_WIDTH * ProfileSort::all().len() as f32 + 40.0,
+        )
+    }
+
+    fn get_profile_message(&mut self) -> Command<FastFileFlowMessage> {
+        self.progress = 0.0;
+        self.enable_loading(true);
+        let selected_file = self.selected_file.clone();
+        Command::perform(
+            async move { selected_file.get_profile().await.map_err(|e| e.to_string()) },
+            FastFileFlowMessage::SetProfile,
+        )
+    }
+
+    /// The inferred type of a column of the schema editor.
+    fn get_schema_inferred(&self, header: Option<&str>) -> Option<&DataType> {
+        let index = self
+            .selected_file
+            .columns
+            .headers
+            .iter()
+            .position(|column| Some(column.column_header.as_str()) == header)?;
+        self.schema_inferred.get(index)
+    }
+
+    fn build_sample_panel(&self) -> Container<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let close_button =
+            Button::new(Text::new(BUTTON_CLOSE)).on_press(FastFileFlowMessage::Router(Page::Main));
+        let apply_button =
+            Button::new(Text::new(BUTTON_APPLY)).on_press(FastFileFlowMessage::SampleApplyClick());
+
+        let combo_mode = combo_box(
+            &self.sample_mode_state,
+            SAMPLE_MODE_PLACEHOLDER,
+            self.sample_mode.as_ref(),
+            FastFileFlowMessage::SampleModeSelected,
+        )
+        .size(12.0)
+        .width(Length::Fixed(110.0));
+        let text_size = text_input(SAMPLE_SIZE_PLACEHOLDER, self.sample_size.as_str())
+            .on_input(FastFileFlowMessage::SampleSizeChange)
+            .size(12.0)
+            .width(Length::Fixed(80.0));
+        let text_seed = text_input(SAMPLE_SEED_PLACEHOLDER, self.sample_seed.as_str())
+            .on_input(FastFileFlowMessage::SampleSeedChange)
+            .size(12.0)
+            .width(Length::Fixed(80.0));
+        let text_step = text_input(SAMPLE_STEP_PLACEHOLDER, self.sample_step.as_str())
+            .on_input(FastFileFlowMessage::SampleStepChange)
+            .size(12.0)
+            .width(Length::Fixed(80.0));
+        let combo_column = combo_box(
+            &self.sample_column_state,
+            SAMPLE_COLUMN_PLACEHOLDER,
+            self.sample_column.as_ref(),
+            FastFileFlowMessage::SampleColumnSelected,
+        )
+        .size(12.0)
+        .width(Length::Fill);
+        let checkbox_models = checkbox(SAMPLE_MODELS_LABEL, self.sample_for_models)
+            .size(Pixels(14.0))
+            .on_toggle(FastFileFlowMessage::SampleForModelsToggled);
+
+        // Only the options of the chosen mode are shown
+        let mode = SampleMode::from_string(self.sample_mode.as_deref().unwrap_or(""));
+        let mut options = row![combo_mode];
+        if mode != SampleMode::All {
+            options = options.push(TAB_SPACE).push(text_size);
+        }
+        options = match mode {
+            SampleMode::Random => options.push(TAB_SPACE).push(text_seed),
+            SampleMode::Stratified => options
+                .push(TAB_SPACE)
+                .push(combo_column)
+                .push(TAB_SPACE)
+                .push(text_seed),
+            SampleMode::EveryNth => options.push(TAB_SPACE).push(text_step),
+            _ => options,
+        };
+
+        let sampled = match self.is_sampled() {
+            true => SAMPLE_ROWS_LABEL
+                .replacen(
+                    "{}",
+                    &self
+                        .selected_file
+                        .sampled_rows
+                        .len()
+                        .to_formatted_string(&Locale::en),
+                    1,
+                )
+                .replacen(
+                    "{}",
+                    &self
+                        .selected_file
+                        .rows
+                        .total
+                        .to_formatted_string(&Locale::en),
+                    1,
+                ),
+            false => String::new(),
+        };
+
+        let panel_sample = column![
+            row![get_text(SAMPLE_TITLE, true)
+                .height(Length::Fixed(24.0))
+                .width(Length::Fixed(PANEL_WIDTH))],
+            options,
+            row![checkbox_models].padding(Padding::from([10.0, 0.0, 10.0, 0.0])),
+            row![get_text_size(sampled, false, Pixels(10.0))],
+            vertical_space(),
+            row![horizontal_space(), apply_button, TAB_SPACE, close_button],
+        ];
+        create_section_container_width(panel_sample, PANEL_WIDTH + 100.0)
+    }
+
+    /// Draws the sample chosen for the file, the table shows it in place of the file.
+    fn get_sample_message(&mut self) -> Command<FastFileFlowMessage> {
+        if !self.is_sampled() {
+            self.selected_file.sampled_rows = std::sync::Arc::new(vec![]);
+            return Command::none();
+        }
+
+        self.enable_loading(true);
+        let selected_file = self.selected_file.clone();
+        Command::perform(
+            async move { selected_file.get_sample().await.map_err(|e| e.to_string()) },
+            FastFileFlowMessage::SetSample,
+        )
+    }
+
+    fn is_sampled(&self) -> bool {
+        self.selected_file.read_options.sampling.is_enabled()
+    }
+
+    /// Rows of the table when it starts, the search matches or the sample when there are.
+    fn get_file_rows(&self) -> Vec<IcedRow> {
+        if self.is_searched() {
+            return self.search_rows.clone();
+        }
+        match self.is_sampled() {
+            true => self.selected_file.sampled_rows.to_vec(),
+            false => self.selected_file.rows.sample.clone(),
+        }
+    }
+
+    fn is_searched(&self) -> bool {
+        self.search.is_some()
+    }
+
+    /// The search of the header, `None` when there is nothing to search for.
+    fn get_search(&self) -> Option<Search> {
+        if self.search_value.is_empty() {
+            return None;
+        }
+
+        let columns = self
+            .selected_file
+            .columns
+            .headers
+            .iter()
+            .enumerate()
+            .filter(|(_, column)| self.search_columns.contains(&column.column_header))
+            .map(|(index, _)| index)
+            .collect();
+        Some(Search::new(
+            self.search_value.clone(),
+            columns,
+            self.search_case_sensitive,
+            self.search_whole_word,
+            self.search_regex,
+        ))
+    }
+
+    /// Leaves the matches, the table shows the file again.
+    fn clear_search(&mut self) {
+        if self.searching {
+            self.enable_loading(false);
+        }
+        self.searching = false;
+        self.search = None;
+        self.search_rows = vec![];
+        self.search_counts = vec![];
+        self.search_match = None;
+    }
+
+    /// The matched cell at `search_match`, as the position of its row among the matches and
+    /// its column.
+    fn get_search_cell(&self, search_match: usize) -> Option<(usize, usize)> {
+        let mut cells = 0;
+        self.search_rows
+            .iter()
+            .enumerate()
+            .find_map(|(position, row)| {
+                let cell = search_match
+                    .checked_sub(cells)
+                    .and_then(|index| row.highlighted.get(index))
+                    .map(|column| (position, *column));
+                cells += row.highlighted.len();
+                cell
+            })
+    }
+
+    fn get_search_total(&self) -> u64 {
+        self.search_counts.iter().sum()
+    }
+
+    fn get_search_match_label(&self) -> String {
+        if !self.is_searched() {
+            return String::new();
+        }
+        let current = self
+            .search_match
+            .map(|search_match| (search_match + 1).to_formatted_string(&Locale::en))
+            .unwrap_or(String::from("-"));
+        SEARCH_MATCH_LABEL.replacen("{}", &current, 1).replacen(
+            "{}",
+            &self.get_search_total().to_formatted_string(&Locale::en),
+            1,
+        )
+    }
+
+    /// Moves the navigation to the match `step` cells away, going around at both ends.
+    fn move_search_match(&mut self, step: i64) -> Command<FastFileFlowMessage> {
+        let total = self.get_search_total() as i64;
+        if total == 0 {
+            return Command::none();
+        }
+        let search_match = match self.search_match {
+            Some(search_match) => (search_match as i64 + step).rem_euclid(total),
+            None if step < 0 => total - 1,
+            None => 0,
+        } as usize;
+        let Some((position, column)) = self.get_search_cell(search_match) else {
+            return Command::none();
+        };
+
+        if let Some(previous) = self.search_match.and_then(|m| self.get_search_cell(m)) {
+            self.set_search_selected(previous.0, None);
+        }
+        self.set_search_selected(position, Some(column));
+        self.search_match = Some(search_match);
+        if self.is_result_table() {
+            return Command::none();
+        }
+        self.move_table_cursor(position as u64)
+    }
+
+    /// Marks the cell of the navigation in the matches and in the table showing them.
+    fn set_search_selected(&mut self, position: usize, column: Option<usize>) {
+        if let Some(row) = self.search_rows.get_mut(position) {
+            row.selected = column;
+        }
+        if !self.is_result_table() {
+            if let Some(row) = self.rows.get_mut(position) {
+                row.selected = column;
+            }
+        }
+    }
+
+    /// Keeps only the rows matching the search when exporting, through the filter of the
+    /// columns searched. The other columns stop filtering by a match.
+    fn set_search_filter(&mut self, search: &Search) {
+        let pattern = search.get_pattern();
+        for column in self.column_options.iter_mut() {
+            let is_searched = search.columns.is_empty() || search.columns.contains(&column.index);
+            let filter = &mut column.save_options.filter;
+            filter.keep_row_if_match = is_searched;
+            filter.keep_row_if_match_text = match is_searched {
+                true => pattern.clone(),
+                false => String::new(),
+            };
+        }
+        if let Some(selected) = self.column_option_selected.as_mut() {
+            if let Some(column) = self.column_options.get(selected.index) {
+                selected.save_options = column.save_options.clone();
+            }
+        }
+        self.column_options_state = combo_box::State::new(self.column_options.clone());
+    }
+
+    /// The sampling of the panel, `None` when its numbers are not valid.
+    fn get_sampling(&self) -> Option<Sampling> {
+        let size = self.sample_size.trim().parse::<usize>().ok()?;
+        let seed = self.sample_seed.trim().parse::<u64>().ok()?;
+        let step = self.sample_step.trim().parse::<u64>().ok()?;
+        if size == 0 || step == 0 {
+            return None;
+        }
+        Some(Sampling::new(
+            SampleMode::from_string(self.sample_mode.as_deref().unwrap_or("")),
+            size,
+            seed,
+            self.sample_column.clone().unwrap_or_default(),
+            step,
+            self.sample_for_models,
+        ))
+    }
+
+    fn build_search_panel(&self) -> Container<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let close_button =
+            Button::new(Text::new(BUTTON_CLOSE)).on_press(FastFileFlowMessage::Router(Page::Main));
+        let mut filter_button = Button::new(Text::new(BUTTON_SEARCH_FILTER));
+        if self.is_searched() {
+            filter_button = filter_button.on_press(FastFileFlowMessage::SearchToFilterClick());
+        }
+
+        let columns =
+            self.selected_file
+                .columns
+                .headers
+                .iter()
+                .enumerate()
+                .map(|(index, column)| {
+                    let header = column.column_header.clone();
+                    let count = match self.search_counts.get(index) {
+                        Some(count) => count.to_formatted_string(&Locale::en),
+                        None => String::new(),
+                    };
+                    row![
+                        checkbox(
+                            column.column_header.as_str(),
+                            self.search_columns.contains(&column.column_header)
+                        )
+                        .size(Pixels(12.0))
+                        .text_size(Pixels(10.0))
+                        .on_toggle(move |checked| {
+                            FastFileFlowMessage::SearchColumnToggled(header.clone(), checked)
+                        }),
+                        horizontal_space(),
+                        get_text_size(count, true, Pixels(10.0))
+                    ]
+                    .padding(Padding::from([0.0, 20.0, 0.0, 0.0]))
+                    .into()
+                });
+
+        let panel_search = column![
+            row![
+                get_text(SEARCH_TITLE, true)
+                    .height(Length::Fixed(24.0))
+                    .width(Length::Fixed(PANEL_WIDTH)),
+                horizontal_space(),
+                get_text(self.get_search_match_label(), false)
+            ],
+            row![get_text_size(SEARCH_COLUMNS_HINT, false, Pixels(10.0))],
+            scrollable(Column::with_children(columns)).height(Length::Fixed(90.0)),
+            vertical_space(),
+            row![horizontal_space(), filter_button, TAB_SPACE, close_button],
+        ];
+        create_section_container_width(panel_search, PANEL_WIDTH + 100.0)
+    }
+
+    fn build_sort_panel(&self) -> Container<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let close_button =
+            Button::new(Text::new(BUTTON_CLOSE)).on_press(FastFileFlowMessage::Router(Page::Main));
+        let apply_button =
+            Button::new(Text::new(BUTTON_APPLY)).on_press(FastFileFlowMessage::SortApplyClick());
+        let add_key_button = Button::new(Text::new(BUTTON_ADD_KEY).size(12.0))
+            .on_press(FastFileFlowMessage::SortAddKeyClick());
+        let clear_button = Button::new(Text::new(BUTTON_CLEAR).size(12.0))
+            .on_press(FastFileFlowMessage::SortClearClick());
+
+        let combo_column = combo_box(
+            &self.sort_column_state,
+            SORT_COLUMN_PLACEHOLDER,
+            self.sort_column.as_ref(),
+            FastFileFlowMessage::SortColumnSelected,
+        )
+        .size(12.0)
+        .width(Length::Fill);
+        let combo_direction = combo_box(
+            &self.sort_direction_state,
+            SORT_DIRECTION_PLACEHOLDER,
+            self.sort_direction.as_ref(),
+            FastFileFlowMessage::SortDirectionSelected,
+        )
+        .size(12.0)
+        .width(Length::Fixed(110.0));
+
+        let keys = match self.sort_keys.is_empty() {
+            true => SORT_KEYS_EMPTY.to_string(),
+            false => self
+                .sort_keys
+                .iter()
+                .map(|key| key.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
+        };
+
+        let panel_sort = column![
+            row![get_text(SORT_TITLE, true)
+                .height(Length::Fixed(24.0))
+                .width(Length::Fixed(PANEL_WIDTH))],
+            row![
+                combo_column,
+                TAB_SPACE,
+                combo_direction,
+                TAB_SPACE,
+                add_key_button,
+                TAB_SPACE,
+                clear_button
+            ],
+            row![get_text_size(keys, true, Pixels(10.0))]
+                .padding(Padding::from([10.0, 0.0, 0.0, 0.0])),
+            vertical_space(),
+            row![horizontal_space(), apply_button, TAB_SPACE, close_button],
+        ];
+        create_section_container_width(panel_sort, PANEL_WIDTH + 100.0)
+    }
+
+    /// Sorts the table by the sort keys, the table pages over the sorted rows once they are
+    /// written.
+    fn get_sort_message(&mut self) -> Command<FastFileFlowMessage> {
+        if self.selected_file.read_options.sort.is_empty() {
+            self.selected_file.sorted_file = None;
+            return Command::none();
+        }
+
+        self.enable_loading(true);
+        let selected_file = self.selected_file.clone();
+        Command::perform(
+            async move { selected_file.sort().await.map_err(|e| e.to_string()) },
+            FastFileFlowMessage::SetSortedFile,
+        )
+    }
+
+    /// Shows the table from its first row, reading it again in its current order.
+    fn reset_table_window(&mut self) -> Command<FastFileFlowMessage> {
+        self.rows_offset = 0;
+        self.row_cursor = 0;
+        self.fetching_window = false;
+        if self.page != Page::Main || self.is_sampled() || self.is_searched() {
+            return Command::none();
+        }
+        self.get_table_fetch_message(0)
+    }
+
+    /// A page with its panel over the table of its result.
+    fn show_result_screen<'a>(
+        &'a self,
+        panel: Container<'a, FastFileFlowMessage, Theme, iced::Renderer>,
+        body: Element<'a, FastFileFlowMessage, Theme, iced::Renderer>,
+    ) -> Element<'a, FastFileFlowMessage, Theme, iced::Renderer> {
+        let render = column![
+            row![panel.width(Length::Fill)],
+            row![TAB_SPACE],
+            row![body].height(Length::Fill),
+            row![TAB_SPACE],
+            row![
+                horizontal_space(),
+                self.build_table_navigation(),
+                TAB_SPACE,
+                self.build_linear()
+            ]
+        ];
+        let border = Border {
+            color: Color::from_rgb(0.315, 0.315, 0.315),
+            width: 1.0,
+            radius: 40.0.into(),
+        };
+
+        container(render)
+            .align_x(iced::alignment::Horizontal::Left)
+            .align_y(iced::alignment::Vertical::Top)
+            .padding(40.0)
+            .style(container::Appearance {
+                border,
+                ..Default::default()
+            })
+            .into()
+    }
+
+    fn build_query_panel(&self) -> Container<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let run_button =
+            Button::new(Text::new(BUTTON_RUN)).on_press(FastFileFlowMessage::QueryRunClick());
+        let close_button =
+            Button::new(Text::new(BUTTON_CLOSE)).on_press(FastFileFlowMessage::QueryCloseClick());
+        let add_file_button = Button::new(Text::new(BUTTON_ADD_FILE).size(12.0))
+            .on_press(FastFileFlowMessage::QueryAddFileClick());
+        let mut remove_button = Button::new(Text::new(BUTTON_REMOVE).size(12.0));
+        if !self.query_files.is_empty() {
+            remove_button = remove_button.on_press(FastFileFlowMessage::QueryRemoveFilesClick());
+        }
+        let mut export_button = Button::new(Text::new(BUTTON_EXPORT));
+        if self.query_result.is_some() {
+            export_button = export_button.on_press(FastFileFlowMessage::QueryExportClick());
+        }
+
+        let tables = match &self.query_database {
+            Some(database) => QUERY_TABLES_LABEL.replace(
+                "{}",
+                &database
+                    .tables
+                    .iter()
+                    .map(|table| format!("{} ({})", table.name, table.headers.join(", ")))
+                    .collect::<Vec<String>>()
+                    .join("; "),
+            ),
+            None => QUERY_TABLES_HINT.to_string(),
+        };
+        let files = QUERY_FILES_LABEL.replace(
+            "{}",
+            &self
+                .query_files
+                .iter()
+                .map(|file_path| StoredFile::get_file_name(file_path))
+                .collect::<Vec<String>>()
+                .join(", "),
+        );
+
+        let panel_query = column![
+            row![get_text(QUERY_TITLE, true).height(Length::Fixed(24.0))],
+            row![text_editor(&self.query_content)
+                .height(Length::Fixed(QUERY_EDITOR_HEIGHT))
+                .on_action(FastFileFlowMessage::QueryActionPerformed)],
+            row![get_text_size(tables, true, Pixels(10.0))]
+                .padding(Padding::from([10.0, 0.0, 0.0, 0.0])),
+            row![
+                get_text_size(files, true, Pixels(10.0)),
+                TAB_SPACE,
+                add_file_button,
+                TAB_SPACE,
+                remove_button
+            ]
+            .align_items(iced::Alignment::Center),
+            row![
+                horizontal_space(),
+                run_button,
+                TAB_SPACE,
+                export_button,
+                TAB_SPACE,
+                close_button
+            ],
+        ];
+        create_section_container(panel_query)
+    }
+
+    /// Runs the query, loading the files into the database first when they changed since
+    /// the last one.
+    fn get_query_message(&mut self) -> Command<FastFileFlowMessage> {
+        let sql = self.query_content.text();
+        if sql.trim().is_empty() {
+            self.set_error(ERROR_QUERY_EMPTY);
+            return Command::none();
+        }
+
+        self.enable_loading(true);
+        let database = self.query_database.clone();
+        let selected_file = self.selected_file.clone();
+        let query_files = self.query_files.clone();
+        Command::perform(
+            async move {
+                let database = match database {
+                    Some(database) => database,
+                    None => Arc::new(QueryDatabase::load(&selected_file, &query_files).await?),
+                };
+                let query_result = database.clone().run(sql, QUERY_ROWS_MAX).await?;
+                Ok::<_, io::Error>((database, query_result))
+            },
+            |result| FastFileFlowMessage::QueryCompleted(result.map_err(|e| e.to_string())),
+        )
+    }
+
+    /// Shows a result in the table, keeping the columns of the file until the page of the
+    /// result is closed.
+    fn show_result_table(&mut self, page: Page, columns: Vec<IcedColumn>, rows: Vec<IcedRow>) {
+        if !self.is_result_table() {
+            self.columns_backup = self.columns.clone();
+            self.header_checked_backup = self.header_checked.clone();
+        }
+        self.columns = columns;
+        self.rows = rows;
+        self.rows_offset = 0;
+        self.row_cursor = 0;
+        self.router(page);
+    }
+
+    fn show_query_table(&mut self) {
+        let (columns, rows) = match &self.query_result {
+            Some(query_result) => (query_result.columns.clone(), query_result.rows.clone()),
+            None => (vec![], vec![]),
+        };
+        self.show_result_table(Page::Query, columns, rows);
+    }
+
+    fn show_pivot_table(&mut self) {
+        let (columns, rows) = match &self.pivot_table {
+            Some(pivot_table) => (
+                pivot_table
+                    .headers
+                    .iter()
+                    .map(|header| IcedColumn::new(header.clone()))
+                    .collect(),
+                pivot_table
+                    .rows
+                    .iter()
+                    .enumerate()
+                    .map(|(index, row)| IcedRow::new(row.clone(), index))
+                    .collect(),
+            ),
+            None => (vec![], vec![]),
+        };
+        self.show_result_table(Page::Pivot, columns, rows);
+    }
+
+    fn close_result_table(&mut self) {
+        self.columns = self.columns_backup.clone();
+        self.columns_backup = vec![];
+        self.header_checked = self.header_checked_backup.clone();
+        self.header_checked_backup = vec![];
+        self.rows = self.get_file_rows();
+        self.rows_offset = 0;
+        self.row_cursor = 0;
+        self.router(Page::Main);
+    }
+
+    /// The export of the file, or of the result of the query or the pivot with its own
+    /// columns.
+    fn get_export(&self) -> Export {
+        let query_result = self.query_result.as_ref().filter(|_| self.export_query);
+        let pivot_table = self.pivot_table.as_ref().filter(|_| self.export_pivot);
+        let headers: Vec<String> = match (query_result, pivot_table) {
+            (Some(query_result), _) => query_result
+                .columns
+                .iter()
+                .map(|column| column.column_header.clone())
+                .collect(),
+            (None, Some(pivot_table)) => pivot_table.headers.clone(),
+            (None, None) => {
+                let mut export_file =
+                    Export::new(self.selected_file.clone(), self.column_options.clone());
+                export_file.sorted = self.export_sorted;
+                return export_file;
+            }
+        };
+
+        let columns = headers
+            .into_iter()
+            .enumerate()
+            .map(|(index, header)| SimpleColumn {
+                index,
+                header,
+                ..SimpleColumn::default()
+            })
+            .collect();
+        let mut export_file = Export::new(self.selected_file.clone(), columns);
+        export_file.query = query_result.map(|query_result| query_result.query.clone());
+        export_file.pivot_table = match query_result {
+            Some(_) => None,
+            None => pivot_table.cloned(),
+        };
+        export_file
+    }
+
+    fn build_pivot_panel(&self) -> Container<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let run_button =
+            Button::new(Text::new(BUTTON_RUN)).on_press(FastFileFlowMessage::PivotRunClick());
+        let close_button =
+            Button::new(Text::new(BUTTON_CLOSE)).on_press(FastFileFlowMessage::PivotCloseClick());
+        let clear_button = Button::new(Text::new(BUTTON_CLEAR).size(12.0))
+            .on_press(FastFileFlowMessage::PivotClearClick());
+        let add_group_button = Button::new(Text::new(BUTTON_ADD).size(12.0))
+            .on_press(FastFileFlowMessage::PivotAddGroupClick());
+        let add_value_button = Button::new(Text::new(BUTTON_ADD).size(12.0))
+            .on_press(FastFileFlowMessage::PivotAddValueClick());
+        let mut no_pivot_button = Button::new(Text::new(BUTTON_REMOVE).size(12.0));
+        if !self.pivot.pivot_column.is_empty() {
+            no_pivot_button =
+                no_pivot_button.on_press(FastFileFlowMessage::PivotColumnClearClick());
+        }
+        let mut chart_button = Button::new(Text::new(BUTTON_CHART));
+        let mut export_button = Button::new(Text::new(BUTTON_EXPORT));
+        if self.pivot_table.is_some() {
+            chart_button = chart_button.on_press(FastFileFlowMessage::PivotChartClick());
+            export_button = export_button.on_press(FastFileFlowMessage::PivotExportClick());
+        }
+        let mut table_button = Button::new(Text::new(BUTTON_TABLE));
+        if self.pivot_chart.is_some() {
+            table_button = table_button.on_press(FastFileFlowMessage::PivotTableClick());
+        }
+
+        let combo_group = combo_box(
+            &self.pivot_columns_state,
+            PIVOT_GROUP_PLACEHOLDER,
+            self.pivot_group_column.as_ref(),
+            FastFileFlowMessage::PivotGroupColumnSelected,
+        )
+        .size(12.0)
+        .width(Length::Fill);
+        let combo_pivot = combo_box(
+            &self.pivot_columns_state,
+            PIVOT_COLUMN_PLACEHOLDER,
+            self.pivot_column.as_ref(),
+            FastFileFlowMessage::PivotColumnSelected,
+        )
+        .size(12.0)
+        .width(Length::Fill);
+        let combo_value = combo_box(
+            &self.pivot_columns_state,
+            PIVOT_VALUE_PLACEHOLDER,
+            self.pivot_value_column.as_ref(),
+            FastFileFlowMessage::PivotValueColumnSelected,
+        )
+        .size(12.0)
+        .width(Length::Fill);
+        let combo_aggregation = combo_box(
+            &self.pivot_aggregation_state,
+            PIVOT_AGGREGATION_PLACEHOLDER,
+            self.pivot_aggregation.as_ref(),
+            FastFileFlowMessage::PivotAggregationSelected,
+        )
+        .size(12.0)
+        .width(Length::Fixed(120.0));
+        let combo_date_group = combo_box(
+            &self.pivot_date_group_state,
+            PIVOT_DATE_GROUP_LABEL,
+            self.pivot_date_group.as_ref(),
+            FastFileFlowMessage::PivotDateGroupSelected,
+        )
+        .size(12.0)
+        .width(Length::Fixed(120.0));
+        let combo_chart = combo_box(
+            &self.pivot_chart_column_state,
+            PIVOT_CHART_PLACEHOLDER,
+            self.pivot_chart_column.as_ref(),
+            FastFileFlowMessage::PivotChartColumnSelected,
+        )
+        .size(12.0)
+        .width(Length::Fixed(220.0));
+
+        let groups = PIVOT_GROUPS_LABEL.replace("{}", &self.pivot.group_by.join(", "));
+        let pivot_column = PIVOT_COLUMN_LABEL.replace(
+            "{}",
+            match self.pivot.pivot_column.is_empty() {
+                true => PIVOT_NONE,
+                false => self.pivot.pivot_column.as_str(),
+            },
+        );
+        let values = PIVOT_VALUES_LABEL.replace(
+            "{}",
+            &self
+                .pivot
+                .values
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
+        );
+
+        let panel_columns = column![
+            row![combo_group, TAB_SPACE, add_group_button],
+            row![get_text_size(groups, true, Pixels(10.0))],
+            row![combo_pivot, TAB_SPACE, no_pivot_button],
+            row![get_text_size(pivot_column, true, Pixels(10.0))],
+        ]
+        .width(Length::Fill);
+        let panel_values = column![
+            row![
+                combo_value,
+                TAB_SPACE,
+                combo_aggregation,
+                TAB_SPACE,
+                add_value_button
+            ],
+            row![get_text_size(values, true, Pixels(10.0))],
+            row![
+                get_text_size(PIVOT_DATE_GROUP_LABEL, true, Pixels(12.0)),
+                TAB_SPACE,
+                combo_date_group
+            ]
+            .align_items(iced::Alignment::Center),
+        ]
+        .width(Length::Fill);
+
+        let panel_pivot = column![
+            row![get_text(PIVOT_TITLE, true).height(Length::Fixed(24.0))],
+            row![panel_columns, TAB_SPACE, panel_values],
+            vertical_space(),
+            row![
+                clear_button,
+                horizontal_space(),
+                run_button,
+                TAB_SPACE,
+                combo_chart,
+                TAB_SPACE,
+                chart_button,
+                TAB_SPACE,
+                table_button,
+                TAB_SPACE,
+                export_button,
+                TAB_SPACE,
+                close_button
+            ]
+            .align_items(iced::Alignment::Center),
+        ];
+        create_section_container(panel_pivot).height(PANEL_HEIGHT)
+    }
+
+    /// The chart of the pivot in place of its table, once it is drawn.
+    fn build_pivot_body(&self) -> Element<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        match &self.pivot_chart {
+            Some(chart) => container(
+                Image::new(chart.clone())
+                    .width(Fixed(IMAGE_WIDTH as f32))
+                    .height(Fixed(IMAGE_HEIGHT as f32)),
+            )
+            .width(Length::Fill)
+            .center_x()
+            .into(),
+            None => self.build_table().into(),
+        }
+    }
+
+    /// The kind of values of a column, from its statistics or the type of its values.
+    fn get_column_classification(&self, header: &str) -> DataClassification {
+        let index = self
+            .column_options
+            .iter()
+            .position(|column| column.header == header);
+        match index.map(|index| &self.column_options[index]) {
+            Some(column) if column.classification != DataClassification::Unknown => {
+                column.classification.clone()
+            }
+            _ => index
+                .and_then(|index| self.selected_file.get_inferred_types().get(index).cloned())
+                .map_or(DataClassification::Unknown, |data_type| {
+                    data_type.get_classification()
+                }),
+        }
+    }
+
+    /// A first pivot from the kind of the columns, the counts and sums of the rows by the
+    /// first qualitative column.
+    fn get_default_pivot(&self) -> Pivot {
+        let headers: Vec<(String, DataClassification)> = self
+            .column_options
+            .iter()
+            .map(|column| {
+                (
+                    column.header.clone(),
+                    self.get_column_classification(&column.header),
+                )
+            })
+            .collect();
+        let group = headers
+            .iter()
+            .find(|(_, classification)| *classification == DataClassification::Qualitative);
+        let number = headers
+            .iter()
+            .find(|(_, classification)| *classification == DataClassification::Quantitative);
+
+        let mut pivot = Pivot::default();
+        if let Some((header, _)) = group {
+            pivot.group_by.push(header.clone());
+            pivot
+                .values
+                .push(PivotValue::new(header.clone(), Aggregation::Count));
+        }
+        if let Some((header, _)) = number {
+            pivot
+                .values
+                .push(PivotValue::new(header.clone(), Aggregation::Sum));
+        }
+        pivot
+    }
+
+    fn get_pivot_message(&mut self) -> Command<FastFileFlowMessage> {
+        if self.pivot.group_by.is_empty() || self.pivot.values.is_empty() {
+            self.set_error(ERROR_PIVOT_OPTIONS);
+            return Command::none();
+        }
+
+        self.enable_loading(true);
+        let selected_file = self.selected_file.clone();
+        let pivot = self.pivot.clone();
+        Command::perform(
+            async move {
+                selected_file
+                    .get_pivot(&pivot)
+                    .await
+                    .map(Arc::new)
+                    .map_err(|e| e.to_string())
+            },
+            FastFileFlowMessage::SetPivotTable,
+        )
+    }
+
+    /// Draws the bars of the chosen column of the pivot table, the first value by default.
+    fn get_pivot_chart_message(&mut self) -> Command<FastFileFlowMessage> {
+        let Some(pivot_table) = self.pivot_table.clone() else {
+            return Command::none();
+        };
+        let column = self
+            .pivot_chart_column
+            .as_ref()
+            .and_then(|header| pivot_table.headers.iter().position(|h| h == header))
+            .unwrap_or(pivot_table.group_columns);
+
+        self.enable_loading(true);
+        Command::perform(
+            async move {
+                tokio::task::spawn_blocking(move || {
+                    pivot_table.draw_chart(column, PIVOT_IMAGE_RESULT)?;
+                    std::fs::read(PIVOT_IMAGE_RESULT)
+                })
+                .await
+                .map_err(|e| e.to_string())?
+                .map_err(|e| e.to_string())
+            },
+            FastFileFlowMessage::SetPivotChart,
+        )
+    }
+
+    fn build_cache_panel(&self) -> Container<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let close_button =
+            Button::new(Text::new(BUTTON_CLOSE)).on_press(FastFileFlowMessage::Router(Page::Main));
+        let apply_button =
+            Button::new(Text::new(BUTTON_APPLY)).on_press(FastFileFlowMessage::CacheApplyClick());
+
+        let checkbox_enabled = checkbox(CACHE_ENABLED_LABEL, self.cache_enabled)
+            .size(Pixels(14.0))
+            .on_toggle(FastFileFlowMessage::CacheEnabledToggled);
+        let checkbox_on_load = checkbox(CACHE_ON_LOAD_LABEL, self.cache_on_load)
+            .size(Pixels(14.0))
+            .on_toggle(FastFileFlowMessage::CacheOnLoadToggled);
+
+        let text_budget = text_input(CACHE_BUDGET_PLACEHOLDER, self.cache_budget.as_str())
+            .on_input(FastFileFlowMessage::CacheBudgetChange)
+            .on_submit(FastFileFlowMessage::CacheApplyClick())
+            .size(12.0)
+            .width(Length::Fixed(80.0));
+
+        let panel_cache = column![
+            row![get_text(CACHE_TITLE, true)
+                .height(Length::Fixed(24.0))
+                .width(Length::Fixed(PANEL_WIDTH))],
+            row![checkbox_enabled],
+            row![checkbox_on_load],
+            row![
+                get_text("Memory budget (MB):", false),
+                TAB_SPACE,
+                text_budget,
+                horizontal_space()
+            ],
+            row![
+                get_text("Status:", false),
+                get_text(self.selected_file.cache.get_status(), true)
+            ],
+            row![
+                TAB_SPACE,
+                horizontal_space(),
+                apply_button,
+                TAB_SPACE,
+                close_button
+            ],
+        ];
+        create_section_container_width(panel_cache, PANEL_WIDTH + 100.0)
+    }
+
+    /// Hands the cache settings to the selected file and builds the cache right away
+    /// when it should be ready on load.
+    fn get_cache_message(&mut self) -> Command<FastFileFlowMessage> {
+        let budget = match self.cache_enabled {
+            true => self.cache_budget.trim().parse::<usize>().unwrap_or(0) * 1024 * 1024,
+            false => 0,
+        };
+        self.selected_file.set_cache_budget(budget);
+
+        if !self.cache_on_load || !self.is_file_loaded() || !self.selected_file.is_supported() {
+            return Command::none();
+        }
+
+        let selected_file = self.selected_file.clone();
+        Command::perform(
+            async move { selected_file.get_cached_table().await.is_some() },
+            |_| FastFileFlowMessage::CacheCompleted(),
+        )
+    }
+
+    fn build_export_panel(&self) -> Container<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let close_button =
+            Button::new(Text::new(BUTTON_CLOSE)).on_press(FastFileFlowMessage::Router(Page::Main));
+        let export_button =
+            Button::new(Text::new(BUTTON_EXPORT)).on_press(FastFileFlowMessage::ExportSaveClick());
+
+        let combo_box = combo_box(
+            &self.export_encoding_state,
+            EXPORT_ENCODING_PLACEHOLDER,
+            self.export_encoding.as_ref(),
+            FastFileFlowMessage::ExportEncodingSelected,
+        )
+        .size(12.0)
+        .width(Length::Fill);
+
+        let text_table = text_input(EXPORT_TABLE_PLACEHOLDER, self.export_table.as_str())
+            .on_input(FastFileFlowMessage::ExportTableChange)
+            .size(12.0)
+            .width(Length::Fill);
+
+        let mut export_query = checkbox(EXPORT_QUERY_LABEL, self.export_query).size(Pixels(14.0));
+        if self.query_result.is_some() {
+            export_query = export_query.on_toggle(FastFileFlowMessage::ExportQueryToggled);
+        }
+        let mut export_pivot = checkbox(EXPORT_PIVOT_LABEL, self.export_pivot).size(Pixels(14.0));
+        if self.pivot_table.is_some() {
+            export_pivot = export_pivot.on_toggle(FastFileFlowMessage::ExportPivotToggled);
+        }
+
+        let panel_export = column![
+            row![get_text(EXPORT_TITLE, true)
+                .height(Length::Fixed(24.0))
+                .width(Length::Fixed(PANEL_WIDTH))],
+            row![get_text("Encoding:", false)],
+            row![combo_box],
+            row![TAB_SPACE, horizontal_space()],
+            row![get_text("Table:", false)],
+            row![text_table],
+            row![checkbox(EXPORT_SORTED_LABEL, self.export_sorted)
+                .size(Pixels(14.0))
+                .on_toggle(FastFileFlowMessage::ExportSortedToggled)]
+            .padding(Padding::from([10.0, 0.0, 0.0, 0.0])),
+            row![export_query],
+            row![export_pivot],
+            row![
+                TAB_SPACE,
+                horizontal_space(),
+                export_button,
+                TAB_SPACE,
+                close_button
+            ],
+        ];
+        create_section_container_width(panel_export, PANEL_WIDTH + 100.0)
+    }
+
+    fn show_user_screen(&self) -> Element<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let container_user = self.build_user_panel().height(PANEL_HEIGHT + 100.0);
+
+        let render = column![
+            row![container_user, TAB_SPACE, horizontal_space(),],
+            row![TAB_SPACE],
+            row![TAB_SPACE, horizontal_space(), self.build_linear(),],
+            row![TAB_SPACE],
+        ];
+        let border = Border {
+            color: Color::from_rgb(0.315, 0.315, 0.315).into(),
+            width: 1.0,
+            radius: 40.0.into(),
+            ..Default::default()
+        };
+
+        container(render)
+            .align_x(iced::alignment::Horizontal::Left)
+            .align_y(iced::alignment::Vertical::Top)
+            .padding(40.0)
+            .style(container::Appearance {
+                border,
+                ..Default::default()
+            })
+            .into()
+    }
+
+    fn build_user_panel(&self) -> Container<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        let close_button =
+            Button::new(Text::new(BUTTON_CLOSE)).on_press(FastFileFlowMessage::Router(Page::Main));
+
+        let panel_user = column![
+            row![TAB_SPACE, horizontal_space()],
+            row![get_text("About: Fast FIle Flow", true)],
+            row![TAB_SPACE, horizontal_space()],
+            row![get_text_size(
+                " Desarrollada por el alumno Marco Antonio Guzman Rodriguez para UNIR como parte de su Trabajo de Fin de Master titulado 'Big Data and Data Visualization'
+Mexico 2024",
+                true,
+                Pixels(PANEL_FONT_SIZE)
+            )],
+            row![TAB_SPACE, horizontal_space()],
+            row![TAB_SPACE, vertical_space()],
+            row![TAB_SPACE, horizontal_space(), close_button],
+        ];
+        create_section_container_width(panel_user, PANEL_WIDTH + 100.0)
+    }
+
+    /// Progress of the running task with the button to cancel it, a task that does not know
+    /// how much is left shows the animation instead.
+    fn build_linear(&self) -> Element<'_, FastFileFlowMessage, Theme, iced::Renderer> {
+        if !self.running {
+            return Linear::default().height(15.0).into();
+        }
+
+        let progress: Element<'_, FastFileFlowMessage, Theme, iced::Renderer> =
+            match self.task.get_total() {
+                0 => Linear::new(340.0, 15.0)
+                    .easing(&easing::EMPHASIZED_ACCELERATE)
+                    .cycle_duration(Duration::from_secs_f32(2_f32))
+                    .into(),
+                _ => progress_bar(0.0..=100.0, self.progress)
+                    .width(Fixed(340.0))
+                    .height(Fixed(15.0))
+                    .into(),
+            };
+        let button_cancel = Button::new(Text::new(BUTTON_CANCEL).size(12.0))
+            .on_press(FastFileFlowMessage::TaskCancelClick())
+            .style(iced::theme::Button::Text);
+
+        row![progress, button_cancel]
+            .spacing(5)
+            .align_items(iced::Alignment::Center)
+            .into()
+    }
+
+    fn build_checkbox<F>(
+        &self,
+        index: usize,
+        checked: bool,
+        option_type: OptionType,
+        label: String,
         f: F,
     ) -> checkbox::Checkbox<'_, FastFileFlowMessage, Theme, iced::Renderer>
     where
@@ -1284,12 +3932,13 @@ Mexico 2024",
             let selected_file = self.selected_file.clone();
             Command::perform(
                 async move { selected_file.get_stadistics(&column_index).await },
-                move |stadistics_file| {
-                    FastFileFlowMessage::SetStadisticsFile(
+                move |stadistics_file| match stadistics_file {
+                    Ok(stadistics_file) => FastFileFlowMessage::SetStadisticsFile(
                         column_index,
                         stadistics_file,
                         is_header_check,
-                    )
+                    ),
+                    Err(e) => FastFileFlowMessage::AnalysisCompleted(e.to_string()),
                 },
             )
         } else {
@@ -1300,7 +3949,228 @@ Mexico 2024",
         }
     }
 
+    fn get_visible_rows(&self, visible_rows: usize) -> &[IcedRow] {
+        let start =
+            (self.row_cursor.saturating_sub(self.rows_offset) as usize).min(self.rows.len());
+        let end = (start + visible_rows).min(self.rows.len());
+        &self.rows[start..end]
+    }
+
+    /// The table shows the preview of the export or the result of a query or a pivot, not
+    /// the file.
+    fn is_result_table(&self) -> bool {
+        matches!(self.page, Page::Preview | Page::Query | Page::Pivot)
+    }
+
+    fn get_table_total_rows(&self) -> u64 {
+        if self.page == Page::Main && !self.is_sampled() && !self.is_searched() {
+            self.selected_file.rows.total
+        } else {
+            self.rows.len() as u64
+        }
+    }
+
+    fn move_table_cursor(&mut self, row_index: u64) -> Command<FastFileFlowMessage> {
+        let last_row = self.get_table_total_rows().saturating_sub(1);
+        self.row_cursor = row_index.min(last_row);
+        self.get_table_window_message()
+    }
+
+    fn get_table_window_message(&mut self) -> Command<FastFileFlowMessage> {
+        if self.page != Page::Main
+            || self.fetching_window
+            || !self.is_file_loaded()
+            || self.is_sampled()
+            || self.is_searched()
+        {
+            return Command::none();
+        }
+
+        let loaded_end = self.rows_offset + self.rows.len() as u64;
+        let visible_end =
+            (self.row_cursor + TABLE_VISIBLE_ROWS_MAX).min(self.selected_file.rows.total);
+        if self.row_cursor >= self.rows_offset && visible_end <= loaded_end {
+            return Command::none();
+        }
+
+        self.get_table_fetch_message(self.row_cursor.saturating_sub(TABLE_WINDOW_ROWS as u64 / 2))
+    }
+
+    /// Loads the rows of the table from `start`, in place of the ones loaded.
+    fn get_table_fetch_message(&mut self, start: u64) -> Command<FastFileFlowMessage> {
+        self.fetching_window = true;
+        let selected_file = self.selected_file.clone();
+        Command::perform(
+            async move {
+                selected_file
+                    .get_rows_window(start, TABLE_WINDOW_ROWS)
+                    .await
+            },
+            move |rows| FastFileFlowMessage::SetTableWindow(start, rows.map_err(|e| e.to_string())),
+        )
+    }
+
+    fn set_read_options(&mut self) {
+        let read_options = &self.selected_file.read_options;
+        self.workbook_sheet_state = combo_box::State::new(self.selected_file.sheets.clone());
+        self.workbook_sheet = Some(read_options.sheet.clone()).filter(|sheet| !sheet.is_empty());
+        self.workbook_header_row = (read_options.header_row + 1).to_string();
+        self.database_table_state = combo_box::State::new(self.selected_file.sheets.clone());
+        self.database_table = Some(read_options.sheet.clone()).filter(|table| !table.is_empty());
+        self.database_query = read_options.query.clone();
+
+        let dialect = &read_options.dialect;
+        self.dialect_delimiter = Dialect::char_to_string(Some(dialect.delimiter));
+        self.dialect_quote = Dialect::char_to_string(Some(dialect.quote));
+        self.dialect_escape = Dialect::char_to_string(dialect.escape);
+        self.dialect_terminator = Some(dialect.terminator.to_string().to_string());
+        self.dialect_has_header = read_options.has_header;
+        self.dialect_headers = read_options.headers.clone();
+        self.rejected_policy = Some(read_options.row_policy.to_string().to_string());
+        self.dataset_source_column = read_options.source_column;
+        self.dataset_pattern = self.selected_file.dataset.path.clone();
+
+        let join = &read_options.join;
+        self.join_file = join.file_path.clone();
+        self.join_type = Some(join.join_type.to_string().to_string());
+        self.join_keys = join
+            .left_keys
+            .iter()
+            .cloned()
+            .zip(join.right_keys.iter().cloned())
+            .collect();
+        self.join_columns = join.columns.clone();
+
+        self.schema_types = read_options.schema.clone();
+        self.schema_inferred = self.selected_file.get_inferred_types();
+        self.schema_column = None;
+        self.schema_column_state = combo_box::State::new(
+            self.selected_file
+                .columns
+                .headers
+                .iter()
+                .map(|column| column.column_header.clone())
+                .collect(),
+        );
+        self.schema_type = None;
+        self.schema_date_format = String::from("");
+        self.schema_decimal_separator = String::from("");
+
+        let sampling = &read_options.sampling;
+        self.sample_mode = Some(sampling.mode.to_string().to_string());
+        self.sample_size = sampling.size.to_string();
+        self.sample_seed = sampling.seed.to_string();
+        self.sample_step = sampling.step.to_string();
+        self.sample_column = Some(sampling.column.clone()).filter(|column| !column.is_empty());
+        self.sample_column_state = combo_box::State::new(
+            self.selected_file
+                .columns
+                .headers
+                .iter()
+                .map(|column| column.column_header.clone())
+                .collect(),
+        );
+        self.sample_for_models = sampling.for_models;
+        self.sort_keys = read_options.sort.clone();
+        self.sort_column = None;
+        let headers: Vec<String> = self
+            .selected_file
+            .columns
+            .headers
+            .iter()
+            .map(|column| column.column_header.clone())
+            .collect();
+        self.sort_column_state = combo_box::State::new(headers.clone());
+        self.pivot_columns_state = combo_box::State::new(headers);
+        self.join_headers = self
+            .selected_file
+            .join_table
+            .as_ref()
+            .map(|join_table| join_table.right_headers.clone())
+            .unwrap_or_default();
+        self.join_left_key = None;
+        self.join_left_key_state = combo_box::State::new(self.selected_file.get_join_key_headers());
+        self.join_right_key = None;
+        self.join_right_key_state = combo_box::State::new(self.join_headers.clone());
+
+        let encoding = self.selected_file.get_encoding_label().to_uppercase();
+        let mut encodings: Vec<String> = ENCODINGS.iter().map(|s| s.to_string()).collect();
+        if !encoding.is_empty() && !encodings.contains(&encoding) {
+            encodings.insert(0, encoding.clone());
+        }
+        self.encoding_state = combo_box::State::new(encodings);
+        self.encoding_selected = Some(encoding).filter(|encoding| !encoding.is_empty());
+    }
+
+    /// Opens a data file, a dataset directory or glob pattern, or a project.
+    fn get_load_message(
+        &mut self,
+        path: String,
+        read_options: ReadOptions,
+    ) -> Command<FastFileFlowMessage> {
+        if path.is_empty() {
+            self.enable_loading(false);
+            self.set_file_not_found_error();
+            return Command::none();
+        }
+
+        let extension = StoredFile::get_file_extension(&path);
+        if DATA_FORMATS.contains(&extension.as_str()) || Dataset::is_dataset_path(&path) {
+            self.file_loaded = path.clone();
+            let task = self.task.clone();
+            Command::perform(
+                async move {
+                    let start = Instant::now();
+                    let file = StoredFile::new_with_task(path.clone(), read_options, task).await;
+                    print_timer("File Loading", start);
+                    file
+                },
+                FastFileFlowMessage::SetSelectedFile,
+            )
+        } else if extension == FFFLOW {
+            match self.load_from_file(path.as_str()) {
+                Ok(_) => {
+                    Command::perform(async move {}, |_| FastFileFlowMessage::SetLoadedProject())
+                }
+                Err(_) => {
+                    self.enable_loading(false);
+                    self.set_error(ERROR_PROJECT_INVALID);
+                    self.reset_state();
+                    Command::none()
+                }
+            }
+        } else {
+            self.enable_loading(false);
+            Command::none()
+        }
+    }
+
+    fn get_reload_message(&mut self, read_options: ReadOptions) -> Command<FastFileFlowMessage> {
+        self.enable_loading(true);
+        let mut stored_file = self.selected_file.clone();
+        stored_file.read_options = read_options;
+
+        Command::perform(
+            async move {
+                let start = Instant::now();
+                let result = stored_file.reload().await;
+                print_timer("File Reloading", start);
+                (stored_file, result)
+            },
+            |(stored_file, result)| match result {
+                Ok(_) => FastFileFlowMessage::SetSelectedFile(stored_file),
+                Err(e) => FastFileFlowMessage::AnalysisCompleted(e.to_string()),
+            },
+        )
+    }
+
+    /// Starting to load begins a new task on the selected file, unless one is running.
     fn enable_loading(&mut self, activate: bool) {
+        if activate && !self.running {
+            self.task = TaskProgress::tracked();
+            self.selected_file.task = self.task.clone();
+            self.progress = 0.0;
+        }
         self.running = activate;
     }
 
@@ -1312,6 +4182,33 @@ Mexico 2024",
         self.enable_loading(false);
         self.header_checked = vec![];
         self.column_option_selected = Option::None;
+        self.rows_offset = 0;
+        self.row_cursor = 0;
+        self.row_scroll_delta = 0.0;
+        self.fetching_window = false;
+        self.go_to_row = String::from("");
+        self.watching_file = false;
+        self.file_rewritten = false;
+        self.profile = vec![];
+        self.profile_selected = None;
+        self.search_columns = vec![];
+        self.searching = false;
+        self.search = None;
+        self.search_rows = vec![];
+        self.search_counts = vec![];
+        self.search_match = None;
+        self.query_database = None;
+        self.query_result = None;
+        self.export_query = false;
+        self.pivot = Pivot::default();
+        self.pivot_group_column = None;
+        self.pivot_column = None;
+        self.pivot_value_column = None;
+        self.pivot_aggregation = None;
+        self.pivot_table = None;
+        self.pivot_chart_column = None;
+        self.pivot_chart = None;
+        self.export_pivot = false;
     }
 
     fn is_file_loaded(&self) -> bool {
@@ -3,10 +3,12 @@ pub mod constants;
 pub mod correlation_analysis;
 pub mod dialog;
 pub mod dynamictable;
+pub mod encoding;
 pub mod export;
 pub mod fast_file_flow;
 pub mod option;
 pub mod save_options;
 pub mod stadistics;
 pub mod stored_file;
+pub mod task_progress;
 pub mod util;
@@ -0,0 +1,80 @@
+use crate::constants::english::{
+    COMPARISON_BETWEEN, COMPARISON_GREATER, COMPARISON_GREATER_OR_EQUAL, COMPARISON_LESS,
+    COMPARISON_LESS_OR_EQUAL, COMPARISON_NOT_BETWEEN,
+};
+
+/// How the value of a column is compared with the value of a filter, or with both ends of
+/// a range.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum Comparison {
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+    /// Both ends are included.
+    Between,
+    NotBetween,
+}
+
+impl Comparison {
+    pub fn default() -> Self {
+        Comparison::GreaterOrEqual
+    }
+
+    pub fn to_string(&self) -> &str {
+        match self {
+            Comparison::Greater => COMPARISON_GREATER,
+            Comparison::GreaterOrEqual => COMPARISON_GREATER_OR_EQUAL,
+            Comparison::Less => COMPARISON_LESS,
+            Comparison::LessOrEqual => COMPARISON_LESS_OR_EQUAL,
+            Comparison::Between => COMPARISON_BETWEEN,
+            Comparison::NotBetween => COMPARISON_NOT_BETWEEN,
+        }
+    }
+
+    pub fn from_string(unwrap: &str) -> Comparison {
+        match unwrap {
+            COMPARISON_GREATER => Comparison::Greater,
+            COMPARISON_LESS => Comparison::Less,
+            COMPARISON_LESS_OR_EQUAL => Comparison::LessOrEqual,
+            COMPARISON_BETWEEN => Comparison::Between,
+            COMPARISON_NOT_BETWEEN => Comparison::NotBetween,
+            _ => Comparison::GreaterOrEqual,
+        }
+    }
+
+    pub fn all() -> Vec<String> {
+        [
+            Comparison::Greater,
+            Comparison::GreaterOrEqual,
+            Comparison::Less,
+            Comparison::LessOrEqual,
+            Comparison::Between,
+            Comparison::NotBetween,
+        ]
+        .iter()
+        .map(|comparison| comparison.to_string().to_string())
+        .collect()
+    }
+
+    /// Compares with both ends of a range instead of a single value.
+    pub fn is_range(&self) -> bool {
+        matches!(self, Comparison::Between | Comparison::NotBetween)
+    }
+
+    /// `to` is only read by the ranges, in any order with `from`.
+    pub fn is_met(&self, value: f64, from: f64, to: f64) -> bool {
+        let (low, high) = match from <= to {
+            true => (from, to),
+            false => (to, from),
+        };
+        match self {
+            Comparison::Greater => value > from,
+            Comparison::GreaterOrEqual => value >= from,
+            Comparison::Less => value < from,
+            Comparison::LessOrEqual => value <= from,
+            Comparison::Between => value >= low && value <= high,
+            Comparison::NotBetween => value < low || value > high,
+        }
+    }
+}
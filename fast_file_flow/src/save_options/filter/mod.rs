@@ -1,4 +1,9 @@
 use crate::option::Option;
+use comparison::Comparison;
+use unparsed_policy::UnparsedPolicy;
+pub mod comparison;
+pub mod range_filter;
+pub mod unparsed_policy;
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
 pub struct FilterOption {
@@ -6,6 +11,18 @@ pub struct FilterOption {
     pub ignore_column: bool,
     pub ignore_row_if: bool,
     pub ignore_row_if_text: String,
+    /// Only the rows whose value matches the regular expression are kept, when any column
+    /// asks for it.
+    pub keep_row_if_match: bool,
+    pub keep_row_if_match_text: String,
+    /// Only the rows whose value, parsed as the type of the column, meets the comparison
+    /// are kept. Every column asking for it has to be met.
+    pub keep_row_if_range: bool,
+    pub keep_row_if_range_comparison: Comparison,
+    pub keep_row_if_range_from: String,
+    /// The other end of the range, for the comparisons that take two values.
+    pub keep_row_if_range_to: String,
+    pub keep_row_if_range_unparsed: UnparsedPolicy,
 }
 impl FilterOption {
     pub fn default() -> Self {
@@ -14,6 +31,13 @@ impl FilterOption {
             ignore_column: bool::default(),
             ignore_row_if: bool::default(),
             ignore_row_if_text: String::default(),
+            keep_row_if_match: bool::default(),
+            keep_row_if_match_text: String::default(),
+            keep_row_if_range: bool::default(),
+            keep_row_if_range_comparison: Comparison::default(),
+            keep_row_if_range_from: String::default(),
+            keep_row_if_range_to: String::default(),
+            keep_row_if_range_unparsed: UnparsedPolicy::default(),
         }
     }
     pub fn new(
@@ -27,6 +51,7 @@ impl FilterOption {
             ignore_column,
             ignore_row_if,
             ignore_row_if_text,
+            ..Self::default()
         }
     }
 }
@@ -37,5 +62,12 @@ impl Option for FilterOption {
             || self.ignore_row_if_empty != bool::default()
             || self.ignore_row_if != bool::default()
             || self.ignore_row_if_text != String::default()
+            || self.keep_row_if_match != bool::default()
+            || self.keep_row_if_match_text != String::default()
+            || self.keep_row_if_range != bool::default()
+            || self.keep_row_if_range_comparison != Comparison::default()
+            || self.keep_row_if_range_from != String::default()
+            || self.keep_row_if_range_to != String::default()
+            || self.keep_row_if_range_unparsed != UnparsedPolicy::default()
     }
 }
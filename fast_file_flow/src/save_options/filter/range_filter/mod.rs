@@ -0,0 +1,102 @@
+use crate::{
+    constants::english::{
+        ERROR_FILTER_RANGE_TYPE, ERROR_FILTER_RANGE_UNPARSED, ERROR_FILTER_RANGE_VALUE,
+    },
+    stadistics::data_type::DataType,
+    stored_file::{schema::ColumnType, sort::to_number},
+};
+use std::io::{Error, ErrorKind};
+
+use super::{comparison::Comparison, unparsed_policy::UnparsedPolicy, FilterOption};
+
+/// The comparison a filter asks of a column, with its values parsed as the type of the
+/// column. Numbers compare by their value and dates and times by the moment they are.
+#[derive(Debug, Clone)]
+pub struct RangeFilter {
+    header: String,
+    comparison: Comparison,
+    from: f64,
+    to: f64,
+    unparsed: UnparsedPolicy,
+    data_type: DataType,
+    column_type: Option<ColumnType>,
+}
+
+impl RangeFilter {
+    /// `column_type` are the parse rules chosen in the schema, the values of the filter are
+    /// written as the values of the column.
+    pub fn new(
+        header: &str,
+        filter: &FilterOption,
+        data_type: DataType,
+        column_type: Option<ColumnType>,
+    ) -> Result<Self, Error> {
+        if !matches!(
+            data_type,
+            DataType::Integer
+                | DataType::Float
+                | DataType::Date
+                | DataType::Time
+                | DataType::DateTime
+        ) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                ERROR_FILTER_RANGE_TYPE.replacen("{}", header, 1).replacen(
+                    "{}",
+                    data_type.to_string(),
+                    1,
+                ),
+            ));
+        }
+
+        let mut range_filter = Self {
+            header: header.to_string(),
+            comparison: filter.keep_row_if_range_comparison.clone(),
+            from: 0.0,
+            to: 0.0,
+            unparsed: filter.keep_row_if_range_unparsed.clone(),
+            data_type,
+            column_type,
+        };
+        range_filter.from = range_filter.parse_bound(&filter.keep_row_if_range_from)?;
+        if range_filter.comparison.is_range() {
+            range_filter.to = range_filter.parse_bound(&filter.keep_row_if_range_to)?;
+        }
+        Ok(range_filter)
+    }
+
+    fn parse_bound(&self, value: &str) -> Result<f64, Error> {
+        self.parse(value).ok_or(Error::new(
+            ErrorKind::InvalidInput,
+            ERROR_FILTER_RANGE_VALUE
+                .replacen("{}", value, 1)
+                .replacen("{}", self.data_type.to_string(), 1)
+                .replacen("{}", &self.header, 1),
+        ))
+    }
+
+    fn parse(&self, value: &str) -> Option<f64> {
+        let value = match &self.column_type {
+            Some(column_type) => column_type.normalize(value),
+            None => value.trim().to_string(),
+        };
+        to_number(&value, &self.data_type)
+    }
+
+    /// Whether the row of the value is kept, failing on the values that do not parse when
+    /// the policy asks for it.
+    pub fn is_kept(&self, value: &str) -> Result<bool, Error> {
+        match (self.parse(value), &self.unparsed) {
+            (Some(number), _) => Ok(self.comparison.is_met(number, self.from, self.to)),
+            (None, UnparsedPolicy::Skip) => Ok(false),
+            (None, UnparsedPolicy::Keep) => Ok(true),
+            (None, UnparsedPolicy::Fail) => Err(Error::new(
+                ErrorKind::InvalidData,
+                ERROR_FILTER_RANGE_UNPARSED
+                    .replacen("{}", value, 1)
+                    .replacen("{}", &self.header, 1)
+                    .replacen("{}", self.data_type.to_string(), 1),
+            )),
+        }
+    }
+}
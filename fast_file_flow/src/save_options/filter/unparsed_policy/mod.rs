@@ -0,0 +1,44 @@
+use crate::constants::english::{UNPARSED_POLICY_FAIL, UNPARSED_POLICY_KEEP, UNPARSED_POLICY_SKIP};
+
+/// What to do with a row whose value can not be compared, because it is empty or does not
+/// parse as the type of its column.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum UnparsedPolicy {
+    Skip,
+    Keep,
+    /// The export stops with the first value that does not parse.
+    Fail,
+}
+
+impl UnparsedPolicy {
+    pub fn default() -> Self {
+        UnparsedPolicy::Skip
+    }
+
+    pub fn to_string(&self) -> &str {
+        match self {
+            UnparsedPolicy::Skip => UNPARSED_POLICY_SKIP,
+            UnparsedPolicy::Keep => UNPARSED_POLICY_KEEP,
+            UnparsedPolicy::Fail => UNPARSED_POLICY_FAIL,
+        }
+    }
+
+    pub fn from_string(unwrap: &str) -> UnparsedPolicy {
+        match unwrap {
+            UNPARSED_POLICY_KEEP => UnparsedPolicy::Keep,
+            UNPARSED_POLICY_FAIL => UnparsedPolicy::Fail,
+            _ => UnparsedPolicy::Skip,
+        }
+    }
+
+    pub fn all() -> Vec<String> {
+        [
+            UnparsedPolicy::Skip,
+            UnparsedPolicy::Keep,
+            UnparsedPolicy::Fail,
+        ]
+        .iter()
+        .map(|policy| policy.to_string().to_string())
+        .collect()
+    }
+}
@@ -10,6 +10,11 @@ pub enum OptionType {
     ProcessReplaceWith = 6,
     ProcessReplaceIf = 7,
     ProcessReplaceIfThen = 8,
+    FilterKeepIfMatch = 9,
+    FilterKeepIfRange = 10,
+    FilterRangeComparison = 11,
+    FilterRangeTo = 12,
+    FilterRangeUnparsed = 13,
 }
 
 impl PartialEq for OptionType {
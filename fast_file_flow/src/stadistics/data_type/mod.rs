@@ -2,6 +2,8 @@ use core::mem::discriminant as tag;
 
 use crate::constants::english::UNKNOWN_LABEL;
 
+use super::data_classification::DataClassification;
+
 #[derive(Debug, Eq, Hash, Clone)]
 pub enum DataType {
     Integer,
@@ -27,6 +29,43 @@ impl DataType {
             DataType::Coordinates => "Coordinates",
         }
     }
+
+    pub fn from_string(unwrap: &str) -> DataType {
+        match unwrap {
+            "Integer" => DataType::Integer,
+            "Float" => DataType::Float,
+            "Text" => DataType::Text,
+            "Date" => DataType::Date,
+            "Time" => DataType::Time,
+            "DateTime" => DataType::DateTime,
+            "Coordinates" => DataType::Coordinates,
+            _ => DataType::Unknown,
+        }
+    }
+
+    /// Types a column can be given, every one but `Unknown`.
+    pub fn all() -> Vec<String> {
+        [
+            DataType::Integer,
+            DataType::Float,
+            DataType::Text,
+            DataType::Date,
+            DataType::Time,
+            DataType::DateTime,
+            DataType::Coordinates,
+        ]
+        .iter()
+        .map(|data_type| data_type.to_string().to_string())
+        .collect()
+    }
+
+    pub fn get_classification(&self) -> DataClassification {
+        match self {
+            DataType::Integer | DataType::Float => DataClassification::Quantitative,
+            DataType::Unknown => DataClassification::Unknown,
+            _ => DataClassification::Qualitative,
+        }
+    }
 }
 
 impl PartialEq for DataType {
@@ -10,6 +10,8 @@ use std::{
 };
 pub mod data_classification;
 pub mod data_type;
+pub mod profile_accumulator;
+pub mod profile_sort;
 use ndarray::{Array1, ArrayView1};
 use rayon::prelude::*;
 
@@ -21,6 +23,7 @@ pub struct Stadistics {
     pub classification: DataClassification,
     pub data_type: DataType,
     pub distinct: String,
+    pub nulls: String,
     pub minimum: String,
     pub maximum: String,
     pub mean: String,
@@ -39,6 +42,7 @@ impl Stadistics {
             classification: DataClassification::Unknown,
             data_type: DataType::Unknown,
             distinct: String::default(),
+            nulls: String::default(),
             minimum: String::default(),
             maximum: String::default(),
             mean: String::default(),
@@ -53,7 +57,36 @@ impl Stadistics {
         }
     }
     pub async fn new(selected_column: &IcedColumn, full_column: Vec<String>) -> Self {
-        let (classification, data_type) = Self::get_column_analysis(&full_column);
+        Self::from_column(selected_column, &full_column)
+    }
+
+    /// Same as `new` without the runtime, so the columns of a profile are analysed in
+    /// parallel. The values are borrowed, a profile reads them from the column cache.
+    pub fn from_column(selected_column: &IcedColumn, full_column: &[String]) -> Self {
+        let nulls = full_column
+            .par_iter()
+            .filter(|value| value.trim().is_empty())
+            .count();
+        // A column without values has nothing to analyse
+        if nulls == full_column.len() {
+            return Self {
+                classification: selected_column.data_type.get_classification(),
+                data_type: selected_column.data_type.clone(),
+                header: selected_column.column_header.clone(),
+                distinct: 0.to_string(),
+                nulls: nulls.to_formatted_string(&Locale::en),
+                ..Self::default()
+            };
+        }
+
+        let (classification, data_type) = if selected_column.data_type != DataType::Unknown {
+            (
+                selected_column.data_type.get_classification(),
+                selected_column.data_type.clone(),
+            )
+        } else {
+            Self::get_column_analysis(full_column)
+        };
         if classification == DataClassification::Quantitative {
             let (
                 distinct_values,
@@ -67,12 +100,13 @@ impl Stadistics {
                 std_dev_value,
                 percentil,
                 quartil,
-            ) = Self::get_analysis_numeric(&full_column);
+            ) = Self::get_analysis_numeric(full_column);
             Self {
                 classification,
                 data_type,
                 header: selected_column.column_header.clone(),
                 distinct: distinct_values.to_formatted_string(&Locale::en),
+                nulls: nulls.to_formatted_string(&Locale::en),
                 minimum: format!("{:.6}", min),
                 maximum: format!("{:.6}", max),
                 mean: format!("{:.6}", mean_value),
@@ -97,12 +131,13 @@ impl Stadistics {
                 std_dev_value,
                 percentil,
                 quartil,
-            ) = Self::get_analysis_text(&full_column);
+            ) = Self::get_analysis_text(full_column);
             Self {
                 classification,
                 data_type,
                 header: selected_column.column_header.clone(),
                 distinct: distinct_values.to_formatted_string(&Locale::en),
+                nulls: nulls.to_formatted_string(&Locale::en),
                 minimum: format!("{:.6}", min),
                 maximum: format!("{:.6}", max),
                 mean: format!("{:.6}", mean_value),
@@ -117,8 +152,10 @@ impl Stadistics {
         }
     }
 
-    pub fn get_column_analysis(column: &Vec<String>) -> (DataClassification, DataType) {
-        let date_re = Regex::new(r"^\d{4}-\d{2}-\d{2}$|^\d{2}/\d{2}/\d{4}$").unwrap();
+    pub fn get_column_analysis(column: &[String]) -> (DataClassification, DataType) {
+        // Dates such as 03/04/2024 read as either day or month first, they are text unless the
+        // schema gives their format
+        let date_re = Regex::new(r"^\d{4}-\d{2}-\d{2}$").unwrap();
         let time_re = Regex::new(r"^\d{2}:\d{2}(:\d{2})?$").unwrap();
         let datetime_re = Regex::new(r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}(:\d{2})?$").unwrap();
         let coordinates_re =
@@ -173,7 +210,7 @@ impl Stadistics {
     }
 
     pub fn get_analysis_numeric(
-        column: &Vec<String>,
+        column: &[String],
     ) -> (usize, f64, f64, f64, f64, f64, f64, f64, f64, f64, f64) {
         let start = Instant::now();
         let data: Vec<f64> = column
@@ -280,7 +317,7 @@ impl Stadistics {
     }
 
     fn get_analysis_text(
-        column: &Vec<String>,
+        column: &[String],
     ) -> (
         usize,
         String,
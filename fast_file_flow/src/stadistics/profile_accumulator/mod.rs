@@ -0,0 +1,219 @@
+use crate::{
+    constants::sizes::{PROFILE_DISTINCT_SKETCH, PROFILE_SAMPLE_VALUES, SAMPLE_SEED_DEFAULT},
+    dynamictable::iced_column::IcedColumn,
+};
+use num_format::{Locale, ToFormattedString};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeSet},
+    hash::{Hash, Hasher},
+};
+
+use super::{data_classification::DataClassification, data_type::DataType, Stadistics};
+
+/// Count, mean and spread of a series of numbers, updated one value at a time.
+#[derive(Debug, Clone)]
+struct Moments {
+    count: usize,
+    minimum: f64,
+    maximum: f64,
+    mean: f64,
+    /// Sum of the squared differences to the mean.
+    squares: f64,
+}
+
+impl Moments {
+    fn new() -> Self {
+        Self {
+            count: 0,
+            minimum: f64::INFINITY,
+            maximum: f64::NEG_INFINITY,
+            mean: 0.0,
+            squares: 0.0,
+        }
+    }
+
+    fn add(&mut self, value: f64) {
+        self.count += 1;
+        self.minimum = self.minimum.min(value);
+        self.maximum = self.maximum.max(value);
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.squares += delta * (value - self.mean);
+    }
+
+    fn variance(&self) -> f64 {
+        self.squares / self.count as f64
+    }
+}
+
+/// Distinct values counted exactly while they are few, and estimated from the smallest
+/// hashes seen once they are many.
+#[derive(Debug, Clone)]
+struct DistinctSketch {
+    hashes: BTreeSet<u64>,
+}
+
+impl DistinctSketch {
+    fn new() -> Self {
+        Self {
+            hashes: BTreeSet::new(),
+        }
+    }
+
+    fn add<T: Hash + ?Sized>(&mut self, value: &T) {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        if self.hashes.len() < PROFILE_DISTINCT_SKETCH {
+            self.hashes.insert(hash);
+        } else if self.hashes.last().is_some_and(|last| hash < *last) && self.hashes.insert(hash) {
+            self.hashes.pop_last();
+        }
+    }
+
+    fn count(&self) -> usize {
+        match self.hashes.last() {
+            Some(last) if self.hashes.len() >= PROFILE_DISTINCT_SKETCH => {
+                ((PROFILE_DISTINCT_SKETCH - 1) as f64 * (u64::MAX as f64 / *last as f64)) as usize
+            }
+            _ => self.hashes.len(),
+        }
+    }
+}
+
+/// The statistics of a column gathered while its values are read, without keeping them.
+/// Counts, extremes, means and spreads are exact; the distinct values are estimated once
+/// there are many, and the medians, modes and quartiles come from a sample of the values.
+#[derive(Debug, Clone)]
+pub struct ProfileAccumulator {
+    count: usize,
+    nulls: usize,
+    numbers: Moments,
+    lengths: Moments,
+    distinct: DistinctSketch,
+    distinct_numbers: DistinctSketch,
+    sample: Vec<String>,
+    rng: StdRng,
+}
+
+impl Default for ProfileAccumulator {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            nulls: 0,
+            numbers: Moments::new(),
+            lengths: Moments::new(),
+            distinct: DistinctSketch::new(),
+            distinct_numbers: DistinctSketch::new(),
+            sample: vec![],
+            rng: StdRng::seed_from_u64(SAMPLE_SEED_DEFAULT),
+        }
+    }
+}
+
+impl ProfileAccumulator {
+    pub fn add(&mut self, value: &str) {
+        self.count += 1;
+        if value.trim().is_empty() {
+            self.nulls += 1;
+            return;
+        }
+
+        if let Ok(number) = value.parse::<f64>() {
+            self.numbers.add(number);
+            self.distinct_numbers.add(&number.to_bits());
+        }
+        self.lengths.add(value.len() as f64);
+        self.distinct.add(value);
+
+        // Every value read has the same chance to be in the sample
+        let seen = self.count - self.nulls;
+        if self.sample.len() < PROFILE_SAMPLE_VALUES {
+            self.sample.push(value.to_string());
+        } else {
+            let index = self.rng.gen_range(0..seen);
+            if index < PROFILE_SAMPLE_VALUES {
+                self.sample[index] = value.to_string();
+            }
+        }
+    }
+
+    /// The statistics of the column the values were read from, typed as `Stadistics::new`
+    /// types a whole column.
+    pub fn into_stadistics(self, selected_column: &IcedColumn) -> Stadistics {
+        let header = selected_column.column_header.clone();
+        let nulls = self.nulls.to_formatted_string(&Locale::en);
+        if self.nulls == self.count {
+            return Stadistics {
+                classification: selected_column.data_type.get_classification(),
+                data_type: selected_column.data_type.clone(),
+                header,
+                distinct: 0.to_string(),
+                nulls,
+                ..Stadistics::default()
+            };
+        }
+
+        let (classification, data_type) = if selected_column.data_type != DataType::Unknown {
+            (
+                selected_column.data_type.get_classification(),
+                selected_column.data_type.clone(),
+            )
+        } else {
+            Stadistics::get_column_analysis(&self.sample)
+        };
+
+        let has_numbers = self.sample.iter().any(|value| value.parse::<f64>().is_ok());
+        if classification == DataClassification::Quantitative && has_numbers {
+            let (_, _, _, _, median, mode, _, _, _, percentil, quartil) =
+                Stadistics::get_analysis_numeric(&self.sample);
+            let variance = self.numbers.variance();
+            Stadistics {
+                classification,
+                data_type,
+                header,
+                distinct: self
+                    .distinct_numbers
+                    .count()
+                    .to_formatted_string(&Locale::en),
+                nulls,
+                minimum: format!("{:.6}", self.numbers.minimum),
+                maximum: format!("{:.6}", self.numbers.maximum),
+                mean: format!("{:.6}", self.numbers.mean),
+                median: format!("{:.6}", median),
+                mode: format!("{:.6}", mode),
+                range: format!("{:.6}", self.numbers.maximum - self.numbers.minimum),
+                variance: format!("{:.6}", variance),
+                quartil: format!("{:.6}", quartil),
+                percentil: format!("{:.6}", percentil),
+                std_dev: format!("{:.6}", variance.sqrt()),
+            }
+        } else {
+            let (_, mode, _, _, _, median, _, _, _, percentil, quartil) =
+                Stadistics::get_analysis_text(&self.sample);
+            let variance = self.lengths.variance();
+            Stadistics {
+                classification,
+                data_type,
+                header,
+                distinct: self.distinct.count().to_formatted_string(&Locale::en),
+                nulls,
+                minimum: format!("{:.6}", self.lengths.minimum as usize),
+                maximum: format!("{:.6}", self.lengths.maximum as usize),
+                mean: format!("{:.6}", self.lengths.mean),
+                median: format!("{:.6}", median),
+                mode: format!("{:.6}", mode),
+                range: format!(
+                    "{:.6}",
+                    (self.lengths.maximum - self.lengths.minimum) as usize
+                ),
+                variance: format!("{:.6}", variance),
+                percentil: format!("{:.6}", percentil),
+                quartil: format!("{:.6}", quartil),
+                std_dev: format!("{:.6}", variance.sqrt()),
+            }
+        }
+    }
+}
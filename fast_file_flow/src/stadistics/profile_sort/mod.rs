@@ -0,0 +1,83 @@
+use std::cmp::Ordering;
+
+use crate::constants::english::{
+    PROFILE_COLUMN_LABEL, PROFILE_DISTINCT_LABEL, PROFILE_MAXIMUM_LABEL, PROFILE_MEAN_LABEL,
+    PROFILE_MINIMUM_LABEL, PROFILE_NULLS_LABEL, PROFILE_TYPE_LABEL,
+};
+
+use super::Stadistics;
+
+/// Columns of the profile grid, each one sorts the profile by its value.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum ProfileSort {
+    Column,
+    Type,
+    Nulls,
+    Distinct,
+    Minimum,
+    Maximum,
+    Mean,
+}
+
+impl ProfileSort {
+    pub fn default() -> Self {
+        ProfileSort::Column
+    }
+
+    pub fn to_string(&self) -> &str {
+        match self {
+            ProfileSort::Column => PROFILE_COLUMN_LABEL,
+            ProfileSort::Type => PROFILE_TYPE_LABEL,
+            ProfileSort::Nulls => PROFILE_NULLS_LABEL,
+            ProfileSort::Distinct => PROFILE_DISTINCT_LABEL,
+            ProfileSort::Minimum => PROFILE_MINIMUM_LABEL,
+            ProfileSort::Maximum => PROFILE_MAXIMUM_LABEL,
+            ProfileSort::Mean => PROFILE_MEAN_LABEL,
+        }
+    }
+
+    pub fn all() -> Vec<ProfileSort> {
+        vec![
+            ProfileSort::Column,
+            ProfileSort::Type,
+            ProfileSort::Nulls,
+            ProfileSort::Distinct,
+            ProfileSort::Minimum,
+            ProfileSort::Maximum,
+            ProfileSort::Mean,
+        ]
+    }
+
+    pub fn get_value<'a>(&self, stadistics: &'a Stadistics) -> &'a str {
+        match self {
+            ProfileSort::Column => &stadistics.header,
+            ProfileSort::Type => stadistics.data_type.to_string(),
+            ProfileSort::Nulls => &stadistics.nulls,
+            ProfileSort::Distinct => &stadistics.distinct,
+            ProfileSort::Minimum => &stadistics.minimum,
+            ProfileSort::Maximum => &stadistics.maximum,
+            ProfileSort::Mean => &stadistics.mean,
+        }
+    }
+
+    /// Numbers are compared by value, thousands separators included, and before any text.
+    pub fn sort(&self, profile: &mut [Stadistics], ascending: bool) {
+        profile.sort_by(|a, b| {
+            let ordering = Self::compare(self.get_value(a), self.get_value(b));
+            match ascending {
+                true => ordering,
+                false => ordering.reverse(),
+            }
+        });
+    }
+
+    fn compare(a: &str, b: &str) -> Ordering {
+        let to_number = |value: &str| value.replace(',', "").parse::<f64>().ok();
+        match (to_number(a), to_number(b)) {
+            (Some(a), Some(b)) => a.total_cmp(&b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => a.cmp(b),
+        }
+    }
+}
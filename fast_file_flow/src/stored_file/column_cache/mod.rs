@@ -0,0 +1,192 @@
+use crate::constants::english::{CACHE_STATUS_BUILDING, CACHE_STATUS_STREAMING};
+use futures::stream::{BoxStream, StreamExt};
+use std::{
+    future::Future,
+    io::{Error, ErrorKind},
+    mem::size_of,
+    sync::Arc,
+};
+use tokio::sync::Mutex;
+
+#[derive(Debug)]
+pub struct CachedColumn {
+    pub values: Vec<String>,
+    /// Only present when every non empty value is a number.
+    pub numbers: Option<Vec<f64>>,
+}
+
+#[derive(Debug)]
+pub struct CachedTable {
+    pub columns: Vec<CachedColumn>,
+    pub rows: usize,
+    pub size: usize,
+}
+
+#[derive(Debug)]
+enum CacheState {
+    Empty,
+    Loaded(Arc<CachedTable>),
+    Exceeded,
+}
+
+/// Columns of a file kept in memory, shared by every clone of the stored file.
+#[derive(Debug)]
+pub struct ColumnCache {
+    /// Memory budget in bytes, zero disables the cache.
+    pub budget: usize,
+    state: Mutex<CacheState>,
+}
+
+impl ColumnCache {
+    pub fn default() -> Self {
+        Self::new(0)
+    }
+
+    pub fn new(budget: usize) -> Self {
+        Self {
+            budget,
+            state: Mutex::new(CacheState::Empty),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.budget > 0
+    }
+
+    /// Returns the table, building it the first time. Callers arriving while it is built
+    /// wait for it instead of reading the file again. A build that was cancelled is tried
+    /// again the next time.
+    pub async fn get_or_build<F, Fut>(&self, records: F) -> Option<Arc<CachedTable>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<BoxStream<'static, Result<Vec<String>, Error>>, Error>>,
+    {
+        if !self.is_enabled() {
+            return None;
+        }
+
+        let mut state = self.state.lock().await;
+        match &*state {
+            CacheState::Loaded(table) => return Some(table.clone()),
+            CacheState::Exceeded => return None,
+            CacheState::Empty => {}
+        }
+
+        let table = match records().await {
+            Ok(records) => CachedTable::build(records, self.budget).await,
+            Err(error) => Err(error),
+        };
+        match table {
+            Ok(Some(table)) => {
+                let table = Arc::new(table);
+                *state = CacheState::Loaded(table.clone());
+                Some(table)
+            }
+            Err(error) if error.kind() == ErrorKind::Interrupted => None,
+            _ => {
+                *state = CacheState::Exceeded;
+                None
+            }
+        }
+    }
+
+    /// Returns the table only when it is already built, never waits.
+    pub fn get_loaded(&self) -> Option<Arc<CachedTable>> {
+        match &*self.state.try_lock().ok()? {
+            CacheState::Loaded(table) => Some(table.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn get_status(&self) -> String {
+        if !self.is_enabled() {
+            return String::new();
+        }
+
+        match self.state.try_lock().as_deref() {
+            Ok(CacheState::Loaded(table)) => {
+                format!("{:.2} MB cached", table.size as f64 / (1024.0 * 1024.0))
+            }
+            Ok(CacheState::Exceeded) => CACHE_STATUS_STREAMING.to_string(),
+            Ok(CacheState::Empty) => String::new(),
+            Err(_) => CACHE_STATUS_BUILDING.to_string(),
+        }
+    }
+}
+
+impl CachedTable {
+    /// Reads every record once, gives up as soon as the budget is exceeded or a record
+    /// can not be read, so the caller falls back to streaming the file.
+    async fn build(
+        mut records: BoxStream<'static, Result<Vec<String>, Error>>,
+        budget: usize,
+    ) -> Result<Option<Self>, Error> {
+        let mut columns: Vec<Vec<String>> = vec![];
+        let mut size = 0;
+        let mut rows = 0;
+
+        while let Some(record) = records.next().await {
+            let record = match record {
+                Ok(record) => record,
+                Err(error) if error.kind() == ErrorKind::Interrupted => return Err(error),
+                Err(_) => return Ok(None),
+            };
+            if rows == 0 {
+                columns = vec![vec![]; record.len()];
+            } else if record.len() != columns.len() {
+                return Ok(None);
+            }
+
+            for (column, value) in columns.iter_mut().zip(record) {
+                size += size_of::<String>() + value.capacity();
+                column.push(value);
+            }
+            rows += 1;
+
+            if size > budget {
+                return Ok(None);
+            }
+        }
+
+        let columns = columns
+            .into_iter()
+            .map(|values| {
+                let numbers =
+                    Self::to_numbers(&values).filter(|_| size + rows * size_of::<f64>() <= budget);
+                if numbers.is_some() {
+                    size += rows * size_of::<f64>();
+                }
+                CachedColumn { values, numbers }
+            })
+            .collect();
+
+        Ok(Some(Self {
+            columns,
+            rows,
+            size,
+        }))
+    }
+
+    fn to_numbers(values: &[String]) -> Option<Vec<f64>> {
+        let mut has_number = false;
+        let numbers = values
+            .iter()
+            .map(|value| match value.as_str() {
+                "" => Some(0.0),
+                value => {
+                    has_number = true;
+                    value.parse::<f64>().ok()
+                }
+            })
+            .collect::<Option<Vec<f64>>>()?;
+
+        Some(numbers).filter(|_| has_number)
+    }
+
+    pub fn get_record(&self, row: usize) -> Vec<String> {
+        self.columns
+            .iter()
+            .map(|column| column.values[row].clone())
+            .collect()
+    }
+}
@@ -0,0 +1,73 @@
+use crate::constants::{
+    english::{COMPRESSION_BZIP2, COMPRESSION_GZIP, COMPRESSION_NONE, COMPRESSION_ZSTD},
+    path::{BZIP2_MAGIC, GZIP_MAGIC, ZSTD_MAGIC},
+};
+use async_compression::tokio::bufread::{BzDecoder, GzipDecoder, ZstdDecoder};
+use std::io::Error;
+use tokio::{
+    fs::File,
+    io::{AsyncRead, AsyncReadExt, BufReader},
+};
+
+pub type FileReader = Box<dyn AsyncRead + Unpin + Send>;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum Compression {
+    None,
+    Gzip,
+    Zstd,
+    Bzip2,
+}
+
+impl Compression {
+    pub fn default() -> Self {
+        Compression::None
+    }
+
+    pub fn to_string(&self) -> &str {
+        match self {
+            Compression::None => COMPRESSION_NONE,
+            Compression::Gzip => COMPRESSION_GZIP,
+            Compression::Zstd => COMPRESSION_ZSTD,
+            Compression::Bzip2 => COMPRESSION_BZIP2,
+        }
+    }
+
+    pub async fn detect(file_path: &str) -> Self {
+        let mut magic = vec![];
+        if let Ok(file) = File::open(file_path).await {
+            let _ = file.take(4).read_to_end(&mut magic).await;
+        }
+
+        if magic.starts_with(GZIP_MAGIC) {
+            Compression::Gzip
+        } else if magic.starts_with(ZSTD_MAGIC) {
+            Compression::Zstd
+        } else if magic.starts_with(BZIP2_MAGIC) {
+            Compression::Bzip2
+        } else {
+            Compression::None
+        }
+    }
+
+    /// Opens the file decompressing it on the fly, concatenated gzip and zstd frames are
+    /// read as a single stream.
+    pub async fn open(&self, file_path: &str) -> Result<FileReader, Error> {
+        let file = File::open(file_path).await?;
+
+        Ok(match self {
+            Compression::None => Box::new(file),
+            Compression::Gzip => {
+                let mut decoder = GzipDecoder::new(BufReader::new(file));
+                decoder.multiple_members(true);
+                Box::new(decoder)
+            }
+            Compression::Zstd => {
+                let mut decoder = ZstdDecoder::new(BufReader::new(file));
+                decoder.multiple_members(true);
+                Box::new(decoder)
+            }
+            Compression::Bzip2 => Box::new(BzDecoder::new(BufReader::new(file))),
+        })
+    }
+}
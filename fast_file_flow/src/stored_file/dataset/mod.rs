@@ -0,0 +1,114 @@
+use crate::constants::{
+    english::{DATASET_COLUMN_ORDER, DATASET_EXTRA_COLUMNS, DATASET_MISSING_COLUMNS},
+    path::DATA_FORMATS,
+};
+use std::{fs::read_dir, path::Path};
+
+use super::StoredFile;
+
+/// Files with the same columns read as a single table, listed from a directory or a glob
+/// pattern such as `data/sales_*.csv`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Dataset {
+    /// The directory or pattern given by the user, empty for a single file.
+    pub path: String,
+    pub sources: Vec<String>,
+    /// Files left out because their columns differ from the first one, with the reason.
+    pub mismatches: Vec<String>,
+}
+
+impl Dataset {
+    pub fn default() -> Self {
+        Self {
+            path: String::new(),
+            sources: vec![],
+            mismatches: vec![],
+        }
+    }
+
+    pub fn new(path: String) -> Self {
+        let sources = Self::list_sources(&path);
+        Self {
+            path,
+            sources,
+            mismatches: vec![],
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.path.is_empty()
+    }
+
+    /// A directory, or a pattern that is not the name of an existing file.
+    pub fn is_dataset_path(path: &str) -> bool {
+        let file_path = Path::new(path);
+        file_path.is_dir() || (!file_path.exists() && path.contains(['*', '?', '[']))
+    }
+
+    /// The file the format of a dataset is detected from, the path itself for a single file.
+    pub fn get_main_source(path: &str) -> String {
+        if !Self::is_dataset_path(path) {
+            return path.to_string();
+        }
+        Self::list_sources(path)
+            .into_iter()
+            .next()
+            .unwrap_or(path.to_string())
+    }
+
+    /// Data files sorted by name, so daily partitions are read in order.
+    fn list_sources(path: &str) -> Vec<String> {
+        let paths: Vec<_> = if Path::new(path).is_dir() {
+            read_dir(path)
+                .map(|entries| {
+                    entries
+                        .filter_map(|entry| entry.ok().map(|e| e.path()))
+                        .collect()
+                })
+                .unwrap_or_default()
+        } else {
+            glob::glob(path)
+                .map(|paths| paths.filter_map(Result::ok).collect())
+                .unwrap_or_default()
+        };
+
+        let mut sources: Vec<String> = paths
+            .into_iter()
+            .filter(|path| path.is_file())
+            .map(|path| path.to_string_lossy().into_owned())
+            .filter(|path| DATA_FORMATS.contains(&StoredFile::get_file_extension(path).as_str()))
+            .collect();
+        sources.sort();
+        sources
+    }
+
+    /// Why the columns of a file do not match the ones of the dataset, `None` when they do.
+    pub fn compare_headers(expected: &[String], found: &[String]) -> Option<String> {
+        if expected == found {
+            return None;
+        }
+
+        let missing: Vec<&str> = expected
+            .iter()
+            .filter(|header| !found.contains(header))
+            .map(String::as_str)
+            .collect();
+        let extra: Vec<&str> = found
+            .iter()
+            .filter(|header| !expected.contains(header))
+            .map(String::as_str)
+            .collect();
+
+        let mut reasons = vec![];
+        if !missing.is_empty() {
+            reasons.push(DATASET_MISSING_COLUMNS.replace("{}", &missing.join(", ")));
+        }
+        if !extra.is_empty() {
+            reasons.push(DATASET_EXTRA_COLUMNS.replace("{}", &extra.join(", ")));
+        }
+        if reasons.is_empty() {
+            reasons.push(DATASET_COLUMN_ORDER.to_string());
+        }
+        Some(reasons.join(", "))
+    }
+}
@@ -0,0 +1,45 @@
+use crate::constants::english::{LINE_TERMINATOR_CR, LINE_TERMINATOR_CRLF, LINE_TERMINATOR_LF};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum LineTerminator {
+    CRLF,
+    LF,
+    CR,
+}
+
+impl LineTerminator {
+    pub fn default() -> Self {
+        LineTerminator::LF
+    }
+
+    pub fn to_string(&self) -> &str {
+        match self {
+            LineTerminator::CRLF => LINE_TERMINATOR_CRLF,
+            LineTerminator::LF => LINE_TERMINATOR_LF,
+            LineTerminator::CR => LINE_TERMINATOR_CR,
+        }
+    }
+
+    pub fn from_string(unwrap: &str) -> LineTerminator {
+        match unwrap {
+            LINE_TERMINATOR_CRLF => LineTerminator::CRLF,
+            LINE_TERMINATOR_CR => LineTerminator::CR,
+            _ => LineTerminator::LF,
+        }
+    }
+
+    pub fn all() -> Vec<String> {
+        [LineTerminator::CRLF, LineTerminator::LF, LineTerminator::CR]
+            .iter()
+            .map(|terminator| terminator.to_string().to_string())
+            .collect()
+    }
+
+    pub fn get_csv_terminator(&self) -> csv::Terminator {
+        match self {
+            LineTerminator::CRLF => csv::Terminator::CRLF,
+            LineTerminator::LF => csv::Terminator::Any(b'\n'),
+            LineTerminator::CR => csv::Terminator::Any(b'\r'),
+        }
+    }
+}
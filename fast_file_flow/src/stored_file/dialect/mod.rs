@@ -0,0 +1,217 @@
+pub mod line_terminator;
+
+use crate::constants::{english::DIALECT_TAB_LABEL, sizes::DIALECT_SNIFF_LINES};
+use csv_async::AsyncReaderBuilder;
+use line_terminator::LineTerminator;
+use std::collections::HashMap;
+
+const DELIMITERS: [u8; 4] = [b',', b';', b'\t', b'|'];
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Dialect {
+    pub delimiter: u8,
+    pub quote: u8,
+    pub escape: Option<u8>,
+    pub terminator: LineTerminator,
+}
+
+impl Dialect {
+    pub fn default() -> Self {
+        Self {
+            delimiter: b',',
+            quote: b'"',
+            escape: None,
+            terminator: LineTerminator::default(),
+        }
+    }
+
+    pub fn new(delimiter: u8, quote: u8, escape: Option<u8>, terminator: LineTerminator) -> Self {
+        Self {
+            delimiter,
+            quote,
+            escape,
+            terminator,
+        }
+    }
+
+    /// Guesses the dialect from the first bytes of a file, the delimiter that splits the
+    /// lines into the most consistent number of fields wins.
+    pub fn sniff(sample: &[u8]) -> Self {
+        let text = String::from_utf8_lossy(sample);
+
+        let terminator = if text.contains("\r\n") {
+            LineTerminator::CRLF
+        } else if text.contains('\r') {
+            LineTerminator::CR
+        } else {
+            LineTerminator::LF
+        };
+
+        let quote = Self::sniff_quote(&text);
+        let escape = Some(b'\\').filter(|_| text.contains(&format!("\\{}", quote as char)));
+
+        let mut dialect = Self::new(b',', quote, escape, terminator);
+        let mut best = (b',', (0, 0));
+        for delimiter in DELIMITERS {
+            dialect.delimiter = delimiter;
+            let score = dialect.get_score(sample);
+            if score > best.1 {
+                best = (delimiter, score);
+            }
+        }
+
+        // A single field per line means no delimiter was found at all
+        let (delimiter, (_, fields)) = best;
+        dialect.delimiter = if fields > 1 { delimiter } else { b',' };
+        dialect
+    }
+
+    /// Number of lines sharing the most common field count, and that field count.
+    fn get_score(&self, sample: &[u8]) -> (usize, usize) {
+        let mut rdr = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .escape(self.escape)
+            .double_quote(self.escape.is_none())
+            .from_reader(sample);
+
+        let mut lengths: Vec<usize> = rdr
+            .records()
+            .take(DIALECT_SNIFF_LINES)
+            .map_while(Result::ok)
+            .map(|record| record.len())
+            .collect();
+
+        // The sample may cut the last line in half
+        if lengths.len() > 1 {
+            lengths.pop();
+        }
+
+        let mut frequencies: HashMap<usize, usize> = HashMap::new();
+        for length in lengths {
+            *frequencies.entry(length).or_default() += 1;
+        }
+
+        frequencies
+            .into_iter()
+            .map(|(length, frequency)| (frequency, length))
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Guesses whether the first line is a header, it is when its values do not share the
+    /// numeric type of the values below them.
+    pub fn sniff_header(&self, sample: &[u8]) -> bool {
+        let mut records: Vec<csv::StringRecord> = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .escape(self.escape)
+            .double_quote(self.escape.is_none())
+            .from_reader(sample)
+            .records()
+            .take(DIALECT_SNIFF_LINES)
+            .map_while(Result::ok)
+            .collect();
+
+        // The sample may cut the last line in half
+        if records.len() > 2 {
+            records.pop();
+        }
+        let Some((header, rows)) = records.split_first() else {
+            return true;
+        };
+
+        let is_number = |value: &str| value.trim().parse::<f64>().is_ok();
+        let mut votes = 0;
+        for (index, value) in header.iter().enumerate() {
+            let mut column = rows
+                .iter()
+                .filter_map(|row| row.get(index))
+                .filter(|value| !value.trim().is_empty())
+                .peekable();
+            if value.trim().is_empty() || column.peek().is_none() || !column.all(is_number) {
+                continue;
+            }
+            votes += if is_number(value) { -1 } else { 1 };
+        }
+
+        votes >= 0
+    }
+
+    fn sniff_quote(text: &str) -> u8 {
+        let count_quoted = |quote: char| {
+            text.lines()
+                .flat_map(|line| {
+                    line.split(|c: char| c.is_ascii() && DELIMITERS.contains(&(c as u8)))
+                })
+                .map(str::trim)
+                .filter(|field| field.len() > 1 && field.starts_with(quote))
+                .filter(|field| field.ends_with(quote))
+                .count()
+        };
+
+        if count_quoted('\'') > count_quoted('"') {
+            b'\''
+        } else {
+            b'"'
+        }
+    }
+
+    pub fn get_reader_builder(&self) -> AsyncReaderBuilder {
+        let mut builder = AsyncReaderBuilder::new();
+        builder
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .escape(self.escape)
+            .double_quote(self.escape.is_none());
+        builder
+    }
+
+    pub fn get_writer_builder(&self) -> csv::WriterBuilder {
+        let mut builder = csv::WriterBuilder::new();
+        builder
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .escape(self.escape.unwrap_or(b'\\'))
+            .double_quote(self.escape.is_none())
+            .terminator(self.terminator.get_csv_terminator());
+        builder
+    }
+
+    pub fn char_to_string(value: Option<u8>) -> String {
+        match value {
+            Some(b'\t') => DIALECT_TAB_LABEL.to_string(),
+            Some(value) => (value as char).to_string(),
+            None => String::new(),
+        }
+    }
+
+    /// Accepts a single ascii character, `\t` or the tab label.
+    pub fn parse_char(value: &str) -> Option<u8> {
+        match value {
+            "\\t" => Some(b'\t'),
+            value if value.eq_ignore_ascii_case(DIALECT_TAB_LABEL) => Some(b'\t'),
+            value if value.len() == 1 && value.is_ascii() => value.bytes().next(),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Dialect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            Self::char_to_string(Some(self.delimiter)),
+            Self::char_to_string(Some(self.quote))
+        )?;
+        if self.escape.is_some() {
+            write!(f, " {}", Self::char_to_string(self.escape))?;
+        }
+        write!(f, " {}", self.terminator.to_string())
+    }
+}
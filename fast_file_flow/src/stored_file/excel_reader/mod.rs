@@ -0,0 +1,96 @@
+use crate::constants::english::GENERATED_HEADER_PREFIX;
+use calamine::{open_workbook_auto, Data, Range, Reader};
+use chrono::NaiveTime;
+use futures::stream::{self, BoxStream, StreamExt};
+use std::io::{Error, ErrorKind};
+
+use super::read_options::ReadOptions;
+
+pub struct ExcelReader {}
+
+impl ExcelReader {
+    pub async fn get_sheet_names(file_path: &str) -> Result<Vec<String>, Error> {
+        let file_path = file_path.to_string();
+        tokio::task::spawn_blocking(move || {
+            let workbook = open_workbook_auto(&file_path).map_err(Self::to_error)?;
+            Ok(workbook.sheet_names())
+        })
+        .await?
+    }
+
+    pub async fn get_headers(
+        file_path: &str,
+        read_options: &ReadOptions,
+    ) -> Result<Vec<String>, Error> {
+        let (range, header_index) = Self::get_range(file_path, read_options).await?;
+
+        Ok((0..range.width())
+            .map(|column| match range.get((header_index, column)) {
+                Some(value) if !Self::to_value(value).trim().is_empty() => Self::to_value(value),
+                _ => format!("{}{}", GENERATED_HEADER_PREFIX, column + 1),
+            })
+            .collect())
+    }
+
+    pub async fn get_records(
+        file_path: &str,
+        read_options: &ReadOptions,
+    ) -> Result<BoxStream<'static, Result<Vec<String>, Error>>, Error> {
+        let (range, header_index) = Self::get_range(file_path, read_options).await?;
+        let first_row = (header_index + 1).min(range.height());
+
+        Ok(stream::iter((first_row..range.height()).map(move |row| {
+            Ok((0..range.width())
+                .map(|column| {
+                    range
+                        .get((row, column))
+                        .map(Self::to_value)
+                        .unwrap_or_default()
+                })
+                .collect())
+        }))
+        .boxed())
+    }
+
+    /// Loads the selected sheet and returns it with the header position relative to the
+    /// used range, since calamine trims the empty rows above the first cell.
+    async fn get_range(
+        file_path: &str,
+        read_options: &ReadOptions,
+    ) -> Result<(Range<Data>, usize), Error> {
+        let file_path = file_path.to_string();
+        let read_options = read_options.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let mut workbook = open_workbook_auto(&file_path).map_err(Self::to_error)?;
+            let sheet = if read_options.sheet.is_empty() {
+                workbook.sheet_names().first().cloned().unwrap_or_default()
+            } else {
+                read_options.sheet
+            };
+            let range = workbook.worksheet_range(&sheet).map_err(Self::to_error)?;
+
+            let first_row = range.start().map(|(row, _)| row as usize).unwrap_or(0);
+            let header_index = read_options.header_row.saturating_sub(first_row);
+            Ok((range, header_index))
+        })
+        .await?
+    }
+
+    fn to_value(value: &Data) -> String {
+        match value {
+            Data::Empty | Data::Error(_) => String::new(),
+            Data::String(text) | Data::DateTimeIso(text) | Data::DurationIso(text) => text.clone(),
+            Data::DateTime(date) if date.is_datetime() => match date.as_datetime() {
+                Some(date) if date.time() == NaiveTime::MIN => date.format("%Y-%m-%d").to_string(),
+                Some(date) => date.format("%Y-%m-%d %H:%M:%S").to_string(),
+                None => date.to_string(),
+            },
+            item => item.to_string(),
+        }
+    }
+
+    fn to_error(error: calamine::Error) -> Error {
+        Error::new(ErrorKind::InvalidData, error.to_string())
+    }
+}
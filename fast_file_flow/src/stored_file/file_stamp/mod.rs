@@ -0,0 +1,78 @@
+use crate::constants::sizes::FILE_STAMP_TAIL_BYTES;
+use std::io::SeekFrom;
+use tokio::{
+    fs::File,
+    io::{AsyncReadExt, AsyncSeekExt},
+};
+
+use super::row_index::RowIndex;
+
+/// How a file changed on disk since it was read.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FileChange {
+    Unchanged,
+    Appended,
+    Rewritten,
+}
+
+/// Size and modification time of the part of a file that was read, with its last bytes to
+/// tell rows appended after them from a file written again.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileStamp {
+    pub size: u64,
+    pub modified: u64,
+    pub tail: Vec<u8>,
+}
+
+impl FileStamp {
+    pub fn default() -> Self {
+        Self {
+            size: 0,
+            modified: 0,
+            tail: vec![],
+        }
+    }
+
+    /// Stamps the first `size` bytes of the file.
+    pub async fn new(file_path: &str, size: u64) -> Self {
+        let modified = RowIndex::get_file_stamp(file_path).map_or(0, |(_, modified)| modified);
+        let start = size.saturating_sub(FILE_STAMP_TAIL_BYTES);
+        Self {
+            size,
+            modified,
+            tail: Self::read_range(file_path, start, size)
+                .await
+                .unwrap_or_default(),
+        }
+    }
+
+    /// A file was appended when it grew and the bytes read before, which end with a
+    /// complete line, are still there. Files that can not be appended are always rewritten.
+    pub async fn get_change(&self, file_path: &str, can_append: bool) -> FileChange {
+        let Ok((size, modified)) = RowIndex::get_file_stamp(file_path) else {
+            return FileChange::Rewritten;
+        };
+        if modified == self.modified {
+            return FileChange::Unchanged;
+        }
+
+        let is_complete = matches!(self.tail.last(), Some(b'\n' | b'\r'));
+        if !can_append || !is_complete || size <= self.size {
+            return FileChange::Rewritten;
+        }
+
+        let start = self.size - self.tail.len() as u64;
+        match Self::read_range(file_path, start, self.size).await {
+            Some(tail) if tail == self.tail => FileChange::Appended,
+            _ => FileChange::Rewritten,
+        }
+    }
+
+    async fn read_range(file_path: &str, start: u64, end: u64) -> Option<Vec<u8>> {
+        let mut file = File::open(file_path).await.ok()?;
+        file.seek(SeekFrom::Start(start)).await.ok()?;
+        let mut bytes = vec![];
+        file.take(end - start).read_to_end(&mut bytes).await.ok()?;
+        Some(bytes)
+    }
+}
@@ -1,13 +1,18 @@
 use core::mem::discriminant as tag;
 
 use crate::constants::english::{
-    DIALOG_FILE_EXTENSION_CSV, DIALOG_FILE_EXTENSION_JSON, UNKNOWN_LABEL,
+    DATABASE_LABEL, DIALOG_FILE_EXTENSION_CSV, DIALOG_FILE_EXTENSION_JSON,
+    DIALOG_FILE_EXTENSION_NDJSON, DIALOG_FILE_EXTENSION_PARQUET, UNKNOWN_LABEL, WORKBOOK_LABEL,
 };
 
 #[derive(Debug, Clone)]
 pub enum FileType {
     CSV,
     JSON,
+    NDJSON,
+    Parquet,
+    Excel,
+    SQLite,
     Unknown,
 }
 
@@ -22,6 +27,10 @@ impl FileType {
         match self {
             FileType::CSV => DIALOG_FILE_EXTENSION_CSV,
             FileType::JSON => DIALOG_FILE_EXTENSION_JSON,
+            FileType::NDJSON => DIALOG_FILE_EXTENSION_NDJSON,
+            FileType::Parquet => DIALOG_FILE_EXTENSION_PARQUET,
+            FileType::Excel => WORKBOOK_LABEL,
+            FileType::SQLite => DATABASE_LABEL,
             FileType::Unknown => UNKNOWN_LABEL,
         }
     }
@@ -30,6 +39,10 @@ impl FileType {
         match unwrap {
             DIALOG_FILE_EXTENSION_CSV => FileType::CSV,
             DIALOG_FILE_EXTENSION_JSON => FileType::JSON,
+            DIALOG_FILE_EXTENSION_NDJSON => FileType::NDJSON,
+            DIALOG_FILE_EXTENSION_PARQUET => FileType::Parquet,
+            WORKBOOK_LABEL => FileType::Excel,
+            DATABASE_LABEL => FileType::SQLite,
             _ => FileType::Unknown, // Maneja todos los demás casos
         }
     }
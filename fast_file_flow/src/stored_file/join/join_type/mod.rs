@@ -0,0 +1,40 @@
+use crate::constants::english::{JOIN_ANTI, JOIN_INNER, JOIN_LEFT};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum JoinType {
+    /// Keeps the rows with a match, once per matching row.
+    Inner,
+    /// Keeps every row, the joined columns are empty when there is no match.
+    Left,
+    /// Keeps only the rows without a match, no column is brought over.
+    Anti,
+}
+
+impl JoinType {
+    pub fn default() -> Self {
+        JoinType::Left
+    }
+
+    pub fn to_string(&self) -> &str {
+        match self {
+            JoinType::Inner => JOIN_INNER,
+            JoinType::Left => JOIN_LEFT,
+            JoinType::Anti => JOIN_ANTI,
+        }
+    }
+
+    pub fn from_string(unwrap: &str) -> JoinType {
+        match unwrap {
+            JOIN_INNER => JoinType::Inner,
+            JOIN_ANTI => JoinType::Anti,
+            _ => JoinType::Left,
+        }
+    }
+
+    pub fn all() -> Vec<String> {
+        [JoinType::Inner, JoinType::Left, JoinType::Anti]
+            .iter()
+            .map(|join_type| join_type.to_string().to_string())
+            .collect()
+    }
+}
@@ -0,0 +1,183 @@
+pub mod join_type;
+
+use crate::constants::english::ERROR_JOIN_COLUMN;
+use futures::stream::{self, StreamExt};
+use join_type::JoinType;
+use std::{
+    collections::HashMap,
+    io::{Error, ErrorKind},
+    path::Path,
+    sync::Arc,
+};
+
+use super::{RecordStream, StoredFile};
+
+/// A second file joined to the loaded one, matching the key columns pair by pair.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Join {
+    /// Empty when nothing is joined.
+    pub file_path: String,
+    pub join_type: JoinType,
+    pub left_keys: Vec<String>,
+    pub right_keys: Vec<String>,
+    /// Columns of the joined file brought over.
+    pub columns: Vec<String>,
+}
+
+impl Join {
+    pub fn default() -> Self {
+        Self {
+            file_path: String::new(),
+            join_type: JoinType::default(),
+            left_keys: vec![],
+            right_keys: vec![],
+            columns: vec![],
+        }
+    }
+
+    pub fn new(
+        file_path: String,
+        join_type: JoinType,
+        left_keys: Vec<String>,
+        right_keys: Vec<String>,
+        columns: Vec<String>,
+    ) -> Self {
+        Self {
+            file_path,
+            join_type,
+            left_keys,
+            right_keys,
+            columns,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.file_path.is_empty()
+    }
+}
+
+/// The joined file kept in memory, its selected columns grouped by key.
+#[derive(Debug)]
+pub struct JoinTable {
+    pub join_type: JoinType,
+    /// Names of the joined columns, prefixed with the file name when the loaded file
+    /// already has a column with that name.
+    pub headers: Vec<String>,
+    /// Every column of the joined file, to choose the ones brought over.
+    pub right_headers: Vec<String>,
+    left_keys: Vec<usize>,
+    rows: HashMap<Vec<String>, Vec<Vec<String>>>,
+}
+
+impl JoinTable {
+    pub async fn build(join: &Join, left_headers: &[String]) -> Result<Self, Error> {
+        let right = Box::pin(StoredFile::new(join.file_path.clone())).await;
+        if !right.is_supported() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                right.get_unsupported_error(),
+            ));
+        }
+
+        let right_headers: Vec<String> = right
+            .columns
+            .headers
+            .iter()
+            .map(|column| column.column_header.clone())
+            .collect();
+        let left_keys = Self::get_indexes(&join.left_keys, left_headers)?;
+        let right_keys = Self::get_indexes(&join.right_keys, &right_headers)?;
+        let columns = match join.join_type {
+            JoinType::Anti => vec![],
+            _ => Self::get_indexes(&join.columns, &right_headers)?,
+        };
+
+        let prefix = Path::new(&join.file_path)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let headers = columns
+            .iter()
+            .map(|index| {
+                let header = &right_headers[*index];
+                match left_headers.contains(header) {
+                    true => format!("{}.{}", prefix, header),
+                    false => header.clone(),
+                }
+            })
+            .collect();
+
+        let mut rows: HashMap<Vec<String>, Vec<Vec<String>>> = HashMap::new();
+        let mut records = right.get_records().await?;
+        while let Some(record) = records.next().await {
+            let record = record?;
+            let key = right_keys
+                .iter()
+                .map(|index| record[*index].clone())
+                .collect();
+            let values = columns.iter().map(|index| record[*index].clone()).collect();
+            rows.entry(key).or_default().push(values);
+        }
+
+        Ok(Self {
+            join_type: join.join_type.clone(),
+            headers,
+            right_headers,
+            left_keys,
+            rows,
+        })
+    }
+
+    fn get_indexes(names: &[String], headers: &[String]) -> Result<Vec<usize>, Error> {
+        names
+            .iter()
+            .map(|name| {
+                headers
+                    .iter()
+                    .position(|header| header == name)
+                    .ok_or(Error::new(
+                        ErrorKind::NotFound,
+                        ERROR_JOIN_COLUMN.replace("{}", name),
+                    ))
+            })
+            .collect()
+    }
+
+    /// The rows a record turns into, none when it is dropped by the join.
+    pub fn apply(&self, record: Vec<String>) -> Vec<Vec<String>> {
+        let key: Vec<String> = self
+            .left_keys
+            .iter()
+            .map(|index| record.get(*index).cloned().unwrap_or_default())
+            .collect();
+        let matches = self.rows.get(&key);
+
+        match (&self.join_type, matches) {
+            (JoinType::Anti, Some(_)) => vec![],
+            (JoinType::Anti, None) => vec![record],
+            (JoinType::Inner, None) => vec![],
+            (JoinType::Left, None) => {
+                let mut values = record;
+                values.resize(values.len() + self.headers.len(), String::new());
+                vec![values]
+            }
+            (_, Some(matches)) => matches
+                .iter()
+                .map(|joined| {
+                    let mut values = record.clone();
+                    values.extend(joined.iter().cloned());
+                    values
+                })
+                .collect(),
+        }
+    }
+
+    pub fn join_records(self: Arc<Self>, records: RecordStream) -> RecordStream {
+        records
+            .flat_map(move |record| match record {
+                Ok(record) => stream::iter(self.apply(record).into_iter().map(Ok)).boxed(),
+                Err(error) => stream::once(async { Err(error) }).boxed(),
+            })
+            .boxed()
+    }
+}
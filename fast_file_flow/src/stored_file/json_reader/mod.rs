@@ -0,0 +1,128 @@
+use crate::constants::english::JSON_VALUE_HEADER;
+use futures::stream::{self, BoxStream, StreamExt};
+use serde_json::Value;
+use std::{
+    collections::{HashMap, HashSet},
+    io::Error,
+};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, BufReader};
+
+use super::{file_type::FileType, row_policy::RejectedRow};
+
+pub struct JsonReader {}
+
+impl JsonReader {
+    pub async fn get_headers<R>(reader: R, sintaxis: &FileType) -> Result<Vec<String>, Error>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        let mut objects = Self::get_objects(reader, sintaxis).await?;
+        let mut headers: Vec<String> = vec![];
+        let mut seen: HashSet<String> = HashSet::new();
+
+        while let Some(object) = objects.next().await {
+            // Bad lines are left to the row policy when the records are read
+            let object = match object {
+                Err(error) if RejectedRow::from_error(&error).is_some() => continue,
+                object => object?,
+            };
+            for (key, _) in Self::flatten(&object) {
+                if seen.insert(key.clone()) {
+                    headers.push(key);
+                }
+            }
+        }
+
+        Ok(headers)
+    }
+
+    pub async fn get_records<R>(
+        reader: R,
+        sintaxis: &FileType,
+        headers: Vec<String>,
+    ) -> Result<BoxStream<'static, Result<Vec<String>, Error>>, Error>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        let objects = Self::get_objects(reader, sintaxis).await?;
+
+        Ok(objects
+            .map(move |object| object.map(|value| Self::to_record(&value, &headers)))
+            .boxed())
+    }
+
+    /// The reader must already yield UTF-8 text.
+    async fn get_objects<R>(
+        mut reader: R,
+        sintaxis: &FileType,
+    ) -> Result<BoxStream<'static, Result<Value, Error>>, Error>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        if *sintaxis == FileType::NDJSON {
+            let lines = BufReader::new(reader).lines();
+            let objects = stream::unfold((lines, 0), |(mut lines, mut number)| async move {
+                loop {
+                    number += 1;
+                    match lines.next_line().await {
+                        Ok(Some(line)) if line.trim().is_empty() => continue,
+                        Ok(Some(line)) => {
+                            let object = serde_json::from_str::<Value>(&line).map_err(|error| {
+                                RejectedRow::new(number, error.to_string(), line, vec![])
+                                    .into_error()
+                            });
+                            return Some((object, (lines, number)));
+                        }
+                        Ok(None) => return None,
+                        Err(e) => return Some((Err(e), (lines, number))),
+                    }
+                }
+            });
+            Ok(objects.boxed())
+        } else {
+            let mut content = String::new();
+            reader.read_to_string(&mut content).await?;
+            let objects = match serde_json::from_str::<Value>(&content)? {
+                Value::Array(items) => items,
+                item => vec![item],
+            };
+            Ok(stream::iter(objects.into_iter().map(Ok)).boxed())
+        }
+    }
+
+    fn to_record(value: &Value, headers: &[String]) -> Vec<String> {
+        let fields: HashMap<String, String> = Self::flatten(value).into_iter().collect();
+        headers
+            .iter()
+            .map(|header| fields.get(header).cloned().unwrap_or_default())
+            .collect()
+    }
+
+    /// Nested objects become dotted headers (`address.city`), arrays are kept as JSON text.
+    fn flatten(value: &Value) -> Vec<(String, String)> {
+        let mut fields = vec![];
+        match value {
+            Value::Object(_) => Self::flatten_into("", value, &mut fields),
+            _ => Self::flatten_into(JSON_VALUE_HEADER, value, &mut fields),
+        }
+        fields
+    }
+
+    fn flatten_into(prefix: &str, value: &Value, fields: &mut Vec<(String, String)>) {
+        match value {
+            Value::Object(map) => {
+                for (key, item) in map {
+                    let key = if prefix.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", prefix, key)
+                    };
+                    Self::flatten_into(&key, item, fields);
+                }
+            }
+            Value::Null => fields.push((prefix.to_string(), String::new())),
+            Value::String(text) => fields.push((prefix.to_string(), text.clone())),
+            item => fields.push((prefix.to_string(), item.to_string())),
+        }
+    }
+}
@@ -1,37 +1,95 @@
+pub mod column_cache;
 pub mod column_stored;
+pub mod compression;
+pub mod dataset;
+pub mod dialect;
+pub mod excel_reader;
+pub mod file_stamp;
 pub mod file_type;
+pub mod join;
+pub mod json_reader;
+pub mod parquet_reader;
+pub mod pivot;
+pub mod query;
+pub mod read_options;
+pub mod row_index;
+pub mod row_policy;
 pub mod row_stored;
+pub mod sampling;
+pub mod schema;
+pub mod search;
+pub mod sort;
+pub mod sqlite_reader;
 
 use crate::{
     ai::{
         dbscan::DensityBaseClustering, k_means::KMeansClustering, linear_regression::LnRegression,
         pca::PrincipalComponentsAnalisys,
     },
-    constants::path::CSV,
+    constants::{
+        english::{
+            DATASET_FORMAT_MISMATCH, DATASET_SOURCE_HEADER, ERROR_FILE_LOAD, ERROR_FILE_SINTAXIS,
+            ERROR_TASK_CANCELLED, GENERATED_HEADER_PREFIX,
+        },
+        path::{
+            COMPRESSED_FORMATS, CSV, DATABASE_FORMATS, JSON, JSONL, NDJSON, OLE_MAGIC, PARQUET,
+            PARQUET_MAGIC, SQLITE_MAGIC, WORKBOOK_FORMATS, ZIP_MAGIC,
+        },
+        sizes::{DIALECT_SNIFF_BYTES, SORT_RUN_MB, TABLE_WINDOW_ROWS},
+    },
     correlation_analysis::CorrelationAnalysis,
     dynamictable::{iced_column::IcedColumn, iced_row::IcedRow, simple_column::SimpleColumn},
+    encoding::{self, decoded_reader::DecodedReader},
     save_options::SaveOptions,
-    stadistics::Stadistics,
+    stadistics::{data_type::DataType, profile_accumulator::ProfileAccumulator, Stadistics},
+    task_progress::TaskProgress,
     util::print_timer,
 };
 use chardet::detect;
+use column_cache::{CachedTable, ColumnCache};
 use column_stored::ColumnStored;
-use csv_async::AsyncReaderBuilder;
+use compression::{Compression, FileReader};
+use csv_async::{AsyncReader, AsyncReaderBuilder};
+use dataset::Dataset;
+use dialect::Dialect;
+use encoding_rs::Encoding;
+use excel_reader::ExcelReader;
+use file_stamp::FileStamp;
 use file_type::FileType;
-use futures::stream::StreamExt;
+use futures::{
+    future,
+    stream::{self, BoxStream, StreamExt, TryStreamExt},
+};
+use join::JoinTable;
+use json_reader::JsonReader;
+use parquet_reader::ParquetReader;
+use pivot::{Pivot, PivotTable};
 use rayon::prelude::*;
+use read_options::ReadOptions;
+use row_index::RowIndex;
+use row_policy::{RejectedRow, RejectedRows};
 use row_stored::RowStored;
+use sampling::{sample_mode::SampleMode, Sampling};
+use schema::ColumnType;
+use search::{Search, SearchCursor};
 use serde_json::Value;
-use std::{fs::metadata, io::Cursor, path::Path, time::Instant};
+use sort::{
+    external_sort::{sort_records, SortedFile},
+    SortKey, Sorter,
+};
+use sqlite_reader::SqliteReader;
+use std::{fs::metadata, io::Cursor, path::Path, sync::Arc, time::Instant};
 use std::{
-    io::Error,
+    io::{Error, ErrorKind, SeekFrom},
     time::{SystemTime, UNIX_EPOCH},
 };
 use tokio::{
     fs::File,
-    io::{AsyncBufReadExt, AsyncReadExt, BufReader},
+    io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncSeekExt, BufReader},
 };
 
+pub type RecordStream = BoxStream<'static, Result<Vec<String>, Error>>;
+
 #[derive(Debug, Clone)]
 pub struct StoredFile {
     pub file_path: String,
@@ -40,8 +98,25 @@ pub struct StoredFile {
     pub size: f64,
     pub format: String,
     pub sintaxis: FileType,
+    pub compression: Compression,
+    pub dataset: Dataset,
+    pub sheets: Vec<String>,
+    pub read_options: ReadOptions,
     pub rows: RowStored,
+    /// Rows drawn by the sampling of the read options, empty when every row is shown.
+    pub sampled_rows: Arc<Vec<IcedRow>>,
+    /// The table in the order of the sort keys, `None` while it is not sorted.
+    pub sorted_file: Option<Arc<SortedFile>>,
     pub columns: ColumnStored,
+    pub cache: Arc<ColumnCache>,
+    pub row_index: Option<Arc<RowIndex>>,
+    pub join_table: Option<Arc<JoinTable>>,
+    pub rejected_rows: Arc<RejectedRows>,
+    pub file_stamp: FileStamp,
+    /// Why the columns of the file could not be read, it is not supported while it is set.
+    pub load_error: Option<String>,
+    /// The long task working on the file, shared by its clones.
+    pub task: TaskProgress,
     pub k_means: KMeansClustering,
     pub principal_components_analisys: PrincipalComponentsAnalisys,
     pub density_base_clustering: DensityBaseClustering,
@@ -57,8 +132,21 @@ impl StoredFile {
             size: 0.0,
             format: String::new(),
             sintaxis: FileType::Unknown,
+            compression: Compression::default(),
+            dataset: Dataset::default(),
+            sheets: vec![],
+            read_options: ReadOptions::default(),
             rows: RowStored::empty(),
+            sampled_rows: Arc::new(vec![]),
+            sorted_file: None,
             columns: ColumnStored::empty(),
+            cache: Arc::new(ColumnCache::default()),
+            row_index: None,
+            join_table: None,
+            rejected_rows: Arc::new(RejectedRows::default()),
+            file_stamp: FileStamp::default(),
+            load_error: None,
+            task: TaskProgress::default(),
             k_means: KMeansClustering::new(),
             principal_components_analisys: PrincipalComponentsAnalisys::new(),
             density_base_clustering: DensityBaseClustering::new(),
@@ -67,63 +155,251 @@ impl StoredFile {
     }
 
     pub async fn new(file_path: String) -> Self {
+        let mut read_options = ReadOptions::default();
+        let main_source = Dataset::get_main_source(&file_path);
+        let compression = Compression::detect(&main_source).await;
+        let text_encoding =
+            encoding::get_encoding(&Self::get_encoding(&main_source, &compression).await);
+        if Self::detect_file_type(&main_source, &compression, text_encoding).await == FileType::CSV
+        {
+            read_options =
+                Self::detect_csv_options(&main_source, &compression, text_encoding).await;
+        }
+        Self::new_with_options(file_path, read_options).await
+    }
+
+    pub async fn new_with_options(file_path: String, read_options: ReadOptions) -> Self {
+        Self::new_with_task(file_path, read_options, TaskProgress::default()).await
+    }
+
+    /// Opens a file, or a dataset when the path is a directory or a glob pattern. The format
+    /// of a dataset is the one of its first file. The rows are counted as part of the task.
+    pub async fn new_with_task(
+        file_path: String,
+        read_options: ReadOptions,
+        task: TaskProgress,
+    ) -> Self {
+        let dataset = match Dataset::is_dataset_path(&file_path) {
+            true => Dataset::new(file_path.clone()),
+            false => Dataset::default(),
+        };
+        let file_path = dataset.sources.first().cloned().unwrap_or(file_path);
+
         let format = Self::get_file_extension(&file_path);
-        let sintaxis = Self::detect_file_type(&file_path).await;
-        let k_means = KMeansClustering::new();
-        let pca = PrincipalComponentsAnalisys::new();
-        let db_scan = DensityBaseClustering::new();
-        let linear_regression_prediction = LnRegression::new();
-
-        if format != CSV || sintaxis != FileType::CSV {
-            Self {
-                file_path: file_path.clone(),
-                file_name: Self::get_file_name(&file_path),
-                encoding: Self::get_encoding(&file_path).await,
-                size: Self::get_size_kb(&file_path),
-                format,
-                rows: RowStored::empty(),
-                columns: ColumnStored::empty(),
-                sintaxis,
-                k_means,
-                principal_components_analisys: pca,
-                density_base_clustering: db_scan,
-                linear_regression_prediction,
-            }
+        let compression = Compression::detect(&file_path).await;
+        let encoding = Self::get_encoding(&file_path, &compression).await;
+        let text_encoding = if read_options.encoding.is_empty() {
+            encoding::get_encoding(&encoding)
         } else {
-            Self {
-                file_path: file_path.clone(),
-                file_name: Self::get_file_name(&file_path),
-                encoding: Self::get_encoding(&file_path).await,
-                size: Self::get_size_kb(&file_path),
-                format: format,
-                rows: Self::get_rows(&file_path).await.unwrap(),
-                columns: Self::get_columns(&file_path).await.unwrap(),
-                sintaxis: sintaxis,
-                k_means,
-                principal_components_analisys: pca,
-                density_base_clustering: db_scan,
-                linear_regression_prediction,
+            encoding::get_encoding(&read_options.encoding)
+        };
+        let sintaxis = Self::detect_file_type(&file_path, &compression, text_encoding).await;
+
+        let mut stored_file = Self {
+            file_path: file_path.clone(),
+            file_name: Self::get_file_name(&file_path),
+            encoding,
+            size: Self::get_size_kb(&file_path),
+            format,
+            rows: RowStored::empty(),
+            sampled_rows: Arc::new(vec![]),
+            sorted_file: None,
+            columns: ColumnStored::empty(),
+            cache: Arc::new(ColumnCache::default()),
+            row_index: None,
+            join_table: None,
+            rejected_rows: Arc::new(RejectedRows::default()),
+            file_stamp: FileStamp::default(),
+            load_error: None,
+            task,
+            sintaxis,
+            compression,
+            dataset,
+            sheets: vec![],
+            read_options,
+            k_means: KMeansClustering::new(),
+            principal_components_analisys: PrincipalComponentsAnalisys::new(),
+            density_base_clustering: DensityBaseClustering::new(),
+            linear_regression_prediction: LnRegression::new(),
+        };
+
+        if stored_file.is_dataset() {
+            stored_file.file_name = Self::get_file_name(&stored_file.dataset.path);
+            stored_file.size = stored_file.get_dataset_size_kb();
+        }
+
+        if matches!(stored_file.sintaxis, FileType::Excel | FileType::SQLite) {
+            stored_file.load_sheets().await;
+        }
+
+        if stored_file.is_supported() {
+            stored_file.check_sources().await;
+            // Malformed content or a query that does not run leaves the file unsupported
+            stored_file.columns = match stored_file.get_columns().await {
+                Ok(columns) => columns,
+                Err(error) => {
+                    stored_file.load_error = Some(error.to_string());
+                    return stored_file;
+                }
+            };
+            // A join that can not be built leaves the file as it is, the join is kept to retry
+            let _ = stored_file.load_join().await;
+            stored_file.apply_schema();
+            // With the fail policy the bad row is kept to be reported instead of the rows
+            if let Err(error) = stored_file
+                .get_rows()
+                .await
+                .map(|rows| stored_file.rows = rows)
+            {
+                let mut rejected_rows = RejectedRows::default();
+                rejected_rows.push(&error);
+                stored_file.rejected_rows = Arc::new(rejected_rows);
             }
+            stored_file.file_stamp = stored_file.get_file_stamp().await;
         }
+
+        stored_file
     }
 
     pub async fn reload(&mut self) -> Result<(), Error> {
-        self.rows = match StoredFile::get_rows(&self.file_path.to_string()).await {
+        // New files matching a dataset are picked up
+        if self.is_dataset() {
+            self.dataset = Dataset::new(self.dataset.path.clone());
+            if let Some(main_source) = self.dataset.sources.first() {
+                self.file_path = main_source.clone();
+            }
+            self.size = self.get_dataset_size_kb();
+        }
+        self.compression = Compression::detect(&self.file_path).await;
+        self.cache = Arc::new(ColumnCache::new(self.cache.budget));
+        self.sorted_file = None;
+        if matches!(self.sintaxis, FileType::Excel | FileType::SQLite) {
+            self.load_sheets().await;
+        }
+        self.check_sources().await;
+
+        self.columns = match self.get_columns().await {
             Ok(it) => it,
             Err(err) => return Err(err),
         };
+        self.load_error = None;
+        self.load_join().await?;
+        self.apply_schema();
 
-        self.columns = match StoredFile::get_columns(&self.file_path.to_string()).await {
+        self.rows = match self.get_rows().await {
             Ok(it) => it,
             Err(err) => return Err(err),
         };
 
+        self.file_stamp = self.get_file_stamp().await;
+
         self.k_means = KMeansClustering::new();
         self.principal_components_analisys = PrincipalComponentsAnalisys::new();
         self.density_base_clustering = DensityBaseClustering::new();
         Ok(())
     }
 
+    /// Only an indexed csv can have its appended rows added, any other change needs a reload.
+    pub fn can_tail(&self) -> bool {
+        self.row_index.is_some()
+    }
+
+    /// Adds the rows appended to the csv since it was read, keeping the columns and the rest
+    /// of the state. Returns how many rows were added.
+    pub async fn tail_rows(&mut self) -> Result<u64, Error> {
+        let mut row_index = match &self.row_index {
+            Some(row_index) => row_index.as_ref().clone(),
+            None => return Err(Error::from(ErrorKind::Unsupported)),
+        };
+        let (appended, tail_rejected_rows) = row_index
+            .extend(
+                &self.file_path,
+                &self.read_options,
+                self.get_file_columns().headers.len(),
+                self.get_text_encoding(),
+            )
+            .await?;
+
+        let mut rejected_rows = self.rejected_rows.as_ref().clone();
+        rejected_rows.append(tail_rejected_rows, "");
+        if rejected_rows.total == 0 {
+            let _ = row_index.save(&self.file_path, &self.read_options).await;
+        }
+        self.rejected_rows = Arc::new(rejected_rows);
+        self.row_index = Some(Arc::new(row_index));
+        self.rows.total += appended;
+        self.size = Self::get_size_kb(&self.file_path);
+        self.file_stamp = self.get_file_stamp().await;
+
+        if appended > 0 {
+            self.cache = Arc::new(ColumnCache::new(self.cache.budget));
+            let loaded = self.rows.sample.len();
+            if loaded < TABLE_WINDOW_ROWS {
+                let rows = self
+                    .get_rows_window(loaded as u64, TABLE_WINDOW_ROWS - loaded)
+                    .await?;
+                self.rows.sample.extend(rows);
+            }
+        }
+        Ok(appended)
+    }
+
+    /// What was read of the file, a csv is read as far as it was indexed.
+    async fn get_file_stamp(&self) -> FileStamp {
+        let size = match &self.row_index {
+            Some(row_index) => row_index.file_size,
+            None => metadata(&self.file_path).map_or(0, |m| m.len()),
+        };
+        FileStamp::new(&self.file_path, size).await
+    }
+
+    pub fn is_supported(&self) -> bool {
+        if self.load_error.is_some() {
+            return false;
+        }
+        match self.sintaxis {
+            FileType::CSV => self.format == CSV,
+            FileType::JSON | FileType::NDJSON => {
+                [JSON, NDJSON, JSONL].contains(&self.format.as_str())
+            }
+            FileType::Parquet => self.format == PARQUET && self.compression == Compression::None,
+            FileType::Excel => {
+                WORKBOOK_FORMATS.contains(&self.format.as_str())
+                    && !self.sheets.is_empty()
+                    && self.compression == Compression::None
+            }
+            FileType::SQLite => {
+                DATABASE_FORMATS.contains(&self.format.as_str())
+                    && (!self.sheets.is_empty() || !self.read_options.query.is_empty())
+                    && self.compression == Compression::None
+            }
+            FileType::Unknown => false,
+        }
+    }
+
+    /// Why the file is not supported, the error it was read with or its format.
+    pub fn get_unsupported_error(&self) -> String {
+        match &self.load_error {
+            Some(load_error) => ERROR_FILE_LOAD.replace("{}", load_error),
+            None => ERROR_FILE_SINTAXIS.replace("{}", self.sintaxis.to_string()),
+        }
+    }
+
+    /// Sheets of a workbook or tables of a database. Keeps the selected one when it still
+    /// exists, otherwise falls back to the first one.
+    pub async fn load_sheets(&mut self) {
+        let sheets = match self.sintaxis {
+            FileType::SQLite => SqliteReader::get_table_names(&self.file_path).await,
+            _ => ExcelReader::get_sheet_names(&self.file_path).await,
+        };
+        self.sheets = sheets.unwrap_or_default();
+
+        if !self.sheets.contains(&self.read_options.sheet) {
+            self.read_options.sheet = self.sheets.first().cloned().unwrap_or_default();
+            self.read_options.header_row = 0;
+        }
+    }
+
     pub fn get_simple_columns(&self) -> Vec<SimpleColumn> {
         if self.columns.total > 0 {
             let simple_column: Vec<SimpleColumn> = self
@@ -134,7 +410,10 @@ impl StoredFile {
                 .map(|(index, item)| SimpleColumn {
                     index: index,
                     header: item.column_header.clone(),
-                    classification: item.stadistics.classification.clone(),
+                    classification: match item.data_type {
+                        DataType::Unknown => item.stadistics.classification.clone(),
+                        _ => item.data_type.get_classification(),
+                    },
                     save_options: SaveOptions::default(),
                 })
                 .collect();
@@ -146,7 +425,7 @@ impl StoredFile {
     }
 
     fn get_size_kb(file_path: &str) -> f64 {
-        metadata(&file_path).map(|m| m.len()).unwrap() as f64 / 1024.0
+        metadata(file_path).map_or(0, |m| m.len()) as f64 / 1024.0
     }
 
     pub fn get_file_name(file_path: &str) -> String {
@@ -160,68 +439,454 @@ impl StoredFile {
         file_name.to_owned()
     }
 
+    /// Compressed files report the extension they had before compression, `data.csv.gz` is `CSV`.
     pub fn get_file_extension(file_path: &str) -> String {
         let path = Path::new(file_path);
         let mut file_extension = String::from("");
 
         if let Some(extension) = path.extension() {
-            file_extension = extension.to_string_lossy().into_owned()
+            file_extension = extension.to_string_lossy().to_uppercase()
         }
 
-        file_extension.to_uppercase().to_owned()
+        if COMPRESSED_FORMATS.contains(&file_extension.as_str()) {
+            if let Some(stem) = path.file_stem() {
+                return Self::get_file_extension(&stem.to_string_lossy());
+            }
+        }
+
+        file_extension.to_owned()
     }
 
-    pub async fn get_columns(file_path: &str) -> Result<ColumnStored, Error> {
-        let mut rdr = csv_async::AsyncReader::from_reader(File::open(file_path).await?);
-        let counter = rdr.headers().await?.into_iter().count() as u64;
-        let headers_vec: Vec<IcedColumn> = rdr
-            .headers()
-            .await
-            .unwrap()
-            .clone()
+    pub async fn get_columns(&self) -> Result<ColumnStored, Error> {
+        let mut headers_vec: Vec<IcedColumn> = match self.sintaxis {
+            FileType::JSON | FileType::NDJSON => {
+                JsonReader::get_headers(self.open_text().await?, &self.sintaxis)
+                    .await?
+                    .into_iter()
+                    .map(IcedColumn::new)
+                    .collect()
+            }
+            FileType::Parquet => ParquetReader::get_columns(&self.file_path).await?,
+            FileType::Excel => ExcelReader::get_headers(&self.file_path, &self.read_options)
+                .await?
+                .into_iter()
+                .map(IcedColumn::new)
+                .collect(),
+            FileType::SQLite => {
+                SqliteReader::get_columns(&self.file_path, &self.read_options).await?
+            }
+            _ => {
+                let mut rdr = self.get_csv_reader().await?;
+                let has_header = self.read_options.has_header;
+                rdr.headers()
+                    .await?
+                    .iter()
+                    .enumerate()
+                    .map(
+                        |(index, header)| match self.read_options.headers.get(index) {
+                            Some(name) if !name.is_empty() => name.clone(),
+                            _ if has_header => header.to_string(),
+                            _ => format!("{}{}", GENERATED_HEADER_PREFIX, index + 1),
+                        },
+                    )
+                    .map(IcedColumn::new)
+                    .collect()
+            }
+        };
+
+        if self.has_source_column() {
+            headers_vec.push(IcedColumn::new(DATASET_SOURCE_HEADER.to_string()));
+        }
+
+        Ok(ColumnStored::new(headers_vec.len() as u64, headers_vec))
+    }
+
+    pub fn is_dataset(&self) -> bool {
+        !self.dataset.is_empty()
+    }
+
+    /// The path the file was opened with, the directory or pattern of a dataset.
+    pub fn get_load_path(&self) -> &str {
+        match self.is_dataset() {
+            true => &self.dataset.path,
+            false => &self.file_path,
+        }
+    }
+
+    fn has_source_column(&self) -> bool {
+        self.is_dataset() && self.read_options.source_column
+    }
+
+    fn get_dataset_size_kb(&self) -> f64 {
+        self.dataset
+            .sources
             .iter()
-            .map(|s| IcedColumn::new(s.to_string()))
-            .collect();
-        Ok(ColumnStored::new(counter, headers_vec.clone()))
+            .map(|source| Self::get_size_kb(source))
+            .sum()
+    }
+
+    /// Columns read from the file itself, without the ones added by a dataset or a join.
+    fn get_file_columns(&self) -> ColumnStored {
+        let added = self.has_source_column() as usize
+            + self
+                .join_table
+                .as_ref()
+                .map_or(0, |join_table| join_table.headers.len());
+        let width = self.columns.headers.len().saturating_sub(added);
+        let headers: Vec<IcedColumn> = self.columns.headers[..width].to_vec();
+        ColumnStored::new(headers.len() as u64, headers)
     }
 
-    #[warn(unused_assignments)]
-    pub async fn get_rows(file_path: &str) -> Result<RowStored, Error> {
+    /// Columns a join can match on, the ones before the joined columns.
+    pub fn get_join_key_headers(&self) -> Vec<String> {
+        let joined = self
+            .join_table
+            .as_ref()
+            .map_or(0, |join_table| join_table.headers.len());
+        let width = self.columns.headers.len().saturating_sub(joined);
+        self.columns.headers[..width]
+            .iter()
+            .map(|column| column.column_header.clone())
+            .collect()
+    }
+
+    /// Builds the lookup of the joined file and adds its columns after the ones of the file.
+    async fn load_join(&mut self) -> Result<(), Error> {
+        self.join_table = None;
+        if self.read_options.join.is_empty() {
+            return Ok(());
+        }
+
+        let left_headers: Vec<String> = self
+            .columns
+            .headers
+            .iter()
+            .map(|column| column.column_header.clone())
+            .collect();
         let start = Instant::now();
+        let join_table = JoinTable::build(&self.read_options.join, &left_headers).await?;
+        print_timer("Join", start);
 
-        let file = match File::open(file_path).await {
-            Ok(it) => it,
-            Err(err) => return Err(err),
+        self.columns.headers.extend(
+            join_table
+                .headers
+                .iter()
+                .map(|header| IcedColumn::new(header.clone())),
+        );
+        self.columns.total = self.columns.headers.len() as u64;
+        self.join_table = Some(Arc::new(join_table));
+        Ok(())
+    }
+
+    /// Gives the columns of the schema the type chosen by the user.
+    fn apply_schema(&mut self) {
+        for column in self.columns.headers.iter_mut() {
+            if let Some(column_type) = self
+                .read_options
+                .schema
+                .iter()
+                .find(|column_type| column_type.header == column.column_header)
+            {
+                column.data_type = column_type.data_type.clone();
+            }
+        }
+    }
+
+    /// The type chosen by the user for a column, `None` when it keeps the inferred one.
+    pub fn get_column_type(&self, column_index: usize) -> Option<&ColumnType> {
+        let header = &self.columns.headers.get(column_index)?.column_header;
+        self.read_options
+            .schema
+            .iter()
+            .find(|column_type| &column_type.header == header)
+    }
+
+    /// The type of every column as declared by the file, or guessed from the loaded rows.
+    pub fn get_inferred_types(&self) -> Vec<DataType> {
+        self.columns
+            .headers
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                if column.data_type != DataType::Unknown && self.get_column_type(index).is_none() {
+                    return column.data_type.clone();
+                }
+                let values: Vec<String> = self
+                    .rows
+                    .sample
+                    .iter()
+                    .filter_map(|row| row.values.get(index))
+                    .filter(|value| !value.is_empty())
+                    .cloned()
+                    .collect();
+                Stadistics::get_column_analysis(&values).1
+            })
+            .collect()
+    }
+
+    /// One file of a dataset, read with the options of the dataset and without its
+    /// source column.
+    async fn get_source(&self, file_path: &str) -> Self {
+        Self {
+            file_path: file_path.to_string(),
+            file_name: Self::get_file_name(file_path),
+            encoding: self.encoding.clone(),
+            format: Self::get_file_extension(file_path),
+            sintaxis: self.sintaxis.clone(),
+            compression: Compression::detect(file_path).await,
+            sheets: self.sheets.clone(),
+            read_options: self.read_options.clone(),
+            columns: self.get_file_columns(),
+            task: self.task.clone(),
+            ..Self::default()
+        }
+    }
+
+    /// Leaves out the files of a dataset whose format or columns differ from the first one,
+    /// and keeps the reason to report it.
+    async fn check_sources(&mut self) {
+        if !self.is_dataset() {
+            return;
+        }
+
+        let get_headers = |columns: ColumnStored| -> Vec<String> {
+            columns
+                .headers
+                .into_iter()
+                .map(|column| column.column_header)
+                .collect()
         };
-        let file_count = File::open(file_path).await.unwrap();
+        let main_source = self.get_source(&self.file_path).await;
+        let expected = main_source
+            .get_columns()
+            .await
+            .map(get_headers)
+            .unwrap_or_default();
+
+        let mut sources = vec![];
+        let mut mismatches = vec![];
+        for source_path in &self.dataset.sources {
+            let source = self.get_source(source_path).await;
+            let mismatch = if source.format != self.format {
+                Some(
+                    DATASET_FORMAT_MISMATCH
+                        .replacen("{}", &source.format, 1)
+                        .replacen("{}", &self.format, 1),
+                )
+            } else {
+                match source.get_columns().await {
+                    Ok(columns) => Dataset::compare_headers(&expected, &get_headers(columns)),
+                    Err(error) => Some(error.to_string()),
+                }
+            };
+
+            match mismatch {
+                Some(reason) => mismatches.push(format!("{}: {}", source.file_name, reason)),
+                None => sources.push(source_path.clone()),
+            }
+        }
+
+        self.dataset.sources = sources;
+        self.dataset.mismatches = mismatches;
+    }
+
+    /// Streams every data record of the file as plain values, whatever its sintaxis, with
+    /// the bad records handled by the row policy. Serves the records from the column cache
+    /// when it is already built.
+    pub async fn get_records(&self) -> Result<RecordStream, Error> {
+        if let Some(table) = self.cache.get_loaded() {
+            return Ok(
+                stream::iter((0..table.rows).map(move |row| Ok(table.get_record(row)))).boxed(),
+            );
+        }
 
-        let mut rdr = csv_async::AsyncReader::from_reader(file);
-        let mut rdr_count = csv_async::AsyncReader::from_reader(file_count);
+        self.read_table_records().await
+    }
+
+    /// Records of the whole table, with the columns of the joined file.
+    async fn read_table_records(&self) -> Result<RecordStream, Error> {
+        let records = self.read_dataset_records().await?;
+        Ok(match &self.join_table {
+            Some(join_table) => join_table.clone().join_records(records),
+            None => records,
+        })
+    }
+
+    /// Records with the row policy applied, the files of a dataset one after the other.
+    async fn read_dataset_records(&self) -> Result<RecordStream, Error> {
+        if !self.is_dataset() {
+            let records = self.read_records().await?;
+            return Ok(self.apply_row_policy(records));
+        }
+
+        let main_source = self.get_source(&self.file_path).await;
+        let source_column = self.has_source_column();
+        let records = stream::iter(self.dataset.sources.clone())
+            .then(move |source_path| {
+                let main_source = main_source.clone();
+                async move {
+                    let source = main_source.get_source(&source_path).await;
+                    let file_name = source.file_name.clone();
+                    let records = match source.read_records().await {
+                        Ok(records) => source.apply_row_policy(records),
+                        Err(error) => stream::once(future::ready(Err(error))).boxed(),
+                    };
+                    records.map(move |record| match record {
+                        Ok(mut values) => {
+                            if source_column {
+                                values.push(file_name.clone());
+                            }
+                            Ok(values)
+                        }
+                        Err(error) => Err(RejectedRow::tag_error(error, &file_name)),
+                    })
+                }
+            })
+            .flatten();
+        Ok(records.boxed())
+    }
+
+    fn apply_row_policy(&self, records: RecordStream) -> RecordStream {
+        let row_policy = self.read_options.row_policy.clone();
+        let width = self.get_file_columns().headers.len();
+        records
+            .filter_map(move |record| future::ready(row_policy.apply(record, width)))
+            .boxed()
+    }
+
+    /// Records of a csv, the ones without a value per column are returned as rejected rows.
+    fn to_csv_records<R>(&self, rdr: AsyncReader<R>) -> RecordStream
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        let width = self.get_file_columns().headers.len();
+        let delimiter = self.read_options.dialect.delimiter;
+
+        rdr.into_records()
+            .map(move |record| {
+                let line = match &record {
+                    Ok(record) => record.position(),
+                    Err(error) => error.position(),
+                }
+                .map_or(0, |position| position.line());
+
+                match record {
+                    Ok(record) => {
+                        let values: Vec<String> = record.iter().map(String::from).collect();
+                        if width == 0 || values.len() == width {
+                            Ok(values)
+                        } else {
+                            Err(RejectedRow::from_values(line, values, width, delimiter)
+                                .into_error())
+                        }
+                    }
+                    Err(error) => {
+                        Err(RejectedRow::new(line, error.to_string(), "".into(), vec![])
+                            .into_error())
+                    }
+                }
+            })
+            .boxed()
+    }
+
+    async fn read_records(&self) -> Result<RecordStream, Error> {
+        match self.sintaxis {
+            FileType::JSON | FileType::NDJSON => {
+                let headers = self
+                    .get_file_columns()
+                    .headers
+                    .into_iter()
+                    .map(|column| column.column_header)
+                    .collect();
+                JsonReader::get_records(self.open_text().await?, &self.sintaxis, headers).await
+            }
+            FileType::Parquet => ParquetReader::get_records(&self.file_path).await,
+            FileType::Excel => ExcelReader::get_records(&self.file_path, &self.read_options).await,
+            FileType::SQLite => {
+                SqliteReader::get_records(&self.file_path, &self.read_options).await
+            }
+            _ => Ok(self.to_csv_records(self.get_csv_reader().await?)),
+        }
+    }
+
+    /// Opens the file as UTF-8 text, decompressed and transcoded.
+    async fn open_text(&self) -> Result<DecodedReader<FileReader>, Error> {
+        let file = self.compression.open(&self.file_path).await?;
+        Ok(DecodedReader::new(file, self.get_text_encoding()))
+    }
+
+    async fn get_csv_reader(&self) -> Result<AsyncReader<DecodedReader<FileReader>>, Error> {
+        let file = self.open_text().await?;
+        Ok(self
+            .read_options
+            .dialect
+            .get_reader_builder()
+            .has_headers(self.read_options.has_header)
+            .flexible(true)
+            .create_reader(file))
+    }
+
+    /// Format shown in the detail panel, with the compression when there is one.
+    pub fn get_format_label(&self) -> String {
+        match self.compression {
+            Compression::None => self.format.clone(),
+            _ => format!("{} ({})", self.format, self.compression.to_string()),
+        }
+    }
+
+    /// The encoding chosen by the user, or the detected one.
+    pub fn get_encoding_label(&self) -> &str {
+        if self.read_options.encoding.is_empty() {
+            &self.encoding
+        } else {
+            &self.read_options.encoding
+        }
+    }
+
+    pub fn get_text_encoding(&self) -> &'static Encoding {
+        encoding::get_encoding(self.get_encoding_label())
+    }
+
+    /// Reads the first rows and counts the rest, the counting pass of a csv also builds its
+    /// row index.
+    pub async fn get_rows(&mut self) -> Result<RowStored, Error> {
+        let start = Instant::now();
+
+        let mut records = self.get_records().await?;
+        let stored_file = self.clone();
 
         let handle_count = tokio::spawn(async move {
-            let count = rdr_count.records().count().await;
-            count as u64
+            match stored_file.get_row_index().await {
+                Some(Ok((row_index, rejected_rows))) => {
+                    Ok((row_index.total, Some(Arc::new(row_index)), rejected_rows))
+                }
+                Some(Err(error)) => Err(error),
+                None => stored_file
+                    .get_row_count()
+                    .await
+                    .map(|(total, rejected_rows)| (total, None, rejected_rows)),
+            }
         });
 
         let handle_records = tokio::spawn(async move {
             let mut records_vec = Vec::new();
             let mut row_index = 0;
-            let mut records = rdr.records();
 
             while let Some(record) = records.next().await {
-                if row_index >= 50 {
+                if row_index >= TABLE_WINDOW_ROWS {
                     break;
                 }
-                let record = record.unwrap();
-                let values: Vec<String> = record.iter().map(|s| s.to_string()).collect();
+                let values = record?;
                 records_vec.push(IcedRow::new(values, row_index));
                 row_index += 1;
             }
-            records_vec
+            Ok::<_, Error>(records_vec)
         });
 
-        let counter = handle_count.await.unwrap();
-        let records_vec = handle_records.await.unwrap();
+        let (counter, row_index, rejected_rows) = handle_count.await.unwrap()?;
+        let records_vec = handle_records.await.unwrap()?;
+        self.row_index = row_index;
+        self.rejected_rows = Arc::new(rejected_rows);
 
         let message = format!("Loading Rows {:?}", counter);
         crate::util::print_timer(&message, start);
@@ -229,14 +894,302 @@ impl StoredFile {
         Ok(RowStored::new(counter, records_vec))
     }
 
+    /// Counts the records kept by the row policy and collects the rejected ones, file by
+    /// file for a dataset.
+    pub async fn get_row_count(&self) -> Result<(u64, RejectedRows), Error> {
+        let (total, rejected_rows) = match self.is_dataset() {
+            true => self.count_dataset_rows().await?,
+            false => self.count_rows().await?,
+        };
+        if self.join_table.is_none() {
+            return Ok((total, rejected_rows));
+        }
+
+        // A join changes the number of rows, so the joined records are counted as well
+        let mut records = self.task.track(self.read_table_records().await?, 0);
+        let mut total = 0;
+        while let Some(record) = records.next().await {
+            match record {
+                Ok(_) => total += 1,
+                Err(error) if error.kind() == ErrorKind::Interrupted => return Err(error),
+                Err(_) => {}
+            }
+        }
+        Ok((total, rejected_rows))
+    }
+
+    async fn count_dataset_rows(&self) -> Result<(u64, RejectedRows), Error> {
+        let mut total = 0;
+        let mut rejected_rows = RejectedRows::default();
+        for source_path in &self.dataset.sources {
+            let source = self.get_source(source_path).await;
+            let (count, source_rejected_rows) = source
+                .count_rows()
+                .await
+                .map_err(|error| RejectedRow::tag_error(error, &source.file_name))?;
+            total += count;
+            rejected_rows.append(source_rejected_rows, &source.file_name);
+        }
+
+        Ok((total, rejected_rows))
+    }
+
+    async fn count_rows(&self) -> Result<(u64, RejectedRows), Error> {
+        let mut rejected_rows = RejectedRows::default();
+        if self.sintaxis == FileType::Parquet {
+            let total = ParquetReader::get_row_count(&self.file_path).await?;
+            return Ok((total, rejected_rows));
+        }
+        if self.sintaxis == FileType::SQLite {
+            let total = SqliteReader::get_row_count(&self.file_path, &self.read_options).await?;
+            return Ok((total, rejected_rows));
+        }
+
+        let row_policy = &self.read_options.row_policy;
+        let width = self.get_file_columns().headers.len();
+        let mut records = self.task.track(self.read_records().await?, 0);
+        let mut total = 0;
+
+        while let Some(record) = records.next().await {
+            if let Err(error) = &record {
+                self.task.check()?;
+                rejected_rows.push(error);
+            }
+            match row_policy.apply(record, width) {
+                Some(Ok(_)) => total += 1,
+                Some(Err(error)) => return Err(error),
+                None => {}
+            }
+        }
+
+        Ok((total, rejected_rows))
+    }
+
+    /// The row index of a csv, with the rows rejected while building it.
+    async fn get_row_index(&self) -> Option<Result<(RowIndex, RejectedRows), Error>> {
+        let is_indexable = self.sintaxis == FileType::CSV
+            && !self.is_dataset()
+            && self.join_table.is_none()
+            && self.compression == Compression::None
+            && RowIndex::is_indexable(self.get_text_encoding());
+        if !is_indexable {
+            return None;
+        }
+
+        let start = Instant::now();
+        let row_index = RowIndex::load_or_build(
+            &self.file_path,
+            &self.read_options,
+            self.get_file_columns().headers.len(),
+            self.get_text_encoding(),
+            &self.task,
+        )
+        .await;
+        print_timer("Row index", start);
+
+        // A failing row or a cancelled task is reported, other errors fall back to streaming
+        // the file
+        match row_index {
+            Err(error)
+                if error.kind() != ErrorKind::Interrupted
+                    && RejectedRow::from_error(&error).is_none() =>
+            {
+                None
+            }
+            row_index => Some(row_index),
+        }
+    }
+
+    /// Records starting at the given row, seeking through the row index when there is one.
+    pub async fn get_records_from(&self, start: u64) -> Result<RecordStream, Error> {
+        let offset = match &self.row_index {
+            Some(row_index) if self.cache.get_loaded().is_none() => row_index.get_offset(start),
+            _ => None,
+        };
+        let Some((offset, skip)) = offset else {
+            return Ok(self.get_records().await?.skip(start as usize).boxed());
+        };
+
+        let mut file = File::open(&self.file_path).await?;
+        file.seek(SeekFrom::Start(offset)).await?;
+
+        let mut builder = self.read_options.dialect.get_reader_builder();
+        builder.has_headers(false).flexible(true);
+        let rdr = builder.create_reader(DecodedReader::new(file, self.get_text_encoding()));
+        let records = self.apply_row_policy(self.to_csv_records(rdr));
+        Ok(records.skip(skip).boxed())
+    }
+
+    pub async fn get_rows_window(&self, start: u64, count: usize) -> Result<Vec<IcedRow>, Error> {
+        let timer = Instant::now();
+        if let Some(sorted_file) = self.get_sorted_file() {
+            let records = sorted_file.get_records(start, count)?;
+            return Ok(records
+                .into_iter()
+                .enumerate()
+                .map(|(index, values)| IcedRow::new(values, start as usize + index))
+                .collect());
+        }
+
+        let records = self.get_records_from(start).await?;
+        // Rows appended since the file was counted, or a line still being written, wait
+        // for the next time the file is read
+        let count = count.min(self.rows.total.saturating_sub(start) as usize);
+
+        let handle_records = tokio::spawn(async move {
+            let mut records_vec = Vec::with_capacity(count);
+            let mut records = records.take(count);
+            let mut row_index = start as usize;
+
+            while let Some(record) = records.next().await {
+                let values = record?;
+                records_vec.push(IcedRow::new(values, row_index));
+                row_index += 1;
+            }
+            Ok::<_, Error>(records_vec)
+        });
+
+        let records_vec = handle_records.await.unwrap()?;
+
+        let message = format!("Loading Rows Window {:?}", start);
+        crate::util::print_timer(&message, timer);
+
+        Ok(records_vec)
+    }
+
+    /// Draws the sample chosen in the read options, the rows keep their number in the file.
+    pub async fn get_sample(&self) -> Result<Vec<IcedRow>, Error> {
+        let sampling = &self.read_options.sampling;
+        let total = self.rows.total;
+        let start = Instant::now();
+        let sample = match sampling.mode {
+            SampleMode::All => Ok(vec![]),
+            SampleMode::Head => sampling.take(self.get_records_from(0).await?, 0).await,
+            SampleMode::Tail => {
+                let start = total.saturating_sub(sampling.size as u64);
+                sampling
+                    .take(self.get_records_from(start).await?, start)
+                    .await
+            }
+            SampleMode::EveryNth => {
+                let records = self.task.track(self.get_records().await?, total);
+                sampling.every_nth(records).await
+            }
+            SampleMode::Random => {
+                let records = self.task.track(self.get_records().await?, total);
+                sampling.reservoir(records).await
+            }
+            SampleMode::Stratified => {
+                let column_index = self
+                    .columns
+                    .headers
+                    .iter()
+                    .position(|column| column.column_header == sampling.column)
+                    .ok_or(Error::new(ErrorKind::NotFound, sampling.column.as_str()))?;
+                let records = self.task.track(self.get_records().await?, total);
+                let strata = Sampling::count_strata(records, column_index).await?;
+                let records = self.task.track(self.get_records().await?, total);
+                sampling.stratified(records, column_index, strata).await
+            }
+        };
+        print_timer("Sample", start);
+        sample
+    }
+
+    /// The sorted table when it follows the sort keys of the read options.
+    pub fn get_sorted_file(&self) -> Option<&Arc<SortedFile>> {
+        self.sorted_file
+            .as_ref()
+            .filter(|sorted_file| sorted_file.keys == self.read_options.sort)
+            .filter(|_| !self.read_options.sort.is_empty())
+    }
+
+    pub fn get_sorter(&self, keys: &[SortKey]) -> Result<Sorter, Error> {
+        let headers: Vec<String> = self
+            .columns
+            .headers
+            .iter()
+            .map(|column| column.column_header.clone())
+            .collect();
+        let column_types: Vec<Option<ColumnType>> = (0..headers.len())
+            .map(|index| self.get_column_type(index).cloned())
+            .collect();
+        Sorter::new(keys, &headers, &self.get_inferred_types(), &column_types)
+    }
+
+    /// Sorts every record by the sort keys, in runs spilled to disk when they do not fit in
+    /// memory.
+    async fn sort_records(&self) -> Result<sort::external_sort::SortedRecords, Error> {
+        let sorter = self.get_sorter(&self.read_options.sort)?;
+        let records = self.task.track(self.get_records().await?, self.rows.total);
+        sort_records(records, sorter, SORT_RUN_MB * 1024 * 1024).await
+    }
+
+    /// Writes the table in the order of the sort keys, for the table to page over it.
+    pub async fn sort(&self) -> Result<Arc<SortedFile>, Error> {
+        let start = Instant::now();
+        let sorted_records = self.sort_records().await?;
+        let keys = self.read_options.sort.clone();
+        let sorted_file =
+            tokio::task::spawn_blocking(move || SortedFile::write(sorted_records, keys)).await??;
+        print_timer("Sort", start);
+        Ok(Arc::new(sorted_file))
+    }
+
+    /// The records in the order of the sort keys, from the sorted table when there is one.
+    pub async fn get_sorted_records(&self) -> Result<RecordStream, Error> {
+        match self.get_sorted_file() {
+            Some(sorted_file) => sorted_file.clone().get_all_records(),
+            None => Ok(self.sort_records().await?.into_stream()),
+        }
+    }
+
+    /// Groups every record of the file by the pivot, the task follows how far it got.
+    pub async fn get_pivot(&self, pivot: &Pivot) -> Result<PivotTable, Error> {
+        let start = Instant::now();
+        let headers: Vec<String> = self
+            .columns
+            .headers
+            .iter()
+            .map(|column| column.column_header.clone())
+            .collect();
+        let column_types: Vec<Option<ColumnType>> = (0..headers.len())
+            .map(|index| self.get_column_type(index).cloned())
+            .collect();
+        let records = self.task.track(self.get_records().await?, self.rows.total);
+        let pivot_table = PivotTable::build(
+            pivot,
+            records,
+            &headers,
+            &self.get_inferred_types(),
+            &column_types,
+        )
+        .await?;
+        print_timer("Pivot", start);
+        Ok(pivot_table)
+    }
+
+    /// Starts a search over every record of the file, the task follows how far it got.
+    pub async fn get_search_cursor(&self, search: Search) -> Result<SearchCursor, Error> {
+        let matcher = search.get_matcher()?;
+        let records = self.task.track(self.get_records().await?, self.rows.total);
+        Ok(SearchCursor::new(search, matcher, records))
+    }
+
     pub fn size_mb_as_str(&self) -> String {
         format!("{:.2} MB", self.size / 1024.0)
     }
 
-    async fn get_encoding(file_path: &str) -> String {
-        let mut file = File::open(file_path).await.unwrap();
-        let mut buffer = vec![0; 4096];
-        file.read(&mut buffer).await.unwrap();
+    async fn read_head(file_path: &str, compression: &Compression, length: usize) -> Vec<u8> {
+        let mut buffer = vec![];
+        if let Ok(file) = compression.open(file_path).await {
+            let _ = file.take(length as u64).read_to_end(&mut buffer).await;
+        }
+        buffer
+    }
+
+    async fn get_encoding(file_path: &str, compression: &Compression) -> String {
+        let buffer = Self::read_head(file_path, compression, 4096).await;
 
         let result = detect(&buffer);
         let encoding = result.0;
@@ -244,23 +1197,65 @@ impl StoredFile {
         encoding.to_uppercase()
     }
 
-    async fn detect_file_type(file_path: &str) -> FileType {
-        let file = File::open(file_path).await.unwrap();
-        let mut buf_reader = BufReader::new(file);
+    /// Sniffs the dialect and whether there is a header from the first bytes of a csv.
+    async fn detect_csv_options(
+        file_path: &str,
+        compression: &Compression,
+        text_encoding: &'static Encoding,
+    ) -> ReadOptions {
+        let sample = Self::read_head(file_path, compression, DIALECT_SNIFF_BYTES).await;
+        let (sample, _) = text_encoding.decode_with_bom_removal(&sample);
+
+        let mut read_options = ReadOptions::default();
+        read_options.dialect = Dialect::sniff(sample.as_bytes());
+        read_options.has_header = read_options.dialect.sniff_header(sample.as_bytes());
+        read_options
+    }
+
+    async fn detect_file_type(
+        file_path: &str,
+        compression: &Compression,
+        text_encoding: &'static Encoding,
+    ) -> FileType {
+        let magic = Self::read_head(file_path, compression, SQLITE_MAGIC.len()).await;
+
+        if magic.starts_with(PARQUET_MAGIC) {
+            return FileType::Parquet;
+        }
+        if magic.starts_with(SQLITE_MAGIC) {
+            return FileType::SQLite;
+        }
+        if magic.starts_with(ZIP_MAGIC) || magic.starts_with(OLE_MAGIC) {
+            return FileType::Excel;
+        }
+
+        let Ok(file) = compression.open(file_path).await else {
+            return FileType::Unknown;
+        };
+        let mut buf_reader = BufReader::new(DecodedReader::new(file, text_encoding));
 
         let mut buffer = String::new();
         let mut total_bytes_read = 0;
 
         while total_bytes_read < 8192 {
-            let bytes_read = buf_reader.read_line(&mut buffer).await.unwrap();
-            if bytes_read == 0 {
-                break;
-            }
+            let bytes_read = match buf_reader.read_line(&mut buffer).await {
+                Ok(0) | Err(_) => break,
+                Ok(bytes_read) => bytes_read,
+            };
             total_bytes_read += bytes_read;
         }
 
-        if serde_json::from_str::<Value>(&buffer).is_ok() {
-            return FileType::JSON;
+        let content = buffer.trim_start();
+        if content.starts_with('{') || content.starts_with('[') {
+            let first_line = content.lines().next().unwrap_or_default();
+            let is_ndjson = serde_json::from_str::<Value>(&buffer).is_err()
+                && serde_json::from_str::<Value>(first_line).is_ok_and(|value| value.is_object());
+
+            return if is_ndjson {
+                FileType::NDJSON
+            } else {
+                FileType::JSON
+            };
         }
 
         let cursor = Cursor::new(buffer);
@@ -272,37 +1267,148 @@ impl StoredFile {
         FileType::Unknown
     }
 
-    pub async fn get_full_column(&self, column_index: &usize) -> Vec<String> {
+    pub fn set_cache_budget(&mut self, budget: usize) {
+        if self.cache.budget != budget {
+            self.cache = Arc::new(ColumnCache::new(budget));
+        }
+    }
+
+    /// Builds the column cache if it is enabled, returns the table when it fits the budget.
+    pub async fn get_cached_table(&self) -> Option<Arc<CachedTable>> {
+        if !self.cache.is_enabled() {
+            return None;
+        }
+
+        let start = Instant::now();
+        let table = self
+            .cache
+            .get_or_build(|| async {
+                let records = self.read_table_records().await?;
+                Ok(self.task.track(records, self.rows.total))
+            })
+            .await;
+        print_timer("Column cache", start);
+        table
+    }
+
+    pub async fn get_full_column(&self, column_index: &usize) -> Result<Vec<String>, Error> {
+        if let Some(column) = self
+            .get_cached_table()
+            .await
+            .as_ref()
+            .and_then(|table| table.columns.get(*column_index))
+        {
+            return Ok(column.values.clone());
+        }
+
         let start = Instant::now();
-        let file = File::open(&self.file_path).await.unwrap();
-        let mut rdr = csv_async::AsyncReader::from_reader(file);
+        let records = self.task.track(self.get_records().await?, self.rows.total);
         let index: usize = *column_index;
 
+        // A record that can not be read stops the column, a cancelled task too
         let handle_records = tokio::spawn(async move {
-            let records_vec: Vec<String> = rdr
-                .records()
-                .filter_map(|result| async {
-                    match result {
-                        Ok(record) => record.get(index).map(|value| value.to_string()),
-                        Err(_) => None,
-                    }
+            let records_vec: Result<Vec<String>, Error> = records
+                .try_filter_map(|mut record| async move {
+                    Ok((index < record.len()).then(|| record.swap_remove(index)))
                 })
-                .collect()
+                .try_collect()
                 .await;
             records_vec
         });
 
-        let records_vec = handle_records.await.unwrap();
+        let records_vec = handle_records.await??;
         print_timer("Get full column", start);
-        records_vec
+        Ok(records_vec)
     }
 
-    pub async fn get_stadistics(&self, column_index: &usize) -> Stadistics {
-        Stadistics::new(
+    pub async fn get_stadistics(&self, column_index: &usize) -> Result<Stadistics, Error> {
+        Ok(Stadistics::new(
             self.columns.headers.get(column_index.clone()).unwrap(),
-            self.get_full_column(column_index).await,
+            self.get_typed_column(column_index).await?,
         )
-        .await
+        .await)
+    }
+
+    /// Statistics of every column from a single read of the file. The values of the column
+    /// cache are borrowed and analysed in parallel; without the cache the records are read
+    /// once into accumulators that keep a bounded part of every column.
+    pub async fn get_profile(&self) -> Result<Vec<Stadistics>, Error> {
+        let start = Instant::now();
+        let profile = match self.get_cached_table().await {
+            Some(table) => {
+                let empty = vec![];
+                self.columns
+                    .headers
+                    .par_iter()
+                    .enumerate()
+                    .map(|(index, header)| {
+                        let values = table
+                            .columns
+                            .get(index)
+                            .map_or(&empty, |column| &column.values);
+                        match self.get_column_type(index) {
+                            Some(column_type) => {
+                                let values: Vec<String> = values
+                                    .iter()
+                                    .map(|value| column_type.normalize(value))
+                                    .collect();
+                                Stadistics::from_column(header, &values)
+                            }
+                            None => Stadistics::from_column(header, values),
+                        }
+                    })
+                    .collect()
+            }
+            None => self
+                .columns
+                .headers
+                .par_iter()
+                .zip(self.accumulate_columns().await?.into_par_iter())
+                .map(|(header, accumulator)| accumulator.into_stadistics(header))
+                .collect(),
+        };
+        print_timer("Profile", start);
+        Ok(profile)
+    }
+
+    /// Every column of the file from one read of its records, in the accumulators of a
+    /// profile.
+    async fn accumulate_columns(&self) -> Result<Vec<ProfileAccumulator>, Error> {
+        let column_types: Vec<Option<ColumnType>> = (0..self.columns.headers.len())
+            .map(|index| self.get_column_type(index).cloned())
+            .collect();
+        let mut records = self.task.track(self.get_records().await?, self.rows.total);
+
+        let handle_records = tokio::spawn(async move {
+            let mut accumulators: Vec<ProfileAccumulator> =
+                vec![ProfileAccumulator::default(); column_types.len()];
+            while let Some(record) = records.next().await {
+                for ((accumulator, column_type), value) in accumulators
+                    .iter_mut()
+                    .zip(column_types.iter())
+                    .zip(record?)
+                {
+                    match column_type {
+                        Some(column_type) => accumulator.add(&column_type.normalize(&value)),
+                        None => accumulator.add(&value),
+                    }
+                }
+            }
+            Ok::<_, Error>(accumulators)
+        });
+        handle_records.await?
+    }
+
+    /// The values of a column parsed with the rules of its type in the schema.
+    async fn get_typed_column(&self, column_index: &usize) -> Result<Vec<String>, Error> {
+        let values = self.get_full_column(column_index).await?;
+        Ok(match self.get_column_type(*column_index) {
+            Some(column_type) => values
+                .par_iter()
+                .map(|value| column_type.normalize(value))
+                .collect(),
+            None => values,
+        })
     }
 
     pub async fn get_correlation(
@@ -310,21 +1416,52 @@ impl StoredFile {
         column_base: &SimpleColumn,
         column_compare: &SimpleColumn,
     ) -> Result<CorrelationAnalysis, &'static str> {
-        let base = Self::convert_to_f64(&self.get_full_column(&column_base.index).await);
-        let compare = Self::convert_to_f64(&self.get_full_column(&column_compare.index).await);
+        let (base, compare) = self
+            .convert_columns_f64(column_base, column_compare)
+            .await?;
         Ok(CorrelationAnalysis::new(&base, &compare).await)
     }
 
+    async fn get_full_column_f64(&self, column_index: &usize) -> Result<Vec<f64>, Error> {
+        if self.get_column_type(*column_index).is_some() {
+            return Ok(Self::convert_to_f64(
+                &self.get_typed_column(column_index).await?,
+            ));
+        }
+
+        if let Some(column) = self
+            .get_cached_table()
+            .await
+            .as_ref()
+            .and_then(|table| table.columns.get(*column_index))
+        {
+            return Ok(match &column.numbers {
+                Some(numbers) => numbers.clone(),
+                None => Self::convert_to_f64(&column.values),
+            });
+        }
+
+        Ok(Self::convert_to_f64(
+            &self.get_full_column(column_index).await?,
+        ))
+    }
+
     fn convert_to_f64(vec: &Vec<String>) -> Vec<f64> {
         vec.par_iter()
             .map(|s| s.parse::<f64>().unwrap_or(0.0))
             .collect()
     }
 
+    /// The export of a dataset directory is written next to it, so it is not read back as
+    /// one more file of the dataset.
     pub fn get_export_path(&self) -> String {
-        let path = Path::new(&self.file_path);
+        let dataset_path = Path::new(&self.dataset.path);
+        let path = match self.is_dataset() && dataset_path.is_dir() {
+            true => dataset_path,
+            false => Path::new(&self.file_path),
+        };
         let stem = path.file_stem().unwrap_or_default();
-        let extension = path.extension().unwrap_or_default();
+        let extension = Path::new(&self.file_path).extension().unwrap_or_default();
         let ticks = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
@@ -347,7 +1484,7 @@ impl StoredFile {
         iteraciones: &u64,
     ) -> Result<String, &'static str> {
         let start = Instant::now();
-        let (base, compare) = self.convert_columns_f64(column_base, column_compare).await;
+        let (base, compare) = self.get_model_columns(column_base, column_compare).await?;
         match self
             .k_means
             .get_prediction(base, compare, *clusters, *iteraciones)
@@ -371,7 +1508,7 @@ impl StoredFile {
         column_compare: &SimpleColumn,
         embedding_size: usize,
     ) -> Result<String, &'static str> {
-        let (base, compare) = self.convert_columns_f64(column_base, column_compare).await;
+        let (base, compare) = self.get_model_columns(column_base, column_compare).await?;
         let start = Instant::now();
         match self
             .principal_components_analisys
@@ -391,14 +1528,56 @@ impl StoredFile {
         }
     }
 
+    /// Columns a model is fed with, only the sampled rows when the sampling is meant for the
+    /// models.
+    async fn get_model_columns(
+        &self,
+        column_base: &SimpleColumn,
+        column_compare: &SimpleColumn,
+    ) -> Result<(Vec<f64>, Vec<f64>), &'static str> {
+        let sampling = &self.read_options.sampling;
+        if !sampling.for_models || !sampling.is_enabled() {
+            return self.convert_columns_f64(column_base, column_compare).await;
+        }
+
+        let get_values = |column_index: usize| -> Vec<String> {
+            self.sampled_rows
+                .iter()
+                .map(|row| {
+                    let value = row.values.get(column_index).cloned().unwrap_or_default();
+                    match self.get_column_type(column_index) {
+                        Some(column_type) => column_type.normalize(&value),
+                        None => value,
+                    }
+                })
+                .collect()
+        };
+        Ok((
+            Self::convert_to_f64(&get_values(column_base.index)),
+            Self::convert_to_f64(&get_values(column_compare.index)),
+        ))
+    }
+
+    /// Both columns as numbers, unless the task was cancelled while they were read.
     async fn convert_columns_f64(
         &self,
         column_base: &SimpleColumn,
         column_compare: &SimpleColumn,
-    ) -> (Vec<f64>, Vec<f64>) {
-        let base = Self::convert_to_f64(&self.get_full_column(&column_base.index).await);
-        let compare = Self::convert_to_f64(&self.get_full_column(&column_compare.index).await);
-        (base, compare)
+    ) -> Result<(Vec<f64>, Vec<f64>), &'static str> {
+        let read_columns = async {
+            Ok::<_, Error>((
+                self.get_full_column_f64(&column_base.index).await?,
+                self.get_full_column_f64(&column_compare.index).await?,
+            ))
+        };
+        match (read_columns.await, self.task.is_cancelled()) {
+            (_, true) => Err(ERROR_TASK_CANCELLED),
+            (Ok(columns), false) => Ok(columns),
+            (Err(e), false) => {
+                let error_msg: &'static str = Box::leak(Box::new(e.to_string()));
+                Err(error_msg)
+            }
+        }
     }
 
     pub async fn get_dbscan_analysis(
@@ -408,7 +1587,7 @@ impl StoredFile {
         tolerance: f64,
         min_points: usize,
     ) -> Result<String, &'static str> {
-        let (base, compare) = self.convert_columns_f64(column_base, column_compare).await;
+        let (base, compare) = self.get_model_columns(column_base, column_compare).await?;
         let start = Instant::now();
         match self
             .density_base_clustering
@@ -440,7 +1619,7 @@ impl StoredFile {
         column_base: &SimpleColumn,
         column_compare: &SimpleColumn,
     ) -> Result<String, &'static str> {
-        let (base, compare) = self.convert_columns_f64(column_base, column_compare).await;
+        let (base, compare) = self.get_model_columns(column_base, column_compare).await?;
         let start = Instant::now();
         match self
             .linear_regression_prediction
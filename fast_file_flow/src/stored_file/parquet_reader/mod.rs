@@ -0,0 +1,98 @@
+use crate::{dynamictable::iced_column::IcedColumn, stadistics::data_type::DataType};
+use arrow_array::RecordBatch;
+use arrow_cast::display::{ArrayFormatter, FormatOptions};
+use arrow_schema::{ArrowError, DataType as ArrowType};
+use futures::stream::{self, BoxStream, StreamExt};
+use parquet::arrow::ParquetRecordBatchStreamBuilder;
+use std::io::Error;
+use tokio::fs::File;
+
+pub struct ParquetReader {}
+
+impl ParquetReader {
+    pub async fn get_columns(file_path: &str) -> Result<Vec<IcedColumn>, Error> {
+        let builder = ParquetRecordBatchStreamBuilder::new(File::open(file_path).await?).await?;
+
+        let columns = builder
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| {
+                let mut column = IcedColumn::new(field.name().to_string());
+                column.data_type = Self::to_data_type(field.data_type());
+                column
+            })
+            .collect();
+
+        Ok(columns)
+    }
+
+    /// Parquet footers already hold the row count, so the file is not scanned.
+    pub async fn get_row_count(file_path: &str) -> Result<u64, Error> {
+        let builder = ParquetRecordBatchStreamBuilder::new(File::open(file_path).await?).await?;
+        Ok(builder.metadata().file_metadata().num_rows() as u64)
+    }
+
+    pub async fn get_records(
+        file_path: &str,
+    ) -> Result<BoxStream<'static, Result<Vec<String>, Error>>, Error> {
+        let builder = ParquetRecordBatchStreamBuilder::new(File::open(file_path).await?).await?;
+        let batches = builder.build()?;
+
+        Ok(batches
+            .flat_map(|batch| {
+                let records = match batch {
+                    Ok(batch) => Self::to_records(&batch),
+                    Err(e) => vec![Err(Error::from(e))],
+                };
+                stream::iter(records)
+            })
+            .boxed())
+    }
+
+    fn to_records(batch: &RecordBatch) -> Vec<Result<Vec<String>, Error>> {
+        let options = FormatOptions::default();
+        let formatters: Result<Vec<ArrayFormatter>, ArrowError> = batch
+            .columns()
+            .iter()
+            .map(|column| ArrayFormatter::try_new(column.as_ref(), &options))
+            .collect();
+
+        match formatters {
+            Ok(formatters) => (0..batch.num_rows())
+                .map(|row| {
+                    Ok(formatters
+                        .iter()
+                        .map(|formatter| formatter.value(row).to_string())
+                        .collect())
+                })
+                .collect(),
+            Err(e) => vec![Err(Error::other(e))],
+        }
+    }
+
+    fn to_data_type(data_type: &ArrowType) -> DataType {
+        match data_type {
+            ArrowType::Int8
+            | ArrowType::Int16
+            | ArrowType::Int32
+            | ArrowType::Int64
+            | ArrowType::UInt8
+            | ArrowType::UInt16
+            | ArrowType::UInt32
+            | ArrowType::UInt64 => DataType::Integer,
+            ArrowType::Float16
+            | ArrowType::Float32
+            | ArrowType::Float64
+            | ArrowType::Decimal128(_, _)
+            | ArrowType::Decimal256(_, _) => DataType::Float,
+            ArrowType::Date32 | ArrowType::Date64 => DataType::Date,
+            ArrowType::Time32(_) | ArrowType::Time64(_) => DataType::Time,
+            ArrowType::Timestamp(_, _) => DataType::DateTime,
+            ArrowType::Boolean | ArrowType::Utf8 | ArrowType::LargeUtf8 | ArrowType::Utf8View => {
+                DataType::Text
+            }
+            _ => DataType::Unknown,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::constants::english::{
+    AGGREGATION_COUNT, AGGREGATION_DISTINCT_COUNT, AGGREGATION_MAX, AGGREGATION_MEAN,
+    AGGREGATION_MEDIAN, AGGREGATION_MIN, AGGREGATION_SUM,
+};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum Aggregation {
+    /// Values that are not empty, of any column.
+    Count,
+    Sum,
+    Mean,
+    Min,
+    Max,
+    /// Different values that are not empty, of any column.
+    DistinctCount,
+    Median,
+}
+
+impl Aggregation {
+    pub fn default() -> Self {
+        Aggregation::Sum
+    }
+
+    pub fn to_string(&self) -> &str {
+        match self {
+            Aggregation::Count => AGGREGATION_COUNT,
+            Aggregation::Sum => AGGREGATION_SUM,
+            Aggregation::Mean => AGGREGATION_MEAN,
+            Aggregation::Min => AGGREGATION_MIN,
+            Aggregation::Max => AGGREGATION_MAX,
+            Aggregation::DistinctCount => AGGREGATION_DISTINCT_COUNT,
+            Aggregation::Median => AGGREGATION_MEDIAN,
+        }
+    }
+
+    pub fn from_string(unwrap: &str) -> Aggregation {
+        match unwrap {
+            AGGREGATION_COUNT => Aggregation::Count,
+            AGGREGATION_MEAN => Aggregation::Mean,
+            AGGREGATION_MIN => Aggregation::Min,
+            AGGREGATION_MAX => Aggregation::Max,
+            AGGREGATION_DISTINCT_COUNT => Aggregation::DistinctCount,
+            AGGREGATION_MEDIAN => Aggregation::Median,
+            _ => Aggregation::Sum,
+        }
+    }
+
+    pub fn all() -> Vec<String> {
+        [
+            Aggregation::Count,
+            Aggregation::Sum,
+            Aggregation::Mean,
+            Aggregation::Min,
+            Aggregation::Max,
+            Aggregation::DistinctCount,
+            Aggregation::Median,
+        ]
+        .iter()
+        .map(|aggregation| aggregation.to_string().to_string())
+        .collect()
+    }
+
+    /// Only counts can be taken of the values of a qualitative column.
+    pub fn is_numeric(&self) -> bool {
+        !matches!(self, Aggregation::Count | Aggregation::DistinctCount)
+    }
+}
@@ -0,0 +1,59 @@
+use crate::constants::english::{
+    DATE_GROUP_DAY, DATE_GROUP_MONTH, DATE_GROUP_VALUE, DATE_GROUP_YEAR,
+};
+use chrono::NaiveDateTime;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum DateGroup {
+    /// Every value is a group of its own.
+    Value,
+    Year,
+    Month,
+    Day,
+}
+
+impl DateGroup {
+    pub fn default() -> Self {
+        DateGroup::Value
+    }
+
+    pub fn to_string(&self) -> &str {
+        match self {
+            DateGroup::Value => DATE_GROUP_VALUE,
+            DateGroup::Year => DATE_GROUP_YEAR,
+            DateGroup::Month => DATE_GROUP_MONTH,
+            DateGroup::Day => DATE_GROUP_DAY,
+        }
+    }
+
+    pub fn from_string(unwrap: &str) -> DateGroup {
+        match unwrap {
+            DATE_GROUP_YEAR => DateGroup::Year,
+            DATE_GROUP_MONTH => DateGroup::Month,
+            DATE_GROUP_DAY => DateGroup::Day,
+            _ => DateGroup::Value,
+        }
+    }
+
+    pub fn all() -> Vec<String> {
+        [
+            DateGroup::Value,
+            DateGroup::Year,
+            DateGroup::Month,
+            DateGroup::Day,
+        ]
+        .iter()
+        .map(|date_group| date_group.to_string().to_string())
+        .collect()
+    }
+
+    /// The group of a date, written so the groups sort in order as text.
+    pub fn get_group(&self, date_time: &NaiveDateTime) -> Option<String> {
+        match self {
+            DateGroup::Value => None,
+            DateGroup::Year => Some(date_time.format("%Y").to_string()),
+            DateGroup::Month => Some(date_time.format("%Y-%m").to_string()),
+            DateGroup::Day => Some(date_time.format("%Y-%m-%d").to_string()),
+        }
+    }
+}
@@ -0,0 +1,442 @@
+pub mod aggregation;
+pub mod date_group;
+
+use crate::{
+    ai::shared::Shared,
+    constants::{
+        english::{
+            ERROR_PIVOT_COLUMNS, PIVOT_COLUMN_HEADER, PIVOT_EMPTY_VALUE, PIVOT_VALUE_HEADER,
+        },
+        sizes::{IMAGE_HEIGHT, IMAGE_WIDTH, PIVOT_CHART_BARS_MAX, PIVOT_COLUMNS_MAX},
+    },
+    stadistics::data_type::DataType,
+};
+use aggregation::Aggregation;
+use date_group::DateGroup;
+use futures::stream::{self, StreamExt};
+use plotters::prelude::*;
+use std::{
+    collections::{HashMap, HashSet},
+    io::{Error, ErrorKind},
+    sync::Arc,
+};
+
+use super::{
+    schema::ColumnType,
+    sort::{parse_date_time, SortKey, Sorter},
+    RecordStream,
+};
+
+/// A column summarized in every group, and how.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct PivotValue {
+    pub header: String,
+    pub aggregation: Aggregation,
+}
+
+impl PivotValue {
+    pub fn new(header: String, aggregation: Aggregation) -> Self {
+        Self {
+            header,
+            aggregation,
+        }
+    }
+}
+
+impl std::fmt::Display for PivotValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(
+            &PIVOT_VALUE_HEADER
+                .replacen("{}", self.aggregation.to_string(), 1)
+                .replacen("{}", &self.header, 1),
+        )
+    }
+}
+
+/// Rows grouped by the values of some columns, with the values of a pivot column spread
+/// as columns of their own.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Pivot {
+    pub group_by: Vec<String>,
+    /// The values of this column become columns, nothing is spread when it is empty.
+    pub pivot_column: String,
+    pub values: Vec<PivotValue>,
+    /// Dates of the group and pivot columns are grouped by their year, month or day.
+    pub date_group: DateGroup,
+}
+
+impl Pivot {
+    pub fn default() -> Self {
+        Self {
+            group_by: vec![],
+            pivot_column: String::new(),
+            values: vec![],
+            date_group: DateGroup::default(),
+        }
+    }
+
+    pub fn new(
+        group_by: Vec<String>,
+        pivot_column: String,
+        values: Vec<PivotValue>,
+        date_group: DateGroup,
+    ) -> Self {
+        Self {
+            group_by,
+            pivot_column,
+            values,
+            date_group,
+        }
+    }
+}
+
+/// The columns of the file a pivot reads, by their position in the records.
+struct PivotColumn {
+    index: usize,
+    data_type: DataType,
+    column_type: Option<ColumnType>,
+}
+
+impl PivotColumn {
+    fn new(
+        header: &str,
+        headers: &[String],
+        types: &[DataType],
+        column_types: &[Option<ColumnType>],
+    ) -> Result<Self, Error> {
+        let index = headers
+            .iter()
+            .position(|name| name == header)
+            .ok_or(Error::new(ErrorKind::NotFound, header))?;
+        Ok(Self {
+            index,
+            data_type: types.get(index).cloned().unwrap_or(DataType::Text),
+            column_type: column_types.get(index).cloned().flatten(),
+        })
+    }
+
+    fn get_value(&self, record: &[String]) -> String {
+        let value = record.get(self.index).map_or("", |value| value.as_str());
+        match &self.column_type {
+            Some(column_type) => column_type.normalize(value),
+            None => value.trim().to_string(),
+        }
+    }
+
+    /// The value a row is grouped by, the group of its date when dates are grouped.
+    fn get_group(&self, record: &[String], date_group: &DateGroup) -> String {
+        let value = self.get_value(record);
+        parse_date_time(&value, &self.data_type)
+            .and_then(|date_time| date_group.get_group(&date_time))
+            .unwrap_or(value)
+    }
+}
+
+/// What an aggregation keeps of the values of a group while the rows are read.
+#[derive(Debug, Clone)]
+enum Accumulator {
+    Count(u64),
+    Sum(f64, u64),
+    Mean(f64, u64),
+    Min(Option<f64>),
+    Max(Option<f64>),
+    DistinctCount(HashSet<String>),
+    Median(Vec<f64>),
+}
+
+impl Accumulator {
+    fn new(aggregation: &Aggregation) -> Self {
+        match aggregation {
+            Aggregation::Count => Accumulator::Count(0),
+            Aggregation::Sum => Accumulator::Sum(0.0, 0),
+            Aggregation::Mean => Accumulator::Mean(0.0, 0),
+            Aggregation::Min => Accumulator::Min(None),
+            Aggregation::Max => Accumulator::Max(None),
+            Aggregation::DistinctCount => Accumulator::DistinctCount(HashSet::new()),
+            Aggregation::Median => Accumulator::Median(vec![]),
+        }
+    }
+
+    /// Empty values are left out, and so are values that are not numbers from the
+    /// aggregations of numbers.
+    fn add(&mut self, value: String) {
+        if value.is_empty() {
+            return;
+        }
+        match self {
+            Accumulator::Count(count) => *count += 1,
+            Accumulator::DistinctCount(values) => {
+                values.insert(value);
+            }
+            _ => {
+                let Ok(number) = value.parse::<f64>() else {
+                    return;
+                };
+                match self {
+                    Accumulator::Sum(sum, count) | Accumulator::Mean(sum, count) => {
+                        *sum += number;
+                        *count += 1;
+                    }
+                    Accumulator::Min(min) => *min = Some(min.map_or(number, |m| m.min(number))),
+                    Accumulator::Max(max) => *max = Some(max.map_or(number, |m| m.max(number))),
+                    Accumulator::Median(numbers) => numbers.push(number),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// The aggregated value, empty when no number was added.
+    fn get_value(&mut self) -> String {
+        let number = match self {
+            Accumulator::Count(count) => Some(*count as f64),
+            Accumulator::DistinctCount(values) => Some(values.len() as f64),
+            Accumulator::Sum(sum, count) => (*count > 0).then_some(*sum),
+            Accumulator::Mean(sum, count) => (*count > 0).then(|| *sum / *count as f64),
+            Accumulator::Min(number) | Accumulator::Max(number) => *number,
+            Accumulator::Median(numbers) => {
+                numbers.sort_by(|a, b| a.total_cmp(b));
+                let middle = numbers.len() / 2;
+                match numbers.len() {
+                    0 => None,
+                    len if len % 2 == 0 => Some((numbers[middle - 1] + numbers[middle]) / 2.0),
+                    _ => Some(numbers[middle]),
+                }
+            }
+        };
+        number.map(format_number).unwrap_or_default()
+    }
+}
+
+/// The result of a pivot, a table of its own that can be shown, charted and exported.
+#[derive(Debug, Clone)]
+pub struct PivotTable {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+    /// The first columns hold the groups, the rest the aggregated values.
+    pub group_columns: usize,
+}
+
+impl PivotTable {
+    /// Reads the records once, keeping what every aggregation needs of every group.
+    /// `types` are the types of every column and `column_types` the parse rules chosen in
+    /// the schema, in the order of the headers.
+    pub async fn build(
+        pivot: &Pivot,
+        mut records: RecordStream,
+        headers: &[String],
+        types: &[DataType],
+        column_types: &[Option<ColumnType>],
+    ) -> Result<Self, Error> {
+        let get_column = |header: &str| PivotColumn::new(header, headers, types, column_types);
+        let group_by = pivot
+            .group_by
+            .iter()
+            .map(|header| get_column(header))
+            .collect::<Result<Vec<_>, Error>>()?;
+        let pivot_column = match pivot.pivot_column.is_empty() {
+            true => None,
+            false => Some(get_column(&pivot.pivot_column)?),
+        };
+        let values = pivot
+            .values
+            .iter()
+            .map(|value| get_column(&value.header))
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let mut groups: HashMap<Vec<String>, HashMap<String, Vec<Accumulator>>> = HashMap::new();
+        let mut pivot_values: HashSet<String> = HashSet::new();
+        while let Some(record) = records.next().await {
+            let record = record?;
+            let key: Vec<String> = group_by
+                .iter()
+                .map(|column| column.get_group(&record, &pivot.date_group))
+                .collect();
+            let pivot_value = pivot_column
+                .as_ref()
+                .map(|column| column.get_group(&record, &pivot.date_group))
+                .unwrap_or_default();
+            if pivot_values.insert(pivot_value.clone()) && pivot_values.len() > PIVOT_COLUMNS_MAX {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    ERROR_PIVOT_COLUMNS.replace("{}", &PIVOT_COLUMNS_MAX.to_string()),
+                ));
+            }
+
+            let accumulators = groups
+                .entry(key)
+                .or_default()
+                .entry(pivot_value)
+                .or_insert_with(|| {
+                    pivot
+                        .values
+                        .iter()
+                        .map(|value| Accumulator::new(&value.aggregation))
+                        .collect()
+                });
+            for (accumulator, column) in accumulators.iter_mut().zip(values.iter()) {
+                accumulator.add(column.get_value(&record));
+            }
+        }
+
+        let group_types: Vec<DataType> = group_by
+            .iter()
+            .map(|column| match pivot.date_group {
+                DateGroup::Value => column.data_type.clone(),
+                _ => DataType::Text,
+            })
+            .collect();
+        let mut keys: Vec<Vec<String>> = groups.keys().cloned().collect();
+        sort_values(&mut keys, &group_types)?;
+        let mut pivot_values: Vec<Vec<String>> =
+            pivot_values.into_iter().map(|value| vec![value]).collect();
+        sort_values(
+            &mut pivot_values,
+            &pivot_column
+                .iter()
+                .map(|column| column.data_type.clone())
+                .collect::<Vec<_>>(),
+        )?;
+
+        let mut table_headers = pivot.group_by.clone();
+        for pivot_value in pivot_values.iter() {
+            for value in pivot.values.iter() {
+                table_headers.push(match (&pivot_column, pivot_value[0].as_str()) {
+                    (None, _) => value.to_string(),
+                    (Some(_), "") => PIVOT_COLUMN_HEADER
+                        .replacen("{}", PIVOT_EMPTY_VALUE, 1)
+                        .replacen("{}", &value.to_string(), 1),
+                    (Some(_), pivot_value) => PIVOT_COLUMN_HEADER
+                        .replacen("{}", pivot_value, 1)
+                        .replacen("{}", &value.to_string(), 1),
+                });
+            }
+        }
+
+        let rows = keys
+            .into_iter()
+            .map(|key| {
+                let mut cells = groups.remove(&key).unwrap_or_default();
+                let mut row = key;
+                for pivot_value in pivot_values.iter() {
+                    // A group without rows of this pivot value counts 0 and has no other value
+                    let mut accumulators = cells.remove(&pivot_value[0]).unwrap_or_else(|| {
+                        pivot
+                            .values
+                            .iter()
+                            .map(|value| Accumulator::new(&value.aggregation))
+                            .collect()
+                    });
+                    row.extend(accumulators.iter_mut().map(Accumulator::get_value));
+                }
+                row
+            })
+            .collect();
+
+        Ok(Self {
+            headers: table_headers,
+            rows,
+            group_columns: pivot.group_by.len(),
+        })
+    }
+
+    pub fn get_records(self: Arc<Self>) -> RecordStream {
+        let rows = (0..self.rows.len()).map(move |index| Ok(self.rows[index].clone()));
+        stream::iter(rows).boxed()
+    }
+
+    /// Bars of an aggregated column for the first groups, saved as an image at `path`.
+    pub fn draw_chart(&self, column: usize, path: &str) -> Result<(), Error> {
+        Shared::create_output_folder();
+        let bars: Vec<(String, f64)> = self
+            .rows
+            .iter()
+            .take(PIVOT_CHART_BARS_MAX)
+            .map(|row| {
+                let label = row[..self.group_columns.min(row.len())].join(" / ");
+                let value = row
+                    .get(column)
+                    .and_then(|value| value.parse::<f64>().ok())
+                    .unwrap_or_default();
+                (label, value)
+            })
+            .collect();
+
+        let min = bars.iter().map(|(_, value)| *value).fold(0.0, f64::min);
+        let max = bars.iter().map(|(_, value)| *value).fold(0.0, f64::max);
+        let max = match min == max {
+            true => min + 1.0,
+            false => max + (max - min) * 0.05,
+        };
+
+        let root = BitMapBackend::new(path, (IMAGE_WIDTH, IMAGE_HEIGHT)).into_drawing_area();
+        root.fill(&WHITE).map_err(to_error)?;
+
+        let caption = self.headers.get(column).cloned().unwrap_or_default();
+        let mut chart = ChartBuilder::on(&root)
+            .caption(caption, ("sans-serif", 30).into_font())
+            .margin(10)
+            .x_label_area_size(60)
+            .y_label_area_size(70)
+            .build_cartesian_2d((0..bars.len()).into_segmented(), min..max)
+            .map_err(to_error)?;
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .x_labels(bars.len())
+            .x_label_formatter(&|x| match x {
+                SegmentValue::CenterOf(index) => bars
+                    .get(*index)
+                    .map(|(label, _)| label.clone())
+                    .unwrap_or_default(),
+                _ => String::new(),
+            })
+            .x_desc(self.headers[..self.group_columns].join(" / "))
+            .draw()
+            .map_err(to_error)?;
+
+        chart
+            .draw_series(
+                Histogram::vertical(&chart)
+                    .style(BLUE.filled())
+                    .margin(5)
+                    .data(
+                        bars.iter()
+                            .enumerate()
+                            .map(|(index, (_, value))| (index, *value)),
+                    ),
+            )
+            .map_err(to_error)?;
+
+        root.present().map_err(to_error)
+    }
+}
+
+/// Orders the values the way the table is sorted, numbers and dates by their value.
+fn sort_values(values: &mut [Vec<String>], types: &[DataType]) -> Result<(), Error> {
+    let headers: Vec<String> = (0..types.len()).map(|index| index.to_string()).collect();
+    let keys: Vec<SortKey> = headers
+        .iter()
+        .map(|header| SortKey::new(header.clone(), true))
+        .collect();
+    let sorter = Sorter::new(&keys, &headers, types, &vec![None; types.len()])?;
+    values.sort_by_cached_key(|value| sorter.get_key(value));
+    Ok(())
+}
+
+/// Whole numbers without decimals, the rest rounded to four.
+fn format_number(number: f64) -> String {
+    if number.fract() == 0.0 && number.abs() < 1e15 {
+        return (number as i64).to_string();
+    }
+    let rounded = format!("{:.4}", number);
+    rounded
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+fn to_error<E: std::error::Error + Send + Sync + 'static>(error: E) -> Error {
+    Error::other(error.to_string())
+}
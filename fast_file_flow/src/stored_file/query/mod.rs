@@ -0,0 +1,345 @@
+use crate::{
+    dynamictable::{iced_column::IcedColumn, iced_row::IcedRow},
+    export::sqlite_writer::{quote, SqliteWriter},
+    stadistics::data_type::DataType,
+};
+use futures::stream::{self, StreamExt};
+use rusqlite::{params_from_iter, Connection};
+use std::{
+    collections::HashSet,
+    io::{Error, ErrorKind},
+    path::Path,
+    sync::{Arc, Mutex},
+};
+use tokio::sync::mpsc::{self, Receiver, Sender};
+
+use super::{sqlite_reader::SqliteReader, RecordStream, StoredFile};
+
+/// Rows read ahead, in both directions, between the files and the database.
+const CHANNEL_ROWS: usize = 1024;
+
+/// A file loaded into the database, with the names it is queried by.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct QueryTable {
+    pub name: String,
+    pub file_path: String,
+    pub headers: Vec<String>,
+}
+
+/// The loaded file and the other opened ones as tables of an in memory sqlite database,
+/// to run SELECT queries over them. It is loaded once and queried until a file changes.
+#[derive(Debug)]
+pub struct QueryDatabase {
+    connection: Arc<Mutex<Connection>>,
+    pub tables: Vec<QueryTable>,
+}
+
+impl QueryDatabase {
+    /// The loaded file is read as the table shows it, with its joined columns and its schema.
+    /// The other files are read with the options found when opening them.
+    pub async fn load(stored_file: &StoredFile, file_paths: &[String]) -> Result<Self, Error> {
+        let connection = Connection::open_in_memory().map_err(SqliteReader::to_error)?;
+        let mut database = Self {
+            connection: Arc::new(Mutex::new(connection)),
+            tables: vec![],
+        };
+
+        let records = stored_file
+            .task
+            .track(stored_file.get_records().await?, stored_file.rows.total);
+        database
+            .load_table(stored_file, records, stored_file.get_inferred_types())
+            .await?;
+
+        for file_path in file_paths {
+            let other_file = Box::pin(StoredFile::new(file_path.clone())).await;
+            if !other_file.is_supported() {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    other_file.get_unsupported_error(),
+                ));
+            }
+            let records = other_file.get_records().await?;
+            database
+                .load_table(&other_file, records, other_file.get_inferred_types())
+                .await?;
+        }
+        Ok(database)
+    }
+
+    /// Inserts the records in one transaction on a blocking thread, fed through a channel
+    /// while they are read.
+    async fn load_table(
+        &mut self,
+        stored_file: &StoredFile,
+        mut records: RecordStream,
+        data_types: Vec<DataType>,
+    ) -> Result<(), Error> {
+        let table = QueryTable {
+            name: self.get_table_name(stored_file.get_load_path()),
+            file_path: stored_file.get_load_path().to_string(),
+            headers: get_column_names(stored_file),
+        };
+
+        let (sender, receiver) = mpsc::channel(CHANNEL_ROWS);
+        let connection = self.connection.clone();
+        let insert_table = table.clone();
+        let handle = tokio::task::spawn_blocking(move || {
+            Self::insert_records(&connection, &insert_table, &data_types, receiver)
+        });
+
+        while let Some(record) = records.next().await {
+            let is_error = record.is_err();
+            // The insert stopped with an error, returned once the thread is joined
+            if sender.send(record).await.is_err() || is_error {
+                break;
+            }
+        }
+        drop(sender);
+
+        handle.await??;
+        self.tables.push(table);
+        Ok(())
+    }
+
+    fn insert_records(
+        connection: &Mutex<Connection>,
+        table: &QueryTable,
+        data_types: &[DataType],
+        mut receiver: Receiver<Result<Vec<String>, Error>>,
+    ) -> Result<(), Error> {
+        let mut connection = connection.lock().map_err(|e| Error::other(e.to_string()))?;
+        let transaction = connection.transaction().map_err(SqliteReader::to_error)?;
+
+        let columns: Vec<String> = table
+            .headers
+            .iter()
+            .enumerate()
+            .map(|(index, header)| {
+                let data_type = data_types.get(index).unwrap_or(&DataType::Text);
+                format!("{} {}", quote(header), SqliteWriter::to_sql_type(data_type))
+            })
+            .collect();
+        transaction
+            .execute(
+                &format!(
+                    "CREATE TABLE {} ({})",
+                    quote(&table.name),
+                    columns.join(", ")
+                ),
+                [],
+            )
+            .map_err(SqliteReader::to_error)?;
+
+        {
+            let insert = format!(
+                "INSERT INTO {} VALUES ({})",
+                quote(&table.name),
+                vec!["?"; table.headers.len()].join(", ")
+            );
+            let mut statement = transaction
+                .prepare(&insert)
+                .map_err(SqliteReader::to_error)?;
+            while let Some(record) = receiver.blocking_recv() {
+                let record = record?;
+                // Short rows are padded and long ones cut, as the table shows them
+                let values = (0..table.headers.len()).map(|index| {
+                    let value = record.get(index).map_or("", |value| value.as_str());
+                    SqliteWriter::to_value(data_types.get(index).unwrap_or(&DataType::Text), value)
+                });
+                statement
+                    .execute(params_from_iter(values))
+                    .map_err(SqliteReader::to_error)?;
+            }
+        }
+
+        transaction.commit().map_err(SqliteReader::to_error)
+    }
+
+    /// A name already taken by another file gets a number.
+    fn get_table_name(&self, file_path: &str) -> String {
+        let name = get_file_table_name(file_path);
+
+        let mut table_name = name.clone();
+        let mut number = 2;
+        while self
+            .tables
+            .iter()
+            .any(|table| table.name.eq_ignore_ascii_case(&table_name))
+        {
+            table_name = format!("{}_{}", name, number);
+            number += 1;
+        }
+        table_name
+    }
+
+    /// Columns of the query, typed from the columns of the tables they come from.
+    pub async fn get_columns(self: &Arc<Self>, sql: &str) -> Result<Vec<IcedColumn>, Error> {
+        let database = self.clone();
+        let sql = sql.to_string();
+        tokio::task::spawn_blocking(move || {
+            let connection = database
+                .connection
+                .lock()
+                .map_err(|e| Error::other(e.to_string()))?;
+            let statement = SqliteReader::prepare(&connection, &sql)?;
+            Ok(statement
+                .columns()
+                .iter()
+                .map(|column| {
+                    let mut iced_column = IcedColumn::new(column.name().to_string());
+                    iced_column.data_type = SqliteReader::to_data_type(column.decl_type());
+                    iced_column
+                })
+                .collect())
+        })
+        .await?
+    }
+
+    /// The rows of the query, read while the stream is consumed.
+    pub fn get_records(self: Arc<Self>, sql: &str) -> RecordStream {
+        let sql = sql.to_string();
+        let (sender, receiver) = mpsc::channel(CHANNEL_ROWS);
+
+        tokio::task::spawn_blocking(move || {
+            if let Err(error) = self.send_records(&sql, &sender) {
+                let _ = sender.blocking_send(Err(error));
+            }
+        });
+
+        stream::unfold(receiver, |mut receiver| async move {
+            receiver.recv().await.map(|record| (record, receiver))
+        })
+        .boxed()
+    }
+
+    fn send_records(
+        &self,
+        sql: &str,
+        sender: &Sender<Result<Vec<String>, Error>>,
+    ) -> Result<(), Error> {
+        let connection = self
+            .connection
+            .lock()
+            .map_err(|e| Error::other(e.to_string()))?;
+        let mut statement = SqliteReader::prepare(&connection, sql)?;
+        let width = statement.column_count();
+        let mut rows = statement.query([]).map_err(SqliteReader::to_error)?;
+
+        while let Some(row) = rows.next().map_err(SqliteReader::to_error)? {
+            let record = (0..width)
+                .map(|index| {
+                    row.get_ref(index)
+                        .map(SqliteReader::to_value)
+                        .unwrap_or_default()
+                })
+                .collect();
+            // The stream was dropped, the rest of the rows are not needed
+            if sender.blocking_send(Ok(record)).is_err() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs the query for the table, keeping `max_rows` rows and counting the rest.
+    pub async fn run(self: Arc<Self>, sql: String, max_rows: usize) -> Result<QueryResult, Error> {
+        let columns = self.get_columns(&sql).await?;
+        let mut records = self.clone().get_records(&sql);
+
+        let mut rows = vec![];
+        let mut total = 0;
+        while let Some(record) = records.next().await {
+            let record = record?;
+            if rows.len() < max_rows {
+                rows.push(IcedRow::new(record, total));
+            }
+            total += 1;
+        }
+
+        Ok(QueryResult {
+            query: SqlQuery {
+                database: self,
+                sql,
+                total: total as u64,
+            },
+            columns,
+            rows,
+        })
+    }
+}
+
+/// A query run on the database, kept to export every row of it.
+#[derive(Debug, Clone)]
+pub struct SqlQuery {
+    pub database: Arc<QueryDatabase>,
+    pub sql: String,
+    /// Rows the query returned, shown or not.
+    pub total: u64,
+}
+
+impl SqlQuery {
+    pub fn get_records(&self) -> RecordStream {
+        self.database.clone().get_records(&self.sql)
+    }
+}
+
+/// The rows of a query the table shows, the first ones when there are too many.
+#[derive(Debug, Clone)]
+pub struct QueryResult {
+    pub query: SqlQuery,
+    pub columns: Vec<IcedColumn>,
+    pub rows: Vec<IcedRow>,
+}
+
+/// Names of the columns in the database, sqlite takes them without case so the repeated
+/// ones get a number.
+fn get_column_names(stored_file: &StoredFile) -> Vec<String> {
+    let mut taken: HashSet<String> = HashSet::new();
+    stored_file
+        .columns
+        .headers
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            let header = match column.column_header.trim() {
+                "" => format!("column_{}", index + 1),
+                header => header.to_string(),
+            };
+            let mut name = header.clone();
+            let mut number = 2;
+            while !taken.insert(name.to_lowercase()) {
+                name = format!("{}_{}", header, number);
+                number += 1;
+            }
+            name
+        })
+        .collect()
+}
+
+/// The name of the file without its extension, written as a plain identifier so it can be
+/// queried without quotes.
+pub fn get_file_table_name(file_path: &str) -> String {
+    let stem = Path::new(file_path)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    to_identifier(&stem, "data")
+}
+
+/// Letters, digits and underscores, not starting with a digit.
+fn to_identifier(value: &str, empty: &str) -> String {
+    let identifier: String = value
+        .chars()
+        .map(|c| match c.is_ascii_alphanumeric() {
+            true => c.to_ascii_lowercase(),
+            false => '_',
+        })
+        .collect();
+    let identifier = identifier.trim_matches('_');
+    match identifier.chars().next() {
+        None => empty.to_string(),
+        Some(c) if c.is_ascii_digit() => format!("_{}", identifier),
+        Some(_) => identifier.to_string(),
+    }
+}
@@ -0,0 +1,100 @@
+use super::{
+    dialect::Dialect, join::Join, row_policy::RowPolicy, sampling::Sampling, schema::ColumnType,
+    sort::SortKey,
+};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct ReadOptions {
+    pub sheet: String,
+    pub header_row: usize,
+    pub dialect: Dialect,
+    pub encoding: String,
+    pub has_header: bool,
+    /// Column names given by the user, they replace the ones read from the file.
+    pub headers: Vec<String>,
+    pub row_policy: RowPolicy,
+    /// Adds the name of the file each row comes from when reading a dataset.
+    pub source_column: bool,
+    pub join: Join,
+    /// A SELECT read from a database instead of the table in `sheet`.
+    pub query: String,
+    /// Types chosen by the user, the other columns keep the inferred one.
+    pub schema: Vec<ColumnType>,
+    pub sampling: Sampling,
+    /// Order of the table and, when asked, of the export.
+    pub sort: Vec<SortKey>,
+    /// Directory the row index of a csv is kept in, the cache of the app when empty.
+    pub index_directory: String,
+}
+
+impl ReadOptions {
+    pub fn default() -> Self {
+        Self {
+            sheet: String::default(),
+            header_row: usize::default(),
+            dialect: Dialect::default(),
+            encoding: String::default(),
+            has_header: true,
+            headers: vec![],
+            row_policy: RowPolicy::default(),
+            source_column: true,
+            join: Join::default(),
+            query: String::default(),
+            schema: vec![],
+            sampling: Sampling::default(),
+            sort: vec![],
+            index_directory: String::default(),
+        }
+    }
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        sheet: String,
+        header_row: usize,
+        dialect: Dialect,
+        encoding: String,
+        has_header: bool,
+        headers: Vec<String>,
+        row_policy: RowPolicy,
+        source_column: bool,
+        join: Join,
+        query: String,
+        schema: Vec<ColumnType>,
+        sampling: Sampling,
+        sort: Vec<SortKey>,
+        index_directory: String,
+    ) -> Self {
+        Self {
+            sheet,
+            header_row,
+            dialect,
+            encoding,
+            has_header,
+            headers,
+            row_policy,
+            source_column,
+            join,
+            query,
+            schema,
+            sampling,
+            sort,
+            index_directory,
+        }
+    }
+
+    /// Reads a header list, one name per line or all of them in a single delimited line.
+    pub fn parse_headers(text: &str, delimiter: u8) -> Vec<String> {
+        let lines: Vec<&str> = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect();
+
+        match lines.as_slice() {
+            [line] => line
+                .split(delimiter as char)
+                .map(|name| name.trim().to_string())
+                .collect(),
+            lines => lines.iter().map(|line| line.to_string()).collect(),
+        }
+    }
+}
@@ -0,0 +1,383 @@
+use crate::{
+    constants::{
+        path::{ROW_INDEX_EXTENSION, ROW_INDEX_FOLDER, ROW_INDEX_MAGIC},
+        sizes::ROW_INDEX_STRIDE,
+    },
+    task_progress::TaskProgress,
+};
+use encoding_rs::Encoding;
+use futures::StreamExt;
+use std::{
+    fs::metadata,
+    hash::{DefaultHasher, Hash, Hasher},
+    io::{Error, ErrorKind, SeekFrom},
+    path::{Path, PathBuf},
+    time::UNIX_EPOCH,
+};
+use tokio::{
+    fs::File,
+    io::{AsyncReadExt, AsyncSeekExt},
+};
+
+use super::{
+    dialect::line_terminator::LineTerminator,
+    read_options::ReadOptions,
+    row_policy::{RejectedRow, RejectedRows},
+};
+
+/// Byte offsets of every `stride` record of a csv file, enough to start reading at any row
+/// without scanning the rows before it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowIndex {
+    pub file_size: u64,
+    pub modified: u64,
+    pub dialect: [u8; 5],
+    pub stride: u64,
+    pub total: u64,
+    pub offsets: Vec<u64>,
+}
+
+impl RowIndex {
+    pub fn default() -> Self {
+        Self {
+            file_size: 0,
+            modified: 0,
+            dialect: [0; 5],
+            stride: ROW_INDEX_STRIDE,
+            total: 0,
+            offsets: vec![],
+        }
+    }
+
+    /// Offsets are taken from the raw bytes, so only ascii compatible charsets can be indexed.
+    pub fn is_indexable(text_encoding: &'static Encoding) -> bool {
+        text_encoding.is_ascii_compatible()
+    }
+
+    /// Loads the index saved for the file, or builds a new one when it is missing or the
+    /// file changed since. Only files without rejected rows get their index saved, so the
+    /// rejected rows are always reported.
+    pub async fn load_or_build(
+        file_path: &str,
+        read_options: &ReadOptions,
+        width: usize,
+        text_encoding: &'static Encoding,
+        task: &TaskProgress,
+    ) -> Result<(Self, RejectedRows), Error> {
+        if let Some(index) = Self::load(file_path, read_options).await {
+            return Ok((index, RejectedRows::default()));
+        }
+
+        let (index, rejected_rows) =
+            Self::build(file_path, read_options, width, text_encoding, task).await?;
+        if rejected_rows.total == 0 {
+            let _ = index.save(file_path, read_options).await;
+        }
+        Ok((index, rejected_rows))
+    }
+
+    /// Indexes the whole file, the rows rejected by the row policy are collected. The bytes
+    /// indexed are reported to the task.
+    pub async fn build(
+        file_path: &str,
+        read_options: &ReadOptions,
+        width: usize,
+        text_encoding: &'static Encoding,
+        task: &TaskProgress,
+    ) -> Result<(Self, RejectedRows), Error> {
+        let (file_size, modified) = Self::get_file_stamp(file_path)?;
+        let mut file = File::open(file_path).await?;
+
+        // A utf-16 BOM overrides the chosen charset when the file is decoded
+        let mut bom = vec![];
+        (&mut file).take(3).read_to_end(&mut bom).await?;
+        if let Some((encoding, _)) = Encoding::for_bom(&bom) {
+            if !Self::is_indexable(encoding) {
+                return Err(Error::new(ErrorKind::InvalidData, encoding.name()));
+            }
+        }
+        file.seek(SeekFrom::Start(0)).await?;
+
+        let mut row_index = Self {
+            file_size,
+            modified,
+            dialect: Self::get_dialect_key(read_options),
+            stride: ROW_INDEX_STRIDE,
+            total: 0,
+            offsets: vec![],
+        };
+        task.start(file_size);
+        let rejected_rows = row_index
+            .index_records(
+                file,
+                0,
+                read_options.has_header,
+                read_options,
+                width,
+                text_encoding,
+                task,
+            )
+            .await?;
+        task.set_done(file_size);
+        Ok((row_index, rejected_rows))
+    }
+
+    /// Indexes the rows appended to the file since it was indexed, up to its last complete
+    /// line so a line still being written is left for the next time. Returns how many rows
+    /// were added, with the ones rejected among them.
+    pub async fn extend(
+        &mut self,
+        file_path: &str,
+        read_options: &ReadOptions,
+        width: usize,
+        text_encoding: &'static Encoding,
+    ) -> Result<(u64, RejectedRows), Error> {
+        let (_, modified) = Self::get_file_stamp(file_path)?;
+        let mut file = File::open(file_path).await?;
+        file.seek(SeekFrom::Start(self.file_size)).await?;
+        let mut appended = vec![];
+        file.read_to_end(&mut appended).await?;
+
+        let Some(end) = appended
+            .iter()
+            .rposition(|byte| *byte == b'\n' || *byte == b'\r')
+        else {
+            return Ok((0, RejectedRows::default()));
+        };
+        appended.truncate(end + 1);
+
+        let total = self.total;
+        let mut rejected_rows = self
+            .index_records(
+                &appended[..],
+                self.file_size,
+                false,
+                read_options,
+                width,
+                text_encoding,
+                &TaskProgress::default(),
+            )
+            .await?;
+        // Lines are counted from the appended bytes, the lines before are only counted
+        // when a row has to be reported
+        if rejected_rows.total > 0 {
+            let lines = Self::count_lines(file_path, self.file_size, read_options).await?;
+            for rejected in rejected_rows.rows.iter_mut() {
+                rejected.line += lines;
+            }
+        }
+
+        self.file_size += appended.len() as u64;
+        self.modified = modified;
+        Ok((self.total - total, rejected_rows))
+    }
+
+    /// Offsets count the records kept by the row policy, the rejected ones are collected.
+    /// Stops when the task is cancelled.
+    #[allow(clippy::too_many_arguments)]
+    async fn index_records<R>(
+        &mut self,
+        reader: R,
+        base_offset: u64,
+        has_header: bool,
+        read_options: &ReadOptions,
+        width: usize,
+        text_encoding: &'static Encoding,
+        task: &TaskProgress,
+    ) -> Result<RejectedRows, Error>
+    where
+        R: tokio::io::AsyncRead + Unpin + Send,
+    {
+        let mut records = read_options
+            .dialect
+            .get_reader_builder()
+            .has_headers(has_header)
+            .flexible(true)
+            .create_reader(reader)
+            .into_byte_records();
+        let delimiter = read_options.dialect.delimiter;
+        let mut rejected_rows = RejectedRows::default();
+
+        while let Some(record) = records.next().await {
+            let position = match &record {
+                Ok(record) => record.position(),
+                Err(error) => error.position(),
+            };
+            let (offset, line) = position.map_or((None, 0), |position| {
+                (Some(base_offset + position.byte()), position.line())
+            });
+
+            // Values are only decoded for the records that do not fit
+            let record = match record {
+                Ok(record) if width == 0 || record.len() == width => Ok(vec![]),
+                Ok(record) => {
+                    let values = record
+                        .iter()
+                        .map(|value| text_encoding.decode_without_bom_handling(value).0.into())
+                        .collect();
+                    Err(RejectedRow::from_values(line, values, width, delimiter).into_error())
+                }
+                Err(error) => {
+                    Err(RejectedRow::new(line, error.to_string(), "".into(), vec![]).into_error())
+                }
+            };
+            if let Err(error) = &record {
+                rejected_rows.push(error);
+            }
+            match read_options.row_policy.apply(record, width) {
+                Some(Ok(_)) => {}
+                Some(Err(error)) => return Err(error),
+                None => continue,
+            }
+
+            if self.total.is_multiple_of(ROW_INDEX_STRIDE) {
+                let offset = offset.ok_or(Error::from(ErrorKind::InvalidData))?;
+                self.offsets.push(offset);
+                task.set_done(offset);
+                task.check()?;
+            }
+            self.total += 1;
+        }
+
+        Ok(rejected_rows)
+    }
+
+    /// Where the reading of `row` has to start, and how many records to skip from there.
+    pub fn get_offset(&self, row: u64) -> Option<(u64, usize)> {
+        let block = row / self.stride;
+        let offset = self.offsets.get(block as usize)?;
+        Some((*offset, (row % self.stride) as usize))
+    }
+
+    /// The directory of the read options, or the cache of the app, so the folders of the
+    /// data are left as they are.
+    pub fn get_index_directory(read_options: &ReadOptions) -> PathBuf {
+        match read_options.index_directory.is_empty() {
+            true => dirs::cache_dir()
+                .unwrap_or(std::env::temp_dir())
+                .join(ROW_INDEX_FOLDER),
+            false => PathBuf::from(&read_options.index_directory),
+        }
+    }
+
+    /// One index per file, named after its full path. The stamp of the file is saved in
+    /// the index, an index of an older version of the file is replaced.
+    pub fn get_index_path(file_path: &str, read_options: &ReadOptions) -> PathBuf {
+        let path = std::fs::canonicalize(file_path).unwrap_or(PathBuf::from(file_path));
+        let mut hasher = DefaultHasher::new();
+        path.hash(&mut hasher);
+        let stem = Path::new(file_path)
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy();
+        Self::get_index_directory(read_options).join(format!(
+            "{}-{:016x}.{}",
+            stem,
+            hasher.finish(),
+            ROW_INDEX_EXTENSION
+        ))
+    }
+
+    async fn load(file_path: &str, read_options: &ReadOptions) -> Option<Self> {
+        let (file_size, modified) = Self::get_file_stamp(file_path).ok()?;
+        let bytes = tokio::fs::read(Self::get_index_path(file_path, read_options))
+            .await
+            .ok()?;
+        let index = Self::from_bytes(&bytes)?;
+
+        let is_valid = index.file_size == file_size
+            && index.modified == modified
+            && index.dialect == Self::get_dialect_key(read_options)
+            && index.stride == ROW_INDEX_STRIDE;
+        is_valid.then_some(index)
+    }
+
+    pub async fn save(&self, file_path: &str, read_options: &ReadOptions) -> Result<(), Error> {
+        tokio::fs::create_dir_all(Self::get_index_directory(read_options)).await?;
+        tokio::fs::write(
+            Self::get_index_path(file_path, read_options),
+            self.to_bytes(),
+        )
+        .await
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = ROW_INDEX_MAGIC.to_vec();
+        bytes.extend_from_slice(&self.dialect);
+        for value in [self.file_size, self.modified, self.stride, self.total] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        for offset in &self.offsets {
+            bytes.extend_from_slice(&offset.to_le_bytes());
+        }
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let bytes = bytes.strip_prefix(ROW_INDEX_MAGIC)?;
+        let (dialect, bytes) = bytes.split_first_chunk::<5>()?;
+        let mut values = bytes
+            .chunks_exact(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()));
+
+        let index = Self {
+            file_size: values.next()?,
+            modified: values.next()?,
+            dialect: *dialect,
+            stride: values.next()?,
+            total: values.next()?,
+            offsets: values.collect(),
+        };
+        (index.stride > 0 && index.offsets.len() as u64 == index.total.div_ceil(index.stride))
+            .then_some(index)
+    }
+
+    /// Lines ended by the terminator of the dialect, a crlf one also ends the lines with a
+    /// bare `\r` or `\n` as the reader does.
+    async fn count_lines(
+        file_path: &str,
+        end: u64,
+        read_options: &ReadOptions,
+    ) -> Result<u64, Error> {
+        let mut file = File::open(file_path).await?.take(end);
+        let mut buffer = vec![0; 64 * 1024];
+        let mut lines = 0;
+        let mut previous = 0;
+        loop {
+            let read = file.read(&mut buffer).await?;
+            if read == 0 {
+                return Ok(lines);
+            }
+            for byte in &buffer[..read] {
+                let is_line_end = match read_options.dialect.terminator {
+                    LineTerminator::LF => *byte == b'\n',
+                    LineTerminator::CR => *byte == b'\r',
+                    LineTerminator::CRLF => *byte == b'\r' || (*byte == b'\n' && previous != b'\r'),
+                };
+                lines += is_line_end as u64;
+                previous = *byte;
+            }
+        }
+    }
+
+    /// Size and modification time in nanoseconds of a file.
+    pub fn get_file_stamp(file_path: &str) -> Result<(u64, u64), Error> {
+        let metadata = metadata(file_path)?;
+        let modified = metadata
+            .modified()?
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_nanos() as u64);
+        Ok((metadata.len(), modified))
+    }
+
+    fn get_dialect_key(read_options: &ReadOptions) -> [u8; 5] {
+        let dialect = &read_options.dialect;
+        [
+            dialect.delimiter,
+            dialect.quote,
+            dialect.escape.unwrap_or_default(),
+            read_options.has_header as u8,
+            dialect.terminator.clone() as u8,
+        ]
+    }
+}
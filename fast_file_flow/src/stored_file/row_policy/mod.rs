@@ -0,0 +1,187 @@
+use crate::constants::{
+    english::{ERROR_ROW_WIDTH, ROW_POLICY_FAIL, ROW_POLICY_PAD, ROW_POLICY_SKIP},
+    sizes::REJECTED_ROWS_MAX,
+};
+use std::io::{Error, ErrorKind};
+
+/// What to do with a record that can not be read or does not have one value per column.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum RowPolicy {
+    Fail,
+    Skip,
+    Pad,
+}
+
+impl RowPolicy {
+    pub fn default() -> Self {
+        RowPolicy::Fail
+    }
+
+    pub fn to_string(&self) -> &str {
+        match self {
+            RowPolicy::Fail => ROW_POLICY_FAIL,
+            RowPolicy::Skip => ROW_POLICY_SKIP,
+            RowPolicy::Pad => ROW_POLICY_PAD,
+        }
+    }
+
+    pub fn from_string(unwrap: &str) -> RowPolicy {
+        match unwrap {
+            ROW_POLICY_SKIP => RowPolicy::Skip,
+            ROW_POLICY_PAD => RowPolicy::Pad,
+            _ => RowPolicy::Fail,
+        }
+    }
+
+    pub fn all() -> Vec<String> {
+        [RowPolicy::Fail, RowPolicy::Skip, RowPolicy::Pad]
+            .iter()
+            .map(|policy| policy.to_string().to_string())
+            .collect()
+    }
+
+    /// Returns the record to keep, `None` when it is dropped. Failing keeps the error so
+    /// the reader stops there.
+    pub fn apply(
+        &self,
+        record: Result<Vec<String>, Error>,
+        width: usize,
+    ) -> Option<Result<Vec<String>, Error>> {
+        let Err(error) = record else {
+            return Some(record);
+        };
+
+        match (self, RejectedRow::from_error(&error)) {
+            (RowPolicy::Fail, _) => Some(Err(error)),
+            (RowPolicy::Pad, Some(rejected)) if !rejected.values.is_empty() => {
+                let mut values = rejected.values.clone();
+                values.resize(width, String::new());
+                Some(Ok(values))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A record that did not fit the file, kept to be reported back.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct RejectedRow {
+    pub line: u64,
+    pub error: String,
+    pub raw: String,
+    /// The values read, empty when the record could not be parsed at all.
+    pub values: Vec<String>,
+    /// The file of a dataset the row comes from, empty for a single file.
+    pub source: String,
+}
+
+impl RejectedRow {
+    pub fn new(line: u64, error: String, raw: String, values: Vec<String>) -> Self {
+        Self {
+            line,
+            error,
+            raw,
+            values,
+            source: String::new(),
+        }
+    }
+
+    pub fn with_source(mut self, source: &str) -> Self {
+        self.source = source.to_string();
+        self
+    }
+
+    /// Tags the rejected row carried by an error with the file it was read from.
+    pub fn tag_error(error: Error, source: &str) -> Error {
+        match Self::from_error(&error) {
+            Some(rejected) => rejected.clone().with_source(source).into_error(),
+            None => error,
+        }
+    }
+
+    /// A record with more or less values than columns.
+    pub fn from_values(line: u64, values: Vec<String>, width: usize, delimiter: u8) -> Self {
+        let error = ERROR_ROW_WIDTH
+            .replacen("{}", &values.len().to_string(), 1)
+            .replacen("{}", &width.to_string(), 1);
+        let raw = values.join(&(delimiter as char).to_string());
+        Self::new(line, error, raw, values)
+    }
+
+    pub fn from_error(error: &Error) -> Option<&RejectedRow> {
+        error.get_ref()?.downcast_ref::<RejectedRow>()
+    }
+
+    pub fn into_error(self) -> Error {
+        Error::new(ErrorKind::InvalidData, self)
+    }
+}
+
+impl std::fmt::Display for RejectedRow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.source.is_empty() {
+            write!(f, "{} ", self.source)?;
+        }
+        write!(f, "line {}: {}", self.line, self.error)
+    }
+}
+
+impl std::error::Error for RejectedRow {}
+
+/// Rejected rows found while counting the file, only the first ones are kept.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct RejectedRows {
+    pub total: u64,
+    pub rows: Vec<RejectedRow>,
+}
+
+impl RejectedRows {
+    pub fn default() -> Self {
+        Self {
+            total: 0,
+            rows: vec![],
+        }
+    }
+
+    pub fn push(&mut self, error: &Error) {
+        self.total += 1;
+        if self.rows.len() < REJECTED_ROWS_MAX {
+            let rejected = RejectedRow::from_error(error).cloned();
+            self.rows.push(
+                rejected
+                    .unwrap_or_else(|| RejectedRow::new(0, error.to_string(), "".into(), vec![])),
+            );
+        }
+    }
+
+    /// Adds the rows rejected in one file of a dataset.
+    pub fn append(&mut self, other: RejectedRows, source: &str) {
+        self.total += other.total;
+        let room = REJECTED_ROWS_MAX.saturating_sub(self.rows.len());
+        self.rows.extend(
+            other
+                .rows
+                .into_iter()
+                .take(room)
+                .map(|row| row.with_source(source)),
+        );
+    }
+
+    /// Writes the quarantine file handed back to whoever produced the data.
+    pub fn write_csv(&self, file_path: &str) -> Result<(), Error> {
+        let mut wtr = csv::Writer::from_path(file_path)?;
+        let has_source = self.rows.iter().any(|row| !row.source.is_empty());
+        match has_source {
+            true => wtr.write_record(["source", "line", "error", "record"])?,
+            false => wtr.write_record(["line", "error", "record"])?,
+        }
+        for row in &self.rows {
+            let mut record = vec![row.line.to_string(), row.error.clone(), row.raw.clone()];
+            if has_source {
+                record.insert(0, row.source.clone());
+            }
+            wtr.write_record(record)?;
+        }
+        wtr.flush()
+    }
+}
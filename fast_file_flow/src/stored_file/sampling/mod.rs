@@ -0,0 +1,207 @@
+pub mod sample_mode;
+
+use crate::{
+    constants::sizes::{SAMPLE_SEED_DEFAULT, SAMPLE_SIZE_DEFAULT, SAMPLE_STEP_DEFAULT},
+    dynamictable::iced_row::IcedRow,
+};
+use futures::{future, StreamExt};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use sample_mode::SampleMode;
+use std::{cmp::Reverse, collections::BTreeMap, io::Error};
+
+use super::RecordStream;
+
+/// The rows shown in the table, and given to the models when asked, instead of the whole
+/// file.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Sampling {
+    pub mode: SampleMode,
+    /// Rows in the sample.
+    pub size: usize,
+    /// Seed of the random and stratified samples.
+    pub seed: u64,
+    /// Column whose values are the strata of a stratified sample.
+    pub column: String,
+    /// Rows from one row of an every nth sample to the next.
+    pub step: u64,
+    /// The models are fed with the sample instead of the whole file.
+    pub for_models: bool,
+}
+
+impl Sampling {
+    pub fn default() -> Self {
+        Self {
+            mode: SampleMode::default(),
+            size: SAMPLE_SIZE_DEFAULT,
+            seed: SAMPLE_SEED_DEFAULT,
+            column: String::new(),
+            step: SAMPLE_STEP_DEFAULT,
+            for_models: false,
+        }
+    }
+
+    pub fn new(
+        mode: SampleMode,
+        size: usize,
+        seed: u64,
+        column: String,
+        step: u64,
+        for_models: bool,
+    ) -> Self {
+        Self {
+            mode,
+            size,
+            seed,
+            column,
+            step,
+            for_models,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.mode != SampleMode::All
+    }
+
+    /// The first rows of the records, numbered from `start`.
+    pub async fn take(&self, records: RecordStream, start: u64) -> Result<Vec<IcedRow>, Error> {
+        let mut records = records.take(self.size);
+        let mut rows = Vec::with_capacity(self.size);
+        while let Some(record) = records.next().await {
+            rows.push(IcedRow::new(record?, start as usize + rows.len()));
+        }
+        Ok(rows)
+    }
+
+    pub async fn every_nth(&self, records: RecordStream) -> Result<Vec<IcedRow>, Error> {
+        let step = self.step.max(1) as usize;
+        let mut records = records
+            .enumerate()
+            .filter(|(row_index, _)| future::ready(row_index % step == 0))
+            .take(self.size);
+        let mut rows = Vec::with_capacity(self.size);
+        while let Some((row_index, record)) = records.next().await {
+            rows.push(IcedRow::new(record?, row_index));
+        }
+        Ok(rows)
+    }
+
+    /// Every row has the same chance to be in the sample, which is drawn in one pass
+    /// keeping `size` rows at most.
+    pub async fn reservoir(&self, mut records: RecordStream) -> Result<Vec<IcedRow>, Error> {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut reservoir = Reservoir::new(self.size);
+        let mut row_index = 0;
+        while let Some(record) = records.next().await {
+            reservoir.offer(IcedRow::new(record?, row_index), &mut rng);
+            row_index += 1;
+        }
+        Ok(Self::sorted(reservoir.rows))
+    }
+
+    /// Gives every value of the column a share of the sample in proportion to its rows,
+    /// `strata` being the rows of every value, and draws a reservoir sample within each one.
+    pub async fn stratified(
+        &self,
+        mut records: RecordStream,
+        column_index: usize,
+        strata: BTreeMap<String, u64>,
+    ) -> Result<Vec<IcedRow>, Error> {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut reservoirs: BTreeMap<String, Reservoir> = Self::get_quotas(&strata, self.size)
+            .into_iter()
+            .map(|(value, quota)| (value, Reservoir::new(quota)))
+            .collect();
+        let mut row_index = 0;
+        while let Some(record) = records.next().await {
+            let record = record?;
+            let value = record.get(column_index).cloned().unwrap_or_default();
+            if let Some(reservoir) = reservoirs.get_mut(&value) {
+                reservoir.offer(IcedRow::new(record, row_index), &mut rng);
+            }
+            row_index += 1;
+        }
+        Ok(Self::sorted(
+            reservoirs
+                .into_values()
+                .flat_map(|reservoir| reservoir.rows)
+                .collect(),
+        ))
+    }
+
+    /// Rows of every value of the column.
+    pub async fn count_strata(
+        mut records: RecordStream,
+        column_index: usize,
+    ) -> Result<BTreeMap<String, u64>, Error> {
+        let mut strata = BTreeMap::new();
+        while let Some(record) = records.next().await {
+            let mut record = record?;
+            let value = match column_index < record.len() {
+                true => record.swap_remove(column_index),
+                false => String::new(),
+            };
+            *strata.entry(value).or_insert(0) += 1;
+        }
+        Ok(strata)
+    }
+
+    /// Proportional shares, the rows left by the rounding go to the largest remainders.
+    fn get_quotas(strata: &BTreeMap<String, u64>, size: usize) -> BTreeMap<String, usize> {
+        let total: u64 = strata.values().sum();
+        let size = (size as u64).min(total);
+        if total == 0 {
+            return BTreeMap::new();
+        }
+
+        let mut quotas: BTreeMap<String, usize> = strata
+            .iter()
+            .map(|(value, rows)| (value.clone(), (rows * size / total) as usize))
+            .collect();
+        let mut remainders: Vec<(&String, u64)> = strata
+            .iter()
+            .map(|(value, rows)| (value, rows * size % total))
+            .collect();
+        remainders.sort_by_key(|remainder| Reverse(remainder.1));
+
+        let left = size as usize - quotas.values().sum::<usize>();
+        for (value, _) in remainders.into_iter().take(left) {
+            *quotas.get_mut(value).unwrap() += 1;
+        }
+        quotas
+    }
+
+    fn sorted(mut rows: Vec<IcedRow>) -> Vec<IcedRow> {
+        rows.sort_by_key(|row| row.row_index);
+        rows
+    }
+}
+
+/// Keeps a uniform sample of the rows offered to it.
+struct Reservoir {
+    size: usize,
+    seen: usize,
+    rows: Vec<IcedRow>,
+}
+
+impl Reservoir {
+    fn new(size: usize) -> Self {
+        Self {
+            size,
+            seen: 0,
+            rows: vec![],
+        }
+    }
+
+    fn offer(&mut self, row: IcedRow, rng: &mut StdRng) {
+        self.seen += 1;
+        if self.rows.len() < self.size {
+            self.rows.push(row);
+            return;
+        }
+
+        let index = rng.gen_range(0..self.seen);
+        if index < self.size {
+            self.rows[index] = row;
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use crate::constants::english::{
+    SAMPLE_ALL, SAMPLE_EVERY_NTH, SAMPLE_HEAD, SAMPLE_RANDOM, SAMPLE_STRATIFIED, SAMPLE_TAIL,
+};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum SampleMode {
+    /// Every row of the file, nothing is sampled.
+    All,
+    Head,
+    Tail,
+    /// Uniform reservoir sample, the same seed draws the same rows.
+    Random,
+    /// Random rows from every value of a column, in proportion to how often it appears.
+    Stratified,
+    /// One row out of every `step` rows.
+    EveryNth,
+}
+
+impl SampleMode {
+    pub fn default() -> Self {
+        SampleMode::All
+    }
+
+    pub fn to_string(&self) -> &str {
+        match self {
+            SampleMode::All => SAMPLE_ALL,
+            SampleMode::Head => SAMPLE_HEAD,
+            SampleMode::Tail => SAMPLE_TAIL,
+            SampleMode::Random => SAMPLE_RANDOM,
+            SampleMode::Stratified => SAMPLE_STRATIFIED,
+            SampleMode::EveryNth => SAMPLE_EVERY_NTH,
+        }
+    }
+
+    pub fn from_string(unwrap: &str) -> SampleMode {
+        match unwrap {
+            SAMPLE_HEAD => SampleMode::Head,
+            SAMPLE_TAIL => SampleMode::Tail,
+            SAMPLE_RANDOM => SampleMode::Random,
+            SAMPLE_STRATIFIED => SampleMode::Stratified,
+            SAMPLE_EVERY_NTH => SampleMode::EveryNth,
+            _ => SampleMode::All,
+        }
+    }
+
+    pub fn all() -> Vec<String> {
+        [
+            SampleMode::All,
+            SampleMode::Head,
+            SampleMode::Tail,
+            SampleMode::Random,
+            SampleMode::Stratified,
+            SampleMode::EveryNth,
+        ]
+        .iter()
+        .map(|sample_mode| sample_mode.to_string().to_string())
+        .collect()
+    }
+}
@@ -0,0 +1,79 @@
+use crate::stadistics::data_type::DataType;
+use chrono::{
+    format::{Item, StrftimeItems},
+    NaiveDate, NaiveDateTime, NaiveTime,
+};
+
+const ISO_DATE: &str = "%Y-%m-%d";
+const ISO_TIME: &str = "%H:%M:%S";
+const ISO_DATETIME: &str = "%Y-%m-%d %H:%M:%S";
+
+/// The type given by the user to a column in place of the inferred one, with the rules to
+/// parse its values.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct ColumnType {
+    pub header: String,
+    pub data_type: DataType,
+    /// chrono format of the dates and times, such as `%d/%m/%Y`, empty when they are ISO.
+    pub date_format: String,
+    /// Decimal separator of the numbers, the other one of `.` and `,` groups the thousands.
+    pub decimal_separator: char,
+}
+
+impl ColumnType {
+    pub fn default() -> Self {
+        Self {
+            header: String::new(),
+            data_type: DataType::Unknown,
+            date_format: String::new(),
+            decimal_separator: '.',
+        }
+    }
+
+    pub fn new(
+        header: String,
+        data_type: DataType,
+        date_format: String,
+        decimal_separator: char,
+    ) -> Self {
+        Self {
+            header,
+            data_type,
+            date_format,
+            decimal_separator,
+        }
+    }
+
+    pub fn is_valid_date_format(date_format: &str) -> bool {
+        !StrftimeItems::new(date_format).any(|item| item == Item::Error)
+    }
+
+    /// The value written the way statistics and typed exports parse it, numbers with a `.`
+    /// and dates in ISO format. Values that do not follow the rules are left as they are.
+    pub fn normalize(&self, value: &str) -> String {
+        let value = value.trim();
+        match self.data_type {
+            DataType::Integer | DataType::Float => {
+                let thousands = if self.decimal_separator == ',' {
+                    '.'
+                } else {
+                    ','
+                };
+                value
+                    .replace(thousands, "")
+                    .replace(self.decimal_separator, ".")
+            }
+            _ if self.date_format.is_empty() => value.to_string(),
+            DataType::Date => NaiveDate::parse_from_str(value, &self.date_format)
+                .map(|date| date.format(ISO_DATE).to_string())
+                .unwrap_or(value.to_string()),
+            DataType::Time => NaiveTime::parse_from_str(value, &self.date_format)
+                .map(|time| time.format(ISO_TIME).to_string())
+                .unwrap_or(value.to_string()),
+            DataType::DateTime => NaiveDateTime::parse_from_str(value, &self.date_format)
+                .map(|date| date.format(ISO_DATETIME).to_string())
+                .unwrap_or(value.to_string()),
+            _ => value.to_string(),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::dynamictable::iced_row::IcedRow;
+use futures::StreamExt;
+use regex::Regex;
+use std::io::{Error, ErrorKind};
+
+use super::RecordStream;
+
+/// What to look for in the values of the file.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Search {
+    pub query: String,
+    /// Columns looked into, every column when it is empty.
+    pub columns: Vec<usize>,
+    pub case_sensitive: bool,
+    /// Only whole words match, not a part of a longer word.
+    pub whole_word: bool,
+    /// The query is a regular expression instead of plain text.
+    pub is_regex: bool,
+}
+
+impl Search {
+    pub fn default() -> Self {
+        Self {
+            query: String::new(),
+            columns: vec![],
+            case_sensitive: false,
+            whole_word: false,
+            is_regex: false,
+        }
+    }
+
+    pub fn new(
+        query: String,
+        columns: Vec<usize>,
+        case_sensitive: bool,
+        whole_word: bool,
+        is_regex: bool,
+    ) -> Self {
+        Self {
+            query,
+            columns,
+            case_sensitive,
+            whole_word,
+            is_regex,
+        }
+    }
+
+    /// The query as one expression with its options inline, so it can be kept as the
+    /// text of a filter. Plain text is escaped so it matches as written.
+    pub fn get_pattern(&self) -> String {
+        let pattern = match self.is_regex {
+            true => self.query.clone(),
+            false => regex::escape(&self.query),
+        };
+        let pattern = match self.whole_word {
+            true => format!(r"\b(?:{})\b", pattern),
+            false => pattern,
+        };
+        match self.case_sensitive {
+            true => pattern,
+            false => format!("(?i){}", pattern),
+        }
+    }
+
+    pub fn get_matcher(&self) -> Result<Regex, Error> {
+        Regex::new(&self.get_pattern())
+            .map_err(|e| Error::new(ErrorKind::InvalidInput, e.to_string()))
+    }
+
+    /// Columns of the record whose value matches, empty when the row is not a match.
+    pub fn find(&self, matcher: &Regex, values: &[String]) -> Vec<usize> {
+        values
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| self.columns.is_empty() || self.columns.contains(index))
+            .filter(|(_, value)| matcher.is_match(value))
+            .map(|(index, _)| index)
+            .collect()
+    }
+}
+
+/// A search going through the records of the file, one batch of rows at a time so the
+/// matches show up while the rest of the file is read.
+pub struct SearchCursor {
+    search: Search,
+    matcher: Regex,
+    records: RecordStream,
+    row_index: usize,
+}
+
+impl SearchCursor {
+    pub fn new(search: Search, matcher: Regex, records: RecordStream) -> Self {
+        Self {
+            search,
+            matcher,
+            records,
+            row_index: 0,
+        }
+    }
+
+    /// The matches among the next `rows` records, `None` once every record was read.
+    pub async fn next_batch(&mut self, rows: usize) -> Option<Result<Vec<IcedRow>, Error>> {
+        let mut matches = vec![];
+        let mut read = 0;
+        while read < rows {
+            let Some(record) = self.records.next().await else {
+                break;
+            };
+            let values = match record {
+                Ok(values) => values,
+                Err(e) => return Some(Err(e)),
+            };
+            let columns = self.search.find(&self.matcher, &values);
+            if !columns.is_empty() {
+                let mut row = IcedRow::new(values, self.row_index);
+                row.highlighted = columns;
+                matches.push(row);
+            }
+            self.row_index += 1;
+            read += 1;
+        }
+
+        match read {
+            0 => None,
+            _ => Some(Ok(matches)),
+        }
+    }
+}
@@ -0,0 +1,331 @@
+use crate::{
+    constants::{path::SORT_EXTENSION, sizes::ROW_INDEX_STRIDE},
+    stored_file::RecordStream,
+};
+use futures::stream::{self, StreamExt};
+use rayon::slice::ParallelSliceMut;
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fs::{remove_file, File},
+    io::{BufReader, BufWriter, Error, Seek, SeekFrom, Write},
+    mem::size_of,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+use tokio::sync::mpsc;
+
+use super::{SortCell, SortKey, SortValue, Sorter};
+
+static NEXT_FILE: AtomicU64 = AtomicU64::new(1);
+
+/// Records read ahead of the stream from the files of a sort.
+const CHANNEL_ROWS: usize = 1024;
+
+/// A record with the key it is ordered by, and the run it comes from so equal keys keep
+/// the order they had in the file.
+type KeyedRecord = (Vec<SortCell>, usize, Vec<String>);
+
+/// Sorts the records in runs that fit in `run_bytes`. The runs are written to disk when
+/// there is more than one and merged while they are read back. Sorting and writing the runs
+/// is blocking work, done aside from the runtime.
+pub async fn sort_records(
+    mut records: RecordStream,
+    sorter: Sorter,
+    run_bytes: usize,
+) -> Result<SortedRecords, Error> {
+    let mut runs: Vec<RunFile> = vec![];
+    let mut run: Vec<KeyedRecord> = vec![];
+    let mut bytes = 0;
+    while let Some(record) = records.next().await {
+        let record = record?;
+        let key = sorter.get_key(&record);
+        bytes += get_record_bytes(&key, &record);
+        run.push((key, runs.len(), record));
+        if bytes >= run_bytes {
+            let full_run = std::mem::take(&mut run);
+            runs.push(
+                tokio::task::spawn_blocking(move || RunFile::write(sort_run(full_run))).await??,
+            );
+            bytes = 0;
+        }
+    }
+
+    tokio::task::spawn_blocking(move || {
+        let run = sort_run(run);
+        if runs.is_empty() {
+            return Ok(SortedRecords::Memory(run.into_iter()));
+        }
+        if !run.is_empty() {
+            runs.push(RunFile::write(run)?);
+        }
+        SortedRecords::merge(runs, sorter)
+    })
+    .await?
+}
+
+/// Memory a record takes in a run, with its key and the strings and vectors they own.
+fn get_record_bytes(key: &[SortCell], record: &[String]) -> usize {
+    let values: usize = record
+        .iter()
+        .map(|value| size_of::<String>() + value.capacity())
+        .sum();
+    let cells: usize = key
+        .iter()
+        .map(|cell| match &cell.value {
+            SortValue::Text(folded, value) => {
+                size_of::<SortCell>() + folded.capacity() + value.capacity()
+            }
+            _ => size_of::<SortCell>(),
+        })
+        .sum();
+    size_of::<KeyedRecord>() + values + cells
+}
+
+/// The records of an iterator over files, read in a blocking task while the stream is
+/// consumed.
+fn into_blocking_stream<I>(records: I) -> RecordStream
+where
+    I: Iterator<Item = Result<Vec<String>, Error>> + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel(CHANNEL_ROWS);
+    tokio::task::spawn_blocking(move || {
+        for record in records {
+            // The stream was dropped, the rest of the records are not needed
+            if sender.blocking_send(record).is_err() {
+                break;
+            }
+        }
+    });
+
+    stream::unfold(receiver, |mut receiver| async move {
+        receiver.recv().await.map(|record| (record, receiver))
+    })
+    .boxed()
+}
+
+fn sort_run(mut run: Vec<KeyedRecord>) -> Vec<KeyedRecord> {
+    run.par_sort_by(|a, b| a.0.cmp(&b.0));
+    run
+}
+
+fn get_temp_path() -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "fast_file_flow_{}_{}.{}",
+        std::process::id(),
+        NEXT_FILE.fetch_add(1, Ordering::Relaxed),
+        SORT_EXTENSION
+    ))
+}
+
+/// A sorted run written to disk, removed once it was merged.
+pub struct RunFile {
+    path: PathBuf,
+    reader: Option<csv::Reader<BufReader<File>>>,
+}
+
+impl RunFile {
+    fn write(run: Vec<KeyedRecord>) -> Result<Self, Error> {
+        let path = get_temp_path();
+        let mut writer = csv::WriterBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .from_writer(BufWriter::new(File::create(&path)?));
+        for (_, _, record) in run {
+            writer.write_record(&record)?;
+        }
+        writer.flush()?;
+        Ok(Self { path, reader: None })
+    }
+
+    fn next_record(&mut self) -> Option<Result<Vec<String>, Error>> {
+        if self.reader.is_none() {
+            match File::open(&self.path) {
+                Ok(file) => {
+                    self.reader = Some(
+                        csv::ReaderBuilder::new()
+                            .has_headers(false)
+                            .flexible(true)
+                            .from_reader(BufReader::new(file)),
+                    )
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+
+        let mut record = csv::StringRecord::new();
+        match self.reader.as_mut()?.read_record(&mut record) {
+            Ok(true) => Some(Ok(record.iter().map(String::from).collect())),
+            Ok(false) => None,
+            Err(e) => Some(Err(e.into())),
+        }
+    }
+}
+
+impl Drop for RunFile {
+    fn drop(&mut self) {
+        self.reader = None;
+        let _ = remove_file(&self.path);
+    }
+}
+
+/// The records in order, from memory or merging the runs on disk.
+pub enum SortedRecords {
+    Memory(std::vec::IntoIter<KeyedRecord>),
+    Merge {
+        runs: Vec<RunFile>,
+        heap: BinaryHeap<Reverse<KeyedRecord>>,
+        sorter: Sorter,
+    },
+}
+
+impl SortedRecords {
+    fn merge(mut runs: Vec<RunFile>, sorter: Sorter) -> Result<Self, Error> {
+        let mut heap = BinaryHeap::with_capacity(runs.len());
+        for (index, run) in runs.iter_mut().enumerate() {
+            if let Some(record) = run.next_record() {
+                let record = record?;
+                heap.push(Reverse((sorter.get_key(&record), index, record)));
+            }
+        }
+        Ok(SortedRecords::Merge { runs, heap, sorter })
+    }
+
+    pub fn into_stream(self) -> RecordStream {
+        match self {
+            SortedRecords::Memory(_) => stream::iter(self).boxed(),
+            SortedRecords::Merge { .. } => into_blocking_stream(self),
+        }
+    }
+}
+
+impl Iterator for SortedRecords {
+    type Item = Result<Vec<String>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            SortedRecords::Memory(records) => records.next().map(|(_, _, record)| Ok(record)),
+            SortedRecords::Merge { runs, heap, sorter } => {
+                let Reverse((_, index, record)) = heap.pop()?;
+                match runs[index].next_record() {
+                    Some(Ok(next)) => heap.push(Reverse((sorter.get_key(&next), index, next))),
+                    Some(Err(e)) => return Some(Err(e)),
+                    None => {}
+                }
+                Some(Ok(record))
+            }
+        }
+    }
+}
+
+/// The whole table in order, written to a file with the offset of every `stride` record
+/// so the table can read any window of it.
+#[derive(Debug)]
+pub struct SortedFile {
+    /// The keys the table was sorted by.
+    pub keys: Vec<SortKey>,
+    path: PathBuf,
+    offsets: Vec<u64>,
+    pub total: u64,
+}
+
+impl SortedFile {
+    pub fn write(records: SortedRecords, keys: Vec<SortKey>) -> Result<Self, Error> {
+        let path = get_temp_path();
+        let mut writer = csv::WriterBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .from_writer(CountingWriter::new(BufWriter::new(File::create(&path)?)));
+        let mut offsets = vec![];
+        let mut total = 0;
+        for record in records {
+            let record = record?;
+            if total % ROW_INDEX_STRIDE == 0 {
+                // What the csv writer holds is counted once it is flushed
+                writer.flush()?;
+                offsets.push(writer.get_ref().written);
+            }
+            writer.write_record(&record)?;
+            total += 1;
+        }
+        writer.flush()?;
+        Ok(Self {
+            keys,
+            path,
+            offsets,
+            total,
+        })
+    }
+
+    /// The records from `start`, `count` at most.
+    pub fn get_records(&self, start: u64, count: usize) -> Result<Vec<Vec<String>>, Error> {
+        let Some(offset) = self.offsets.get((start / ROW_INDEX_STRIDE) as usize) else {
+            return Ok(vec![]);
+        };
+        let mut file = File::open(&self.path)?;
+        file.seek(SeekFrom::Start(*offset))?;
+        let reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(BufReader::new(file));
+        reader
+            .into_records()
+            .skip((start % ROW_INDEX_STRIDE) as usize)
+            .take(count)
+            .map(|record| {
+                record
+                    .map(|record| record.iter().map(String::from).collect())
+                    .map_err(Error::from)
+            })
+            .collect()
+    }
+
+    /// Every record of the file in order, the file is kept until they are read.
+    pub fn get_all_records(self: Arc<Self>) -> Result<RecordStream, Error> {
+        let reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(BufReader::new(File::open(&self.path)?));
+        Ok(into_blocking_stream(reader.into_records().map(
+            move |record| {
+                let _sorted_file = &self;
+                record
+                    .map(|record| record.iter().map(String::from).collect())
+                    .map_err(Error::from)
+            },
+        )))
+    }
+}
+
+impl Drop for SortedFile {
+    fn drop(&mut self) {
+        let _ = remove_file(&self.path);
+    }
+}
+
+/// Counts the bytes written, to know the offset of every record.
+struct CountingWriter<W: Write> {
+    inner: W,
+    written: u64,
+}
+
+impl<W: Write> CountingWriter<W> {
+    fn new(inner: W) -> Self {
+        Self { inner, written: 0 }
+    }
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.written += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
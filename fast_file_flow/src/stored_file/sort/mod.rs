@@ -0,0 +1,218 @@
+pub mod external_sort;
+
+use crate::{
+    constants::english::{SORT_ASCENDING, SORT_DESCENDING},
+    stadistics::data_type::DataType,
+};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use std::{
+    cmp::Ordering,
+    io::{Error, ErrorKind},
+};
+
+use super::schema::ColumnType;
+
+// Dates such as 03/04/2024 read as either day or month first, they only parse with the
+// format chosen in the schema and are compared as text otherwise
+const DATE_FORMATS: [&str; 1] = ["%Y-%m-%d"];
+const TIME_FORMATS: [&str; 2] = ["%H:%M:%S", "%H:%M"];
+const DATETIME_FORMATS: [&str; 4] = [
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%dT%H:%M",
+];
+
+/// A column the rows are ordered by, the first key decides and the next ones break ties.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct SortKey {
+    pub header: String,
+    pub ascending: bool,
+}
+
+impl SortKey {
+    pub fn new(header: String, ascending: bool) -> Self {
+        Self { header, ascending }
+    }
+}
+
+impl std::fmt::Display for SortKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.ascending {
+            true => write!(f, "{} {}", self.header, SORT_ASCENDING),
+            false => write!(f, "{} {}", self.header, SORT_DESCENDING),
+        }
+    }
+}
+
+/// A value as it is compared, numbers and dates by their value and text by its folded
+/// form. Empty values go last whatever the direction.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SortValue {
+    Number(f64),
+    /// The folded text first, the text as written breaks the ties.
+    Text(String, String),
+    Empty,
+}
+
+impl SortValue {
+    /// Values that do not parse as the type of their column are compared as text, after
+    /// the ones that do.
+    fn compare(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (SortValue::Number(a), SortValue::Number(b)) => a.total_cmp(b),
+            (SortValue::Text(a, a_raw), SortValue::Text(b, b_raw)) => {
+                a.cmp(b).then_with(|| a_raw.cmp(b_raw))
+            }
+            (SortValue::Number(_), SortValue::Text(..)) => Ordering::Less,
+            (SortValue::Text(..), SortValue::Number(_)) => Ordering::Greater,
+            (SortValue::Empty, SortValue::Empty) => Ordering::Equal,
+            (SortValue::Empty, _) => Ordering::Greater,
+            (_, SortValue::Empty) => Ordering::Less,
+        }
+    }
+}
+
+/// A value of a key with the direction of its column, so whole keys compare in order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SortCell {
+    value: SortValue,
+    ascending: bool,
+}
+
+impl Eq for SortCell {}
+
+impl PartialOrd for SortCell {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SortCell {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let ordering = self.value.compare(&other.value);
+        let is_empty = self.value == SortValue::Empty || other.value == SortValue::Empty;
+        match self.ascending || is_empty {
+            true => ordering,
+            false => ordering.reverse(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SortColumn {
+    index: usize,
+    ascending: bool,
+    data_type: DataType,
+    column_type: Option<ColumnType>,
+}
+
+/// Turns the records into the keys they are ordered by.
+#[derive(Debug, Clone)]
+pub struct Sorter {
+    columns: Vec<SortColumn>,
+}
+
+impl Sorter {
+    /// `types` are the types of every column and `column_types` the parse rules chosen in
+    /// the schema, in the order of the headers.
+    pub fn new(
+        keys: &[SortKey],
+        headers: &[String],
+        types: &[DataType],
+        column_types: &[Option<ColumnType>],
+    ) -> Result<Self, Error> {
+        let columns = keys
+            .iter()
+            .map(|key| {
+                let index = headers
+                    .iter()
+                    .position(|header| header == &key.header)
+                    .ok_or(Error::new(ErrorKind::NotFound, key.header.as_str()))?;
+                Ok(SortColumn {
+                    index,
+                    ascending: key.ascending,
+                    data_type: types.get(index).cloned().unwrap_or(DataType::Text),
+                    column_type: column_types.get(index).cloned().flatten(),
+                })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(Self { columns })
+    }
+
+    pub fn get_key(&self, record: &[String]) -> Vec<SortCell> {
+        self.columns
+            .iter()
+            .map(|column| SortCell {
+                value: Self::get_value(column, record.get(column.index)),
+                ascending: column.ascending,
+            })
+            .collect()
+    }
+
+    fn get_value(column: &SortColumn, value: Option<&String>) -> SortValue {
+        let value = match &column.column_type {
+            Some(column_type) => column_type.normalize(value.map_or("", |v| v.as_str())),
+            None => value.map_or("", |v| v.trim()).to_string(),
+        };
+        if value.is_empty() {
+            return SortValue::Empty;
+        }
+
+        match to_number(&value, &column.data_type) {
+            Some(number) => SortValue::Number(number),
+            None => SortValue::Text(Self::fold(&value), value),
+        }
+    }
+
+    /// Text the way people expect it ordered, without case and with the accents of the
+    /// latin letters taken as the plain letter. The ñ goes between the n and the o.
+    fn fold(value: &str) -> String {
+        let mut folded = String::with_capacity(value.len());
+        for c in value.chars().flat_map(char::to_lowercase) {
+            match c {
+                'á' | 'à' | 'â' | 'ä' | 'ã' | 'å' => folded.push('a'),
+                'é' | 'è' | 'ê' | 'ë' => folded.push('e'),
+                'í' | 'ì' | 'î' | 'ï' => folded.push('i'),
+                'ó' | 'ò' | 'ô' | 'ö' | 'õ' | 'ø' => folded.push('o'),
+                'ú' | 'ù' | 'û' | 'ü' => folded.push('u'),
+                'ç' => folded.push('c'),
+                'ý' | 'ÿ' => folded.push('y'),
+                'ñ' => folded.push_str("n\u{7f}"),
+                _ => folded.push(c),
+            }
+        }
+        folded
+    }
+}
+
+/// A number, or a date or time as the seconds since 1970, so they compare by their value.
+/// `None` for text or values that do not parse as the type.
+pub fn to_number(value: &str, data_type: &DataType) -> Option<f64> {
+    match data_type {
+        DataType::Integer | DataType::Float => value.parse::<f64>().ok(),
+        _ => parse_date_time(value, data_type)
+            .map(|date_time| date_time.and_utc().timestamp() as f64),
+    }
+}
+
+/// The value of a date, time or date and time column, a time is taken on the first day of
+/// 1970. `None` for other types or values in none of the known formats.
+pub fn parse_date_time(value: &str, data_type: &DataType) -> Option<NaiveDateTime> {
+    match data_type {
+        DataType::Date => DATE_FORMATS.iter().find_map(|format| {
+            NaiveDate::parse_from_str(value, format)
+                .ok()
+                .map(|date| date.and_time(NaiveTime::MIN))
+        }),
+        DataType::Time => TIME_FORMATS.iter().find_map(|format| {
+            NaiveTime::parse_from_str(value, format)
+                .ok()
+                .map(|time| NaiveDate::default().and_time(time))
+        }),
+        DataType::DateTime => DATETIME_FORMATS
+            .iter()
+            .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok()),
+        _ => None,
+    }
+}
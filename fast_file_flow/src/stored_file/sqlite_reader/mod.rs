@@ -0,0 +1,184 @@
+use crate::{
+    constants::english::ERROR_DATABASE_QUERY, dynamictable::iced_column::IcedColumn,
+    stadistics::data_type::DataType,
+};
+use futures::stream::{self, BoxStream, StreamExt};
+use rusqlite::{types::ValueRef, Connection, OpenFlags, Statement};
+use std::io::{Error, ErrorKind};
+use tokio::sync::mpsc::{self, Sender};
+
+use super::read_options::ReadOptions;
+
+/// Rows read ahead by the query while the stream is consumed.
+const CHANNEL_ROWS: usize = 1024;
+
+pub struct SqliteReader {}
+
+impl SqliteReader {
+    /// Tables and views of the database, without the internal ones of sqlite.
+    pub async fn get_table_names(file_path: &str) -> Result<Vec<String>, Error> {
+        let file_path = file_path.to_string();
+        tokio::task::spawn_blocking(move || {
+            let connection = Self::open(&file_path)?;
+            let mut statement = connection
+                .prepare(
+                    "SELECT name FROM sqlite_master WHERE type IN ('table', 'view') \
+                     AND name NOT LIKE 'sqlite_%' ORDER BY name",
+                )
+                .map_err(Self::to_error)?;
+            let names = statement
+                .query_map([], |row| row.get::<_, String>(0))
+                .map_err(Self::to_error)?
+                .collect::<Result<Vec<String>, _>>()
+                .map_err(Self::to_error)?;
+            Ok(names)
+        })
+        .await?
+    }
+
+    /// Columns of the query, typed from the declared type of the table columns.
+    pub async fn get_columns(
+        file_path: &str,
+        read_options: &ReadOptions,
+    ) -> Result<Vec<IcedColumn>, Error> {
+        let file_path = file_path.to_string();
+        let query = Self::get_query(read_options);
+        tokio::task::spawn_blocking(move || {
+            let connection = Self::open(&file_path)?;
+            let statement = Self::prepare(&connection, &query)?;
+            Ok(statement
+                .columns()
+                .iter()
+                .map(|column| {
+                    let mut iced_column = IcedColumn::new(column.name().to_string());
+                    iced_column.data_type = Self::to_data_type(column.decl_type());
+                    iced_column
+                })
+                .collect())
+        })
+        .await?
+    }
+
+    /// Counted by sqlite, so the rows are not sent through the stream.
+    pub async fn get_row_count(file_path: &str, read_options: &ReadOptions) -> Result<u64, Error> {
+        let file_path = file_path.to_string();
+        let query = format!("SELECT COUNT(*) FROM ({})", Self::get_query(read_options));
+        tokio::task::spawn_blocking(move || {
+            let connection = Self::open(&file_path)?;
+            let total = connection
+                .query_row(&query, [], |row| row.get::<_, i64>(0))
+                .map_err(Self::to_error)?;
+            Ok(total as u64)
+        })
+        .await?
+    }
+
+    pub async fn get_records(
+        file_path: &str,
+        read_options: &ReadOptions,
+    ) -> Result<BoxStream<'static, Result<Vec<String>, Error>>, Error> {
+        let file_path = file_path.to_string();
+        let query = Self::get_query(read_options);
+        let (sender, receiver) = mpsc::channel(CHANNEL_ROWS);
+
+        tokio::task::spawn_blocking(move || {
+            if let Err(error) = Self::send_records(&file_path, &query, &sender) {
+                let _ = sender.blocking_send(Err(error));
+            }
+        });
+
+        Ok(stream::unfold(receiver, |mut receiver| async move {
+            receiver.recv().await.map(|record| (record, receiver))
+        })
+        .boxed())
+    }
+
+    fn send_records(
+        file_path: &str,
+        query: &str,
+        sender: &Sender<Result<Vec<String>, Error>>,
+    ) -> Result<(), Error> {
+        let connection = Self::open(file_path)?;
+        let mut statement = Self::prepare(&connection, query)?;
+        let width = statement.column_count();
+        let mut rows = statement.query([]).map_err(Self::to_error)?;
+
+        while let Some(row) = rows.next().map_err(Self::to_error)? {
+            let record = (0..width)
+                .map(|index| row.get_ref(index).map(Self::to_value).unwrap_or_default())
+                .collect();
+            // The stream was dropped, the rest of the rows are not needed
+            if sender.blocking_send(Ok(record)).is_err() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// The query written by the user, or every row of the selected table.
+    fn get_query(read_options: &ReadOptions) -> String {
+        let query = read_options.query.trim().trim_end_matches(';').trim_end();
+        if query.is_empty() {
+            format!(
+                "SELECT * FROM \"{}\"",
+                read_options.sheet.replace('"', "\"\"")
+            )
+        } else {
+            query.to_string()
+        }
+    }
+
+    fn open(file_path: &str) -> Result<Connection, Error> {
+        Connection::open_with_flags(file_path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .map_err(Self::to_error)
+    }
+
+    pub fn prepare<'a>(connection: &'a Connection, query: &str) -> Result<Statement<'a>, Error> {
+        let statement = connection.prepare(query).map_err(Self::to_error)?;
+        if !statement.readonly() {
+            return Err(Error::new(ErrorKind::InvalidInput, ERROR_DATABASE_QUERY));
+        }
+        Ok(statement)
+    }
+
+    pub fn to_value(value: ValueRef) -> String {
+        match value {
+            ValueRef::Null => String::new(),
+            ValueRef::Integer(number) => number.to_string(),
+            ValueRef::Real(number) => number.to_string(),
+            ValueRef::Text(text) => String::from_utf8_lossy(text).into_owned(),
+            ValueRef::Blob(bytes) => bytes.iter().map(|byte| format!("{:02x}", byte)).collect(),
+        }
+    }
+
+    /// Follows the type affinity rules of sqlite, expressions without a declared type are
+    /// left for the statistics to infer.
+    pub fn to_data_type(decl_type: Option<&str>) -> DataType {
+        let decl_type = decl_type.unwrap_or_default().to_uppercase();
+        if decl_type.contains("INT") {
+            DataType::Integer
+        } else if ["CHAR", "CLOB", "TEXT"]
+            .iter()
+            .any(|name| decl_type.contains(name))
+        {
+            DataType::Text
+        } else if ["REAL", "FLOA", "DOUB", "NUMERIC", "DECIMAL"]
+            .iter()
+            .any(|name| decl_type.contains(name))
+        {
+            DataType::Float
+        } else if decl_type.contains("DATETIME") || decl_type.contains("TIMESTAMP") {
+            DataType::DateTime
+        } else if decl_type.contains("DATE") {
+            DataType::Date
+        } else if decl_type.contains("TIME") {
+            DataType::Time
+        } else {
+            DataType::Unknown
+        }
+    }
+
+    pub fn to_error(error: rusqlite::Error) -> Error {
+        Error::new(ErrorKind::InvalidData, error.to_string())
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{constants::english::ERROR_TASK_CANCELLED, stored_file::RecordStream};
+use futures::{future, StreamExt};
+use std::{
+    io::{Error, ErrorKind},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
+};
+use tokio::sync::watch;
+
+/// Records read between two checks of the progress.
+const TRACK_STEP: u64 = 1024;
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// The long task that is running, shared by the task and the UI. The task reports what it
+/// has done and stops when it is cancelled, the UI gets every change through a channel.
+#[derive(Debug, Clone)]
+pub struct TaskProgress {
+    pub id: u64,
+    done: Arc<AtomicU64>,
+    total: Arc<AtomicU64>,
+    cancelled: Arc<AtomicBool>,
+    sender: Arc<watch::Sender<f32>>,
+}
+
+impl TaskProgress {
+    /// A task nobody follows nor cancels.
+    pub fn default() -> Self {
+        Self {
+            id: 0,
+            done: Arc::new(AtomicU64::new(0)),
+            total: Arc::new(AtomicU64::new(0)),
+            cancelled: Arc::new(AtomicBool::new(false)),
+            sender: Arc::new(watch::Sender::new(0.0)),
+        }
+    }
+
+    /// A task of its own, that the UI follows and can cancel.
+    pub fn tracked() -> Self {
+        Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            ..Self::default()
+        }
+    }
+
+    /// Starts a new step of the task, `total` is zero when it is not known.
+    pub fn start(&self, total: u64) {
+        self.total.store(total, Ordering::Relaxed);
+        self.set_done(0);
+    }
+
+    pub fn advance(&self, done: u64) {
+        self.set_done(self.done.load(Ordering::Relaxed) + done);
+    }
+
+    /// Only a change of the whole percentage, or of the count when the total is not known,
+    /// is sent to the UI.
+    pub fn set_done(&self, done: u64) {
+        let previous = self.done.swap(done, Ordering::Relaxed);
+        let total = self.total.load(Ordering::Relaxed);
+        let is_changed = match total {
+            0 => previous / TRACK_STEP != done / TRACK_STEP || done == 0,
+            _ => previous * 100 / total != done * 100 / total || done == 0,
+        };
+        if is_changed {
+            self.sender.send_replace(self.get_percentage());
+        }
+    }
+
+    pub fn get_done(&self) -> u64 {
+        self.done.load(Ordering::Relaxed)
+    }
+
+    pub fn get_total(&self) -> u64 {
+        self.total.load(Ordering::Relaxed)
+    }
+
+    pub fn get_percentage(&self) -> f32 {
+        match self.get_total() {
+            0 => 0.0,
+            total => (self.get_done().min(total) as f64 * 100.0 / total as f64) as f32,
+        }
+    }
+
+    pub fn subscribe(&self) -> watch::Receiver<f32> {
+        self.sender.subscribe()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+        self.sender.send_replace(self.get_percentage());
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Fails once the task was cancelled, so the task stops at the next check.
+    pub fn check(&self) -> Result<(), Error> {
+        match self.is_cancelled() {
+            true => Err(Error::new(ErrorKind::Interrupted, ERROR_TASK_CANCELLED)),
+            false => Ok(()),
+        }
+    }
+
+    /// Counts the records read from the stream as a new step of `total` rows, and ends it
+    /// with an error when the task is cancelled.
+    pub fn track(&self, records: RecordStream, total: u64) -> RecordStream {
+        self.start(total);
+        let task = self.clone();
+        let mut read = 0;
+        records
+            .map(move |record| {
+                read += 1;
+                if read % TRACK_STEP == 0 {
+                    task.set_done(read);
+                    task.check()?;
+                }
+                record
+            })
+            .scan(false, |is_stopped, record| {
+                let is_cancelled = matches!(&record, Err(e) if e.kind() == ErrorKind::Interrupted);
+                let record = (!*is_stopped).then_some(record);
+                *is_stopped |= is_cancelled;
+                future::ready(record)
+            })
+            .boxed()
+    }
+}